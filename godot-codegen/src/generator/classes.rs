@@ -7,7 +7,9 @@
 use crate::context::{Context, NotificationEnum};
 use crate::generator::functions_common::{FnCode, FnDefinition, FnDefinitions};
 use crate::generator::method_tables::MethodTableKey;
-use crate::generator::{constants, docs, enums, functions_common, notifications, virtual_traits};
+use crate::generator::{
+    constants, docs, enums, functions_common, notifications, signals, virtual_traits,
+};
 use crate::models::domain::{
     ApiView, Class, ClassLike, ClassMethod, ExtensionApi, FnDirection, FnQualifier, Function,
     ModName, TyName,
@@ -130,10 +132,14 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
     let (notification_enum, notification_enum_name) =
         notifications::make_notification_enum(class_name, &all_bases, &cfg_attributes, ctx);
 
+    let (signal_collection, with_signals_impl) =
+        signals::make_class_signals(class, &all_bases, &cfg_attributes, view);
+
     // Associated "sidecar" module is made public if there are other symbols related to the class, which are not
     // in top-level godot::classes module (notification enums are not in the sidecar, but in godot::classes::notify).
     // This checks if token streams (i.e. code) is empty.
-    let has_sidecar_module = !enums.is_empty() || !builders.is_empty();
+    let has_sidecar_module =
+        !enums.is_empty() || !builders.is_empty() || signal_collection.is_some();
 
     let class_doc = docs::make_class_doc(
         class_name,
@@ -236,6 +242,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
                 unsafe impl crate::obj::Inherits<crate::classes::#all_bases> for #class_name {}
             )*
 
+            #with_signals_impl
             #godot_default_impl
             #deref_impl
 
@@ -256,6 +263,7 @@ fn make_class(class: &Class, ctx: &mut Context, view: &ApiView) -> GeneratedClas
 
         #builders
         #enums
+        #signal_collection
     };
     // note: TypePtr -> ObjectPtr conversion OK?
 
@@ -624,9 +624,20 @@ pub(crate) fn make_params_exprs_virtual<'a>(
                 ret.callsig_param_types.push(quote! { #param_ty });
             }
 
-            // All other methods and parameter types: standard handling.
-            // For now, virtual methods always receive their parameter by value.
-            //_ => ret.push_regular(param_name, param_ty, true, false, false),
+            // Objects that are never null, strings, arrays, dictionaries, variants, ...: borrowed from the engine, see ParamBorrow.
+            RustTy::EngineClass { .. }
+            | RustTy::BuiltinIdent {
+                arg_passing: ArgPassing::ByRef | ArgPassing::ImplAsArg,
+                ..
+            }
+            | RustTy::BuiltinArray { .. }
+            | RustTy::EngineArray { .. } => {
+                ret.param_decls.push(quote! { #param_name: & #param_ty });
+                ret.arg_exprs.push(quote! { #param_name });
+                ret.callsig_param_types.push(quote! { #param_ty });
+            }
+
+            // Copy types such as numbers, vectors and enums, as well as raw pointers: by value.
             _ => {
                 ret.param_decls.push(quote! { #param_name: #param_ty });
                 ret.arg_exprs.push(quote! { #param_name });
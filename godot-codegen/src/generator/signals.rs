@@ -0,0 +1,191 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Signal collections for engine classes. User classes have their own codegen in godot-macros (class/data_models/signal.rs).
+// Collections form a Deref chain mirroring the class hierarchy, so inherited signals are accessible without repeating them in every class.
+
+use crate::models::domain::{ApiView, Class, ClassLike, ClassSignal, RustTy, TyName};
+use crate::util::safe_ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+
+/// Returns `(collection, with_signals_impl)`.
+///
+/// The collection struct is only generated if the class declares signals itself; it is meant to be placed in the sidecar module.
+/// The `WithSignals` impl is generated for every class that has at least one (own or inherited) signal, and refers to the collection of
+/// the nearest class in the hierarchy that declares signals.
+pub fn make_class_signals(
+    class: &Class,
+    all_bases: &[TyName],
+    cfg_attributes: &TokenStream,
+    view: &ApiView,
+) -> (Option<TokenStream>, TokenStream) {
+    let nearest_base = all_bases
+        .iter()
+        .map(|base| view.get_engine_class(base))
+        .find(|base| !base.signals.is_empty());
+
+    let collection = if class.signals.is_empty() {
+        None
+    } else {
+        Some(make_signal_collection(class, nearest_base, cfg_attributes))
+    };
+
+    let collection_class = if collection.is_some() {
+        class
+    } else if let Some(base) = nearest_base {
+        base
+    } else {
+        // Neither the class nor any of its bases declare signals.
+        return (None, TokenStream::new());
+    };
+
+    let class_name = class.name();
+    let collection_path = make_collection_path(collection_class);
+
+    let with_signals_impl = quote! {
+        impl crate::obj::WithSignals for #class_name {
+            type SignalCollection<'c> = #collection_path<'c>;
+            #[doc(hidden)]
+            type __SignalObj<'c> = Gd<Object>;
+
+            #[doc(hidden)]
+            fn __signals_from_external(external: &Gd<Self>) -> Self::SignalCollection<'_> {
+                #collection_path::__new(external.clone().upcast_object())
+            }
+        }
+    };
+
+    (collection, with_signals_impl)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn make_signal_collection(
+    class: &Class,
+    nearest_base: Option<&Class>,
+    cfg_attributes: &TokenStream,
+) -> TokenStream {
+    let class_name = class.name();
+    let collection_name = make_collection_name(class_name);
+
+    let collection_doc = format!(
+        "A collection of signals for the [`{c}`][crate::classes::{c}] class, including inherited ones.\n\n\
+        Obtained via [`Gd::signals()`][crate::obj::Gd::signals].",
+        c = class_name.rust_ty
+    );
+
+    let accessors = class.signals.iter().map(make_signal_accessor);
+
+    // Signals of base classes are reachable via Deref; only the root (Object) stores the object pointer.
+    let (fields, constructor, deref_impl);
+    if let Some(base) = nearest_base {
+        let base_path = make_collection_path(base);
+
+        fields = quote! {
+            #[doc(hidden)]
+            pub(crate) __base: #base_path<'c>,
+        };
+        constructor = quote! {
+            Self {
+                __base: #base_path::__new(object),
+            }
+        };
+        deref_impl = quote! {
+            impl<'c> std::ops::Deref for #collection_name<'c> {
+                type Target = #base_path<'c>;
+
+                fn deref(&self) -> &Self::Target {
+                    &self.__base
+                }
+            }
+            impl<'c> std::ops::DerefMut for #collection_name<'c> {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    &mut self.__base
+                }
+            }
+        };
+    } else {
+        fields = quote! {
+            #[doc(hidden)]
+            pub(crate) __object: Gd<Object>,
+            _lifetime: std::marker::PhantomData<&'c ()>,
+        };
+        constructor = quote! {
+            Self {
+                __object: object,
+                _lifetime: std::marker::PhantomData,
+            }
+        };
+        deref_impl = TokenStream::new();
+    }
+
+    quote! {
+        #[doc = #collection_doc]
+        #cfg_attributes
+        pub struct #collection_name<'c> {
+            #fields
+        }
+
+        impl<'c> #collection_name<'c> {
+            #[doc(hidden)]
+            pub(crate) fn __new(object: Gd<Object>) -> Self {
+                #constructor
+            }
+
+            #( #accessors )*
+        }
+
+        #deref_impl
+    }
+}
+
+fn make_signal_accessor(signal: &ClassSignal) -> TokenStream {
+    let ClassSignal {
+        name,
+        parameters,
+        surrounding_class,
+    } = signal;
+
+    let class_name = &surrounding_class.rust_ty;
+    let accessor_name = safe_ident(name);
+    let accessor_doc = format!(
+        "Signal `{name}`.\n\n\
+        See also [Godot docs](https://docs.godotengine.org/en/stable/classes/class_{godot_lower}.html#class-{godot_lower}-signal-{anchor}).",
+        godot_lower = surrounding_class.godot_ty.to_ascii_lowercase(),
+        anchor = name.replace('_', "-"),
+    );
+
+    let param_types = parameters.iter().map(|param| make_param_type(&param.type_));
+
+    quote! {
+        #[doc = #accessor_doc]
+        pub fn #accessor_name(&mut self) -> crate::registry::signal::TypedSignal<'c, crate::classes::#class_name, (#( #param_types, )*)> {
+            crate::registry::signal::TypedSignal::new(self.__object.clone(), #name)
+        }
+    }
+}
+
+/// Objects passed to signals may be null, so they are received as `Option<Gd<T>>`, like return values of engine methods.
+fn make_param_type(ty: &RustTy) -> TokenStream {
+    match ty {
+        RustTy::EngineClass { tokens, .. } => quote! { Option<#tokens> },
+        other => other.to_token_stream(),
+    }
+}
+
+fn make_collection_name(class_name: &TyName) -> Ident {
+    format_ident!("SignalsOf{}", class_name.rust_ty)
+}
+
+fn make_collection_path(class: &Class) -> TokenStream {
+    let module_name = class.mod_name();
+    let collection_name = make_collection_name(class.name());
+
+    quote! { crate::classes::#module_name::#collection_name }
+}
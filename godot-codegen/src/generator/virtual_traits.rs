@@ -47,10 +47,11 @@ pub fn make_virtual_methods_trait(
 
 fn make_special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
     quote! {
-        #[doc(hidden)]
-        fn register_class(builder: &mut crate::builder::ClassBuilder<Self>) {
-            unimplemented!()
-        }
+        /// Registers additional members of this class at runtime, e.g. methods or properties generated from configuration files.
+        ///
+        /// Called once during class registration, after members declared through proc-macro attributes have been registered.
+        /// See [`ClassBuilder`][crate::builder::ClassBuilder] for details.
+        fn register_class(_builder: &mut crate::builder::ClassBuilder<Self>) {}
 
         /// Godot constructor, accepting an injected `base` object.
         ///
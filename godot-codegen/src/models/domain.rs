@@ -166,6 +166,7 @@ pub struct Class {
     pub constants: Vec<ClassConstant>,
     pub enums: Vec<Enum>,
     pub methods: Vec<ClassMethod>,
+    pub signals: Vec<ClassSignal>,
 }
 
 impl ClassLike for Class {
@@ -438,6 +439,16 @@ impl fmt::Display for ClassMethod {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// A signal declared by an engine class (not including inherited ones).
+pub struct ClassSignal {
+    /// Name as declared in Godot, e.g. `"tree_entered"`.
+    pub name: String,
+    pub parameters: Vec<FnParam>,
+    pub surrounding_class: TyName,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 #[derive(Copy, Clone, Debug)]
 pub enum FnDirection {
     /// Godot -> Rust.
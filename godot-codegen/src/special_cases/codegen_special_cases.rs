@@ -10,7 +10,7 @@
 // TODO make this file private and only accessed by special_cases.rs.
 
 use crate::context::Context;
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases;
 
 pub(crate) fn is_builtin_method_excluded(method: &JsonBuiltinMethod) -> bool {
@@ -91,6 +91,16 @@ pub(crate) fn is_class_method_excluded(method: &JsonClassMethod, ctx: &mut Conte
     false
 }
 
+pub(crate) fn is_class_signal_excluded(signal: &JsonSignal, ctx: &mut Context) -> bool {
+    // Same rules as for method parameters.
+    signal.arguments.as_ref().map_or(false, |args| {
+        args.iter().any(|arg| {
+            special_cases::is_godot_type_deleted(arg.type_.as_str())
+                || is_type_excluded(arg.type_.as_str(), ctx)
+        })
+    })
+}
+
 #[cfg(feature = "codegen-full")]
 pub(crate) fn is_utility_function_excluded(
     _function: &JsonUtilityFunction,
@@ -11,6 +11,7 @@
 // * Class methods:             is_class_method_*
 // * Builtin methods:           is_builtin_method_*
 // * Class or builtin methods:  is_method_*
+// * Class signals:             is_signal_*
 
 // Open design decisions:
 // * Should Godot types like Node3D have all the "obj level" methods like to_string(), get_instance_id(), etc; or should those
@@ -25,7 +26,7 @@
 
 use crate::conv::to_enum_type_uncached;
 use crate::models::domain::{Enum, RustTy, TyName};
-use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonUtilityFunction};
+use crate::models::json::{JsonBuiltinMethod, JsonClassMethod, JsonSignal, JsonUtilityFunction};
 use crate::special_cases::codegen_special_cases;
 use crate::Context;
 use proc_macro2::Ident;
@@ -84,6 +85,20 @@ pub fn is_class_method_deleted(class_name: &TyName, method: &JsonClassMethod, ct
     }
 }
 
+/// True if a signal should not be available in the typed signal API (it remains accessible through the untyped `Object` methods).
+pub fn is_signal_deleted(_class_name: &TyName, signal: &JsonSignal, ctx: &mut Context) -> bool {
+    // Typed signals are limited by the number of tuple elements supported in `ParamTuple`.
+    if signal
+        .arguments
+        .as_ref()
+        .map_or(false, |args| args.len() > 10)
+    {
+        return true;
+    }
+
+    codegen_special_cases::is_class_signal_excluded(signal, ctx)
+}
+
 pub fn is_class_deleted(class_name: &TyName) -> bool {
     codegen_special_cases::is_class_excluded(&class_name.godot_ty)
         || is_godot_type_deleted(&class_name.godot_ty)
@@ -5,136 +5,286 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-// use crate::GodotClass;
-// use godot_core::Variant;
-// use godot_ffi as sys;
-// use std::borrow::Cow;
-// use std::ffi::CStr;
-
-// pub trait Method<C> {
-//     type ReturnType;
-//     type ParamTypes;
-//
-//     fn method_name(&self) -> Cow<CStr>;
-//     fn ptrcall(&mut self, instance: &mut C, args: Self::ParamTypes) -> Self::ReturnType;
-// }
+use std::ffi::c_void;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
 
-// ----------------------------------------------------------------------------------------------------------------------------------------------
-/*
+use godot_ffi as sys;
+
+use crate::builtin::{StringName, Variant};
+use crate::global::MethodFlags;
+use crate::meta::error::CallError;
+use crate::meta::{
+    ptrcall_arg, ptrcall_return, varcall_arg_ref, CallContext, FromGodot, ToGodot,
+    VarcallSignatureTuple,
+};
+use crate::obj::GodotClass;
+use crate::private::{as_storage, Storage};
+use crate::registry::method::ClassMethodInfo;
+
+use super::ClassBuilder;
+
+/// Rust function or closure that can be registered as a method of class `C` through [`ClassBuilder::method()`].
+///
+/// Implemented for all `Fn(&mut C, P0, P1, ...) -> R`, where each parameter implements `FromGodot` and the return type implements
+/// `ToGodot`. `Ps` is the parameter list (without receiver) as a tuple. You should not need to implement this trait yourself.
+pub trait MethodFn<C: GodotClass, Ps>: Send + Sync + 'static {
+    /// Signature tuple `(R, P0, P1, ...)`, used to register parameter and return types with Godot.
+    #[doc(hidden)]
+    type Signature: VarcallSignatureTuple;
+
+    /// Converts `args` to the parameter types, invokes the function and converts its return value to `Variant`.
+    #[doc(hidden)]
+    fn call_varargs(
+        &self,
+        instance: &mut C,
+        call_ctx: &CallContext,
+        args: &[&Variant],
+    ) -> Result<Variant, CallError>;
+
+    /// Reads the parameters from `args_ptr`, invokes the function and writes its return value to `ret`.
+    ///
+    /// # Safety
+    /// `args_ptr` must point to one valid argument per parameter and `ret` to a valid value of the return type, as provided by Godot.
+    #[doc(hidden)]
+    unsafe fn call_ptrcall(
+        &self,
+        instance: &mut C,
+        call_ctx: &CallContext,
+        args_ptr: *const sys::GDExtensionConstTypePtr,
+        ret: sys::GDExtensionTypePtr,
+    );
+}
 
-/// Method known at compile time (statically), either a Rust `fn` or closure.
-pub trait CodeMethod<C, R, Ps>
+/// Builder for a method registered via [`ClassBuilder::method()`] or [`ClassBuilder::virtual_method()`].
+///
+/// The method is registered with Godot when [`done()`][Self::done] is called.
+#[must_use]
+pub struct MethodBuilder<'cb, C, F, Ps> {
+    _class_builder: &'cb mut ClassBuilder<C>,
+    name: &'cb str,
+    method: F,
+    param_names: Vec<String>,
+    flags: MethodFlags,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+impl<'cb, C, F, Ps> MethodBuilder<'cb, C, F, Ps>
 where
     C: GodotClass,
+    F: MethodFn<C, Ps>,
 {
-    const PARAM_COUNT: usize;
-    const NAME: &'static str;
+    pub(super) fn new(
+        class_builder: &'cb mut ClassBuilder<C>,
+        name: &'cb str,
+        method: F,
+        flags: MethodFlags,
+    ) -> Self {
+        // Default names, visible in the editor and GDScript autocompletion.
+        let param_names = (0..F::Signature::PARAM_COUNT)
+            .map(|i| format!("arg{i}"))
+            .collect();
 
-    unsafe fn varcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
-        err: *mut sys::GDExtensionCallError,
-    );
+        Self {
+            _class_builder: class_builder,
+            name,
+            method,
+            param_names,
+            flags,
+            _signature: PhantomData,
+        }
+    }
 
-    unsafe fn ptrcall(
-        &mut self,
-        instance: sys::GDExtensionClassInstancePtr,
-        args: *const sys::GDExtensionTypePtr,
-        ret: sys::GDExtensionTypePtr,
+    /// Sets the parameter names shown in Godot. By default, parameters are named `arg0`, `arg1`, ...
+    ///
+    /// # Panics
+    /// If the number of names does not match the number of parameters.
+    pub fn param_names(mut self, names: &[&str]) -> Self {
+        assert_eq!(
+            names.len(),
+            F::Signature::PARAM_COUNT,
+            "method `{}::{}`: expected {} parameter names, got {}",
+            C::class_name(),
+            self.name,
+            F::Signature::PARAM_COUNT,
+            names.len(),
+        );
+
+        self.param_names = names.iter().map(|name| name.to_string()).collect();
+        self
+    }
+
+    /// Registers the method with Godot.
+    pub fn done(self) {
+        let Self {
+            _class_builder: _,
+            name,
+            method,
+            param_names,
+            flags,
+            _signature,
+        } = self;
+
+        let param_names: Vec<&str> = param_names.iter().map(String::as_str).collect();
+
+        // Godot offers no callback to free method userdata, and classes are only registered once per library load.
+        // The data is thus kept alive until the end of the process.
+        let method_data = Box::new(MethodData {
+            class_name: C::class_name().to_string(),
+            method_name: name.to_string(),
+            method,
+        });
+        let method_data_ptr = Box::into_raw(method_data) as *mut c_void;
+
+        // SAFETY: varcall_fn + ptrcall_fn interpret their parameters according to `F::Signature`, and read `MethodData<F>` as userdata.
+        let method_info = unsafe {
+            ClassMethodInfo::from_signature::<C, F::Signature>(
+                StringName::from(name),
+                Some(varcall_fn::<C, F, Ps>),
+                Some(ptrcall_fn::<C, F, Ps>),
+                flags,
+                &param_names,
+                Vec::new(),
+            )
+            .with_method_userdata(method_data_ptr)
+        };
+
+        sys::out!("   Register fn:   {}::{} (builder)", C::class_name(), name);
+        method_info.register_extension_class_method();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// FFI glue
+
+/// Userdata of each method registered through the builder.
+struct MethodData<F> {
+    class_name: String,
+    method_name: String,
+    method: F,
+}
+
+unsafe extern "C" fn varcall_fn<C, F, Ps>(
+    method_data: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) where
+    C: GodotClass,
+    F: MethodFn<C, Ps>,
+{
+    let data = &*(method_data as *const MethodData<F>);
+    let call_ctx = CallContext::func(&data.class_name, &data.method_name);
+
+    crate::private::handle_varcall_panic(
+        &call_ctx,
+        &mut *err,
+        AssertUnwindSafe(|| {
+            let args: Vec<&Variant> = (0..arg_count as usize)
+                .map(|i| Variant::borrow_var_sys(*args_ptr.add(i)))
+                .collect();
+
+            let storage = as_storage::<C>(instance_ptr);
+            let mut instance = storage.get_mut();
+
+            let result = data.method.call_varargs(&mut *instance, &call_ctx, &args)?;
+            *(ret as *mut Variant) = result;
+            Ok(())
+        }),
     );
 }
 
+unsafe extern "C" fn ptrcall_fn<C, F, Ps>(
+    method_data: *mut c_void,
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) where
+    C: GodotClass,
+    F: MethodFn<C, Ps>,
+{
+    let data = &*(method_data as *const MethodData<F>);
+    let call_ctx = CallContext::func(&data.class_name, &data.method_name);
 
-// TODO code duplication ((2))
-macro_rules! count_idents {
-    () => {
-        0
-    };
-    ($name:ident, $($other:ident,)*) => {
-        1 + $crate::gdext_count_idents!($($other,)*)
-    }
+    let _success = crate::private::handle_panic(
+        || &call_ctx,
+        AssertUnwindSafe(|| {
+            let storage = as_storage::<C>(instance_ptr);
+            let mut instance = storage.get_mut();
+
+            data.method
+                .call_ptrcall(&mut *instance, &call_ctx, args_ptr, ret);
+        }),
+    );
 }
 
-macro_rules! impl_code_method {
-// 	( $( $Param:ident ),* ) => {
-    ( $( $Param:ident $arg:ident ),* ) => {
-        impl<C, F, R, $( $Param ),*> CodeMethod<C, R, ( $( $Param, )* )> for F
-        where
-            C: $crate::GodotClass + $crate::GodotDefault, // TODO only GodotClass
-            F: Fn(&C, $( $Param ),* ) -> R,
-            $(
-                $Param: sys::GodotFfi,
-            )*
-            R: sys::GodotFfi + 'static,
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Generated impls
+
+macro_rules! impl_method_fn_for_tuple {
+    (
+        $PARAM_COUNT:literal
+        $(, ($pn:ident, $n:tt) : $Pn:ident)*
+    ) => {
+        impl<C, F, R, $($Pn,)*> MethodFn<C, ($($Pn,)*)> for F
+            where
+                C: GodotClass,
+                F: Fn(&mut C, $($Pn,)*) -> R + Send + Sync + 'static,
+                R: ToGodot + FromGodot + Debug,
+                $(
+                    $Pn: ToGodot + FromGodot + Debug,
+                )*
         {
-            const PARAM_COUNT: usize = count_idents!($( $Param, )*);
-
-            // Varcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn varcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
-                ret: sys::GDExtensionTypePtr,
-                err: *mut sys::GDExtensionCallError,
-            ) {
-                let storage = ::godot_core::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
+            type Signature = (R, $($Pn,)*);
 
-                let mut idx = 0;
+            fn call_varargs(
+                &self,
+                instance: &mut C,
+                call_ctx: &CallContext,
+                args: &[&Variant],
+            ) -> Result<Variant, CallError> {
+                CallError::check_arg_count(call_ctx, args.len(), $PARAM_COUNT)?;
 
                 $(
-                    let $arg = <$Param as FromVariant::from_variant(&*(*args.offset(idx) as *mut Variant));
-                    idx += 1;
+                    let $pn = varcall_arg_ref::<$Pn>(args[$n], $n, call_ctx)?;
                 )*
 
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                *(ret as *mut Variant) = Variant::from(ret_val);
-                (*err).error = sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK;
+                let rust_result = self(instance, $($pn,)*);
+                Ok(rust_result.to_variant())
             }
 
-
-            // Ptrcall
-            #[inline]
-            #[allow(unused_variables, unused_assignments, unused_mut)]
-            unsafe fn ptrcall(
-                &mut self,
-                instance: sys::GDExtensionClassInstancePtr,
-                args: *const sys::GDExtensionTypePtr,
+            #[allow(unused_variables)]
+            unsafe fn call_ptrcall(
+                &self,
+                instance: &mut C,
+                call_ctx: &CallContext,
+                args_ptr: *const sys::GDExtensionConstTypePtr,
                 ret: sys::GDExtensionTypePtr,
             ) {
-                let storage = $crate::private::as_storage::<C>(instance);
-                let instance = storage.get_mut_lateinit();
-
-                // TODO reuse code, see ((1))
-                let mut idx = 0;
+                let call_type = sys::PtrcallType::Standard;
 
                 $(
-                    let $arg = <$Param as sys::GodotFfi>::from_sys(*args.offset(idx));
-                    // FIXME update refcount, e.g. Gd::ready() or T::DynMemory::maybe_inc_ref(&result);
-                    // possibly in from_sys() directly; what about from_sys_init() and from_{obj|str}_sys()?
-                    idx += 1;
+                    let $pn = ptrcall_arg::<$Pn, $n>(args_ptr, call_ctx, call_type);
                 )*
 
-                let ret_val = self(&instance, $(
-                    $arg,
-                )*);
-
-                <R as sys::GodotFfi>::write_sys(&ret_val, ret);
+                let rust_result = self(instance, $($pn,)*);
+                ptrcall_return::<R>(rust_result, ret, call_ctx, call_type);
             }
         }
-
     };
 }
 
-impl_code_method!();
-impl_code_method!(P0 arg0);
-impl_code_method!(P0 arg0, P1 arg1);
-impl_code_method!(P0 arg0, P1 arg1, P2 arg2);
-*/
+impl_method_fn_for_tuple!(0);
+impl_method_fn_for_tuple!(1, (p0, 0): P0);
+impl_method_fn_for_tuple!(2, (p0, 0): P0, (p1, 1): P1);
+impl_method_fn_for_tuple!(3, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_method_fn_for_tuple!(4, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_method_fn_for_tuple!(5, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_method_fn_for_tuple!(6, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_method_fn_for_tuple!(7, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_method_fn_for_tuple!(8, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
+impl_method_fn_for_tuple!(9, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8);
+impl_method_fn_for_tuple!(10, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9);
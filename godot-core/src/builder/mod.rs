@@ -5,11 +5,64 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::obj::GodotClass;
+//! Runtime registration of class members, as an alternative to proc-macro attributes.
+
 use std::marker::PhantomData;
 
+use crate::builtin::StringName;
+use crate::global::{MethodFlags, PropertyUsageFlags};
+use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
+use crate::obj::GodotClass;
+use crate::registry::constant::{ConstantKind, ExportConstant, IntegerConstant};
+use crate::registry::godot_register_wrappers::{register_signal, register_var, validate_export};
+use crate::registry::property::{Export, Var};
+
 mod method;
 
+pub use method::{MethodBuilder, MethodFn};
+
+/// Registers members of class `C` with Godot at runtime.
+///
+/// Obtained in the `register_class()` function of the class' `I*` interface trait. This allows registering methods, properties, signals
+/// and constants whose shape is only known at runtime, e.g. when generated from configuration files.
+///
+/// Members registered here are added after those from `#[godot_api]` and `#[var]`/`#[export]` attributes, so they can refer to the latter.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::register::ClassBuilder;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Inventory {
+///     capacity: i64,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl INode for Inventory {
+///     fn register_class(builder: &mut ClassBuilder<Self>) {
+///         builder
+///             .method("get_capacity", |this: &mut Self| this.capacity)
+///             .done();
+///
+///         builder
+///             .method("set_capacity", |this: &mut Self, value: i64| this.capacity = value)
+///             .param_names(&["value"])
+///             .done();
+///
+///         builder
+///             .property::<i64>("capacity")
+///             .getter("get_capacity")
+///             .setter("set_capacity")
+///             .done();
+///
+///         builder.signal("item_added").param::<GString>("item_name").done();
+///         builder.constant("MAX_CAPACITY", 64);
+///     }
+/// }
+/// ```
 pub struct ClassBuilder<C> {
     _c: PhantomData<C>,
 }
@@ -22,33 +75,203 @@ where
         Self { _c: PhantomData }
     }
 
-    pub fn virtual_method<'cb, F>(
+    /// Name of the class being registered.
+    pub fn class_name(&self) -> ClassName {
+        C::class_name()
+    }
+
+    /// Registers a method, callable from GDScript and other languages.
+    ///
+    /// `method` can be any function or closure taking `&mut C` as first parameter; see [`MethodFn`].
+    pub fn method<'cb, F, Ps>(
         &'cb mut self,
         name: &'cb str,
         method: F,
-    ) -> MethodBuilder<'cb, C, F> {
-        MethodBuilder::new(self, name, method)
+    ) -> MethodBuilder<'cb, C, F, Ps>
+    where
+        F: MethodFn<C, Ps>,
+    {
+        MethodBuilder::new(self, name, method, MethodFlags::NORMAL)
+    }
+
+    /// Registers a method that can be overridden by scripts attached to an instance, with `method` as its default implementation.
+    ///
+    /// Equivalent to `#[func(virtual)]`.
+    pub fn virtual_method<'cb, F, Ps>(
+        &'cb mut self,
+        name: &'cb str,
+        method: F,
+    ) -> MethodBuilder<'cb, C, F, Ps>
+    where
+        F: MethodFn<C, Ps>,
+    {
+        MethodBuilder::new(
+            self,
+            name,
+            method,
+            MethodFlags::NORMAL | MethodFlags::VIRTUAL,
+        )
+    }
+
+    /// Registers a property of type `T`, backed by getter and setter methods.
+    ///
+    /// By default, the property is not shown in the editor, like `#[var]`. Use [`PropertyBuilder::export()`] to change that.
+    pub fn property<'cb, T: Var>(&'cb mut self, name: &'cb str) -> PropertyBuilder<'cb, C, T> {
+        PropertyBuilder::new(self, name)
+    }
+
+    /// Registers a signal. Parameters can be added with [`SignalBuilder::param()`].
+    pub fn signal<'cb>(&'cb mut self, name: &'cb str) -> SignalBuilder<'cb, C> {
+        SignalBuilder::new(self, name)
+    }
+
+    /// Registers an integer constant, accessible as `ClassName.NAME` in GDScript.
+    ///
+    /// # Panics
+    /// If `value` is not representable as `i64`.
+    pub fn constant<T>(&mut self, name: &str, value: T)
+    where
+        T: TryInto<i64> + Copy + std::fmt::Debug,
+    {
+        let kind = ConstantKind::Integer(IntegerConstant::new(name, value));
+        ExportConstant::new(C::class_name(), kind).register();
+    }
+
+    /// Registers an enum with the given `(name, value)` enumerators, accessible as `ClassName.EnumName.NAME` in GDScript.
+    ///
+    /// # Panics
+    /// If any value is not representable as `i64`.
+    pub fn enum_constants<T>(&mut self, enum_name: &str, enumerators: &[(&str, T)])
+    where
+        T: TryInto<i64> + Copy + std::fmt::Debug,
+    {
+        let kind = ConstantKind::Enum {
+            name: StringName::from(enum_name),
+            enumerators: enumerators
+                .iter()
+                .map(|&(name, value)| IntegerConstant::new(name, value))
+                .collect(),
+        };
+        ExportConstant::new(C::class_name(), kind).register();
     }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)] // TODO rm
+/// Builder for a property registered via [`ClassBuilder::property()`].
+///
+/// The property is registered with Godot when [`done()`][Self::done] is called.
 #[must_use]
-pub struct MethodBuilder<'cb, C, F> {
-    class_builder: &'cb mut ClassBuilder<C>,
+pub struct PropertyBuilder<'cb, C, T> {
+    _class_builder: &'cb mut ClassBuilder<C>,
     name: &'cb str,
-    method: F,
+    getter: &'cb str,
+    setter: &'cb str,
+    hint_info: PropertyHintInfo,
+    usage: PropertyUsageFlags,
+    _ty: PhantomData<T>,
 }
 
-impl<'cb, C, F> MethodBuilder<'cb, C, F> {
-    pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str, method: F) -> Self {
+impl<'cb, C, T> PropertyBuilder<'cb, C, T>
+where
+    C: GodotClass,
+    T: Var,
+{
+    fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str) -> Self {
         Self {
-            class_builder,
+            _class_builder: class_builder,
             name,
-            method,
+            getter: "",
+            setter: "",
+            hint_info: T::var_hint(),
+            usage: PropertyUsageFlags::NONE,
+            _ty: PhantomData,
         }
     }
 
-    pub fn done(self) {}
+    /// Name of the registered method that returns the property's value. Without getter, the property is write-only.
+    pub fn getter(mut self, method_name: &'cb str) -> Self {
+        self.getter = method_name;
+        self
+    }
+
+    /// Name of the registered method that accepts the property's new value. Without setter, the property is read-only.
+    pub fn setter(mut self, method_name: &'cb str) -> Self {
+        self.setter = method_name;
+        self
+    }
+
+    /// Shows the property in the editor and stores it in scenes/resources, like `#[export]`.
+    ///
+    /// # Panics
+    /// If `T` is a node type, but `C` does not inherit `Node`.
+    pub fn export(mut self) -> Self
+    where
+        T: Export,
+    {
+        validate_export::<C, T>();
+
+        self.hint_info = T::export_hint();
+        self.usage = PropertyUsageFlags::DEFAULT;
+        self
+    }
+
+    /// Overrides the property hint, e.g. to specify a range.
+    pub fn hint_info(mut self, hint_info: PropertyHintInfo) -> Self {
+        self.hint_info = hint_info;
+        self
+    }
+
+    /// Overrides the usage flags.
+    pub fn usage(mut self, usage: PropertyUsageFlags) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Registers the property with Godot.
+    pub fn done(self) {
+        register_var::<C, T>(
+            self.name,
+            self.getter,
+            self.setter,
+            self.hint_info,
+            self.usage,
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Builder for a signal registered via [`ClassBuilder::signal()`].
+///
+/// The signal is registered with Godot when [`done()`][Self::done] is called.
+#[must_use]
+pub struct SignalBuilder<'cb, C> {
+    _class_builder: &'cb mut ClassBuilder<C>,
+    name: &'cb str,
+    parameters: Vec<PropertyInfo>,
+}
+
+impl<'cb, C> SignalBuilder<'cb, C>
+where
+    C: GodotClass,
+{
+    fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str) -> Self {
+        Self {
+            _class_builder: class_builder,
+            name,
+            parameters: Vec::new(),
+        }
+    }
+
+    /// Adds a parameter of type `T`.
+    pub fn param<T: GodotConvert>(mut self, name: &str) -> Self {
+        self.parameters.push(T::Via::property_info(name));
+        self
+    }
+
+    /// Registers the signal with Godot.
+    pub fn done(self) {
+        register_signal(C::class_name(), self.name, &self.parameters);
+    }
 }
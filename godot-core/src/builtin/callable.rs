@@ -189,6 +189,50 @@ impl Callable {
         })
     }
 
+    /// Create callable from **single-threaded** Rust function or closure with typed parameters.
+    ///
+    /// Unlike [`from_local_fn()`][Self::from_local_fn], the function does not operate on `&[&Variant]`, but declares its parameters and
+    /// return type directly. Arguments are converted via [`FromGodot`][crate::meta::FromGodot], and the return value via `ToGodot`.
+    /// If the callable is invoked with the wrong number of arguments, or an argument cannot be converted, the function is not called;
+    /// instead, a call error is reported to Godot and `Nil` is returned.
+    ///
+    /// `name` is used for the string representation of the closure, which helps debugging. Like `from_local_fn()`, the callable may only be
+    /// invoked from the thread it was created in.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let callable = Callable::from_typed_fn("add", |a: i64, b: i64| a + b);
+    ///
+    /// let sum = callable.callv(&varray![3, 4]);
+    /// assert_eq!(sum, 7.to_variant());
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn from_typed_fn<F, Ps, S>(name: S, rust_function: F) -> Self
+    where
+        F: meta::TypedFn<Ps>,
+        S: meta::AsArg<GString>,
+    {
+        meta::arg_into_owned!(name);
+
+        let wrapper = FnWrapper {
+            rust_function,
+            name,
+            thread_id: Some(std::thread::current().id()),
+        };
+        let userdata = CallableUserdata { inner: wrapper };
+
+        let info = CallableCustomInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            call_func: Some(rust_callable_call_typed_fn::<F, Ps>),
+            free_func: Some(rust_callable_destroy::<FnWrapper<F>>),
+            to_string_func: Some(rust_callable_to_string_named::<F>),
+            ..Self::default_callable_custom_info()
+        };
+
+        Self::from_custom_info(info)
+    }
+
     #[deprecated = "Now split into from_local_fn (single-threaded) and from_sync_fn (multi-threaded)."]
     #[cfg(since_api = "4.2")]
     pub fn from_fn<F, S>(name: S, rust_function: F) -> Self
@@ -455,6 +499,7 @@ unsafe impl GodotFfi for Callable {
 }
 
 meta::impl_godot_as_self!(Callable);
+meta::impl_param_borrow!(opaque Callable);
 
 impl fmt::Debug for Callable {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -512,6 +557,23 @@ mod custom_callable {
         pub(super) thread_id: Option<ThreadId>,
     }
 
+    impl<F> FnWrapper<F> {
+        fn check_thread(&self) {
+            if self
+                .thread_id
+                .is_some_and(|tid| tid != std::thread::current().id())
+            {
+                // NOTE: this panic is currently not propagated to the caller, but results in an error message and Nil return.
+                // See comments in itest callable_call() for details.
+                panic!(
+                    "Callable '{}' created with from_local_fn() or from_typed_fn() must be called from the same thread it was created in.\n\
+                    If you need to call it from any thread, use from_sync_fn() instead (requires `experimental-threads` feature).",
+                    self.name
+                );
+            }
+        }
+    }
+
     /// Represents a custom callable object defined in Rust.
     ///
     /// This trait has a single method, `invoke`, which is called upon invocation.
@@ -573,17 +635,7 @@ mod custom_callable {
             // Get the FnWrapper again inside closure so the FnMut doesn't have to be UnwindSafe.
             let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
 
-            if w.thread_id
-                .is_some_and(|tid| tid != std::thread::current().id())
-            {
-                // NOTE: this panic is currently not propagated to the caller, but results in an error message and Nil return.
-                // See comments in itest callable_call() for details.
-                panic!(
-                    "Callable '{}' created with from_local_fn() must be called from the same thread it was created in.\n\
-                    If you need to call it from any thread, use from_sync_fn() instead (requires `experimental-threads` feature).",
-                    w.name
-                );
-            }
+            w.check_thread();
 
             let result = (w.rust_function)(arg_refs);
             meta::varcall_return_checked(result, r_return, r_error);
@@ -591,6 +643,36 @@ mod custom_callable {
         });
     }
 
+    pub unsafe extern "C" fn rust_callable_call_typed_fn<F, Ps>(
+        callable_userdata: *mut std::ffi::c_void,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) where
+        F: meta::TypedFn<Ps>,
+    {
+        let arg_refs: &[&Variant] = Variant::borrow_ref_slice(p_args, p_argument_count as usize);
+
+        let name = {
+            let w: &FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+            w.name.to_string()
+        };
+        let ctx = meta::CallContext::custom_callable(name.as_str());
+        let call_ctx = &ctx;
+
+        crate::private::handle_varcall_panic(&ctx, &mut *r_error, move || {
+            // Get the FnWrapper again inside closure so the FnMut doesn't have to be UnwindSafe.
+            let w: &mut FnWrapper<F> = CallableUserdata::inner_from_raw(callable_userdata);
+            w.check_thread();
+
+            // Argument count or type mismatch is returned as CallError, reported by handle_varcall_panic().
+            let result = w.rust_function.call_varargs(call_ctx, arg_refs)?;
+            meta::varcall_return_checked(Ok(result), r_return, r_error);
+            Ok(())
+        });
+    }
+
     pub unsafe extern "C" fn rust_callable_destroy<T>(callable_userdata: *mut std::ffi::c_void) {
         let rust_ptr = callable_userdata as *mut CallableUserdata<T>;
         let _drop = Box::from_raw(rust_ptr);
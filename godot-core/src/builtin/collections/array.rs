@@ -7,14 +7,15 @@
 
 use std::fmt;
 use std::marker::PhantomData;
+use std::ops;
 
 use crate::builtin::*;
 use crate::meta;
 use crate::meta::error::{ConvertError, FromGodotError, FromVariantError};
 use crate::meta::{
-    element_godot_type_name, element_variant_type, ArrayElement, ArrayTypeInfo, AsArg, CowArg,
-    FromGodot, GodotConvert, GodotFfiVariant, GodotType, ParamType, PropertyHintInfo, RefArg,
-    ToGodot,
+    element_godot_type_name, element_variant_type, ArrayElement, ArrayTypeInfo, AsArg, ClassName,
+    CowArg, FromGodot, GodotConvert, GodotFfiVariant, GodotType, ParamType, PropertyHintInfo,
+    RefArg, ToGodot,
 };
 use crate::registry::property::{Export, Var};
 use godot_ffi as sys;
@@ -150,24 +151,6 @@ impl<'a> std::ops::Deref for ImmutableInnerArray<'a> {
 /// A Godot `Array` without an assigned type.
 pub type VariantArray = Array<Variant>;
 
-// TODO check if these return a typed array
-impl_builtin_froms!(VariantArray;
-    PackedByteArray => array_from_packed_byte_array,
-    PackedColorArray => array_from_packed_color_array,
-    PackedFloat32Array => array_from_packed_float32_array,
-    PackedFloat64Array => array_from_packed_float64_array,
-    PackedInt32Array => array_from_packed_int32_array,
-    PackedInt64Array => array_from_packed_int64_array,
-    PackedStringArray => array_from_packed_string_array,
-    PackedVector2Array => array_from_packed_vector2_array,
-    PackedVector3Array => array_from_packed_vector3_array,
-);
-
-#[cfg(since_api = "4.3")]
-impl_builtin_froms!(VariantArray;
-    PackedVector4Array => array_from_packed_vector4_array,
-);
-
 impl<T: ArrayElement> Array<T> {
     fn from_opaque(opaque: sys::types::OpaqueArray) -> Self {
         // Note: type is not yet checked at this point, because array has not yet been initialized!
@@ -280,8 +263,9 @@ impl<T: ArrayElement> Array<T> {
     ///
     /// # Panics
     ///
-    /// If `index` is out of bounds.
+    /// If `index` is out of bounds, or the array is read-only.
     pub fn set(&mut self, index: usize, value: impl AsArg<T>) {
+        self.check_writable();
         let ptr_mut = self.ptr_mut(index);
 
         meta::arg_into_ref!(value: T);
@@ -682,6 +666,262 @@ impl<T: ArrayElement> Array<T> {
         unsafe { self.as_inner_mut() }.shuffle();
     }
 
+    /// Returns `true` if the array is read-only.
+    ///
+    /// See [`make_read_only()`][Self::make_read_only].
+    pub fn is_read_only(&self) -> bool {
+        self.as_inner().is_read_only()
+    }
+
+    /// Makes the array read-only, i.e. disables modification of its elements.
+    ///
+    /// This cannot be undone; however, duplicates of the array are writable again. Rust methods that modify elements, such as
+    /// [`set()`][Self::set] or [`modify()`][Self::modify], panic on read-only arrays. Most other modifications are rejected by Godot
+    /// with an error message.
+    ///
+    /// In GDScript, arrays are automatically read-only if declared with the `const` keyword.
+    pub fn make_read_only(&mut self) {
+        // SAFETY: Making an array read-only does not write any values.
+        unsafe { self.as_inner_mut() }.make_read_only();
+    }
+
+    /// Makes the array read-only and returns it as a [`ReadOnlyArray`], which statically prevents modification.
+    ///
+    /// Other references to the same array (obtained through [`clone()`][Clone::clone]) observe the read-only state as well.
+    pub fn into_read_only(mut self) -> ReadOnlyArray<T> {
+        self.make_read_only();
+        ReadOnlyArray::from_read_only(self)
+    }
+
+    /// Modifies the element at `index` in place through a closure, returning the closure's result.
+    ///
+    /// The element is converted from Godot, passed to `f` and written back afterwards. This avoids the manual `get` + `set` round trip.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds, or the array is read-only.
+    pub fn modify<R, F>(&mut self, index: usize, f: F) -> R
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        self.check_writable();
+
+        let mut element = self.at(index);
+        let result = f(&mut element);
+
+        // Look up the pointer only now: `f` may have modified the array through another reference.
+        let ptr_mut = self.ptr_mut(index);
+
+        // SAFETY: `ptr_mut` just checked that the index is not out of bounds, and `element` has type `T`.
+        unsafe { element.to_variant().move_into_var_ptr(ptr_mut) };
+
+        result
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` is out of bounds, or the array is read-only.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.check_writable();
+        self.check_bounds(a);
+        self.check_bounds(b);
+
+        // SAFETY: We only move around values that already exist in the array.
+        let elements = unsafe { self.as_variant_slice_mut() };
+        elements.swap(a, b);
+    }
+
+    /// Splits the array in two at the given index, returning the elements `[at, len)` as a new array.
+    ///
+    /// This array retains the elements `[0, at)`. The returned array has the same element type.
+    ///
+    /// # Panics
+    ///
+    /// If `at > len`, or the array is read-only.
+    #[must_use]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len();
+        assert!(at <= len, "split_off index {at} out of bounds (len {len})");
+        self.check_writable();
+
+        let tail = self.subarray_shallow(at, len, None);
+        self.shrink(at);
+
+        tail
+    }
+
+    /// Removes the elements in `range` and returns them as a new array.
+    ///
+    /// Unlike [`Vec::drain()`], the elements are removed eagerly, even if the returned array is not used.
+    ///
+    /// # Panics
+    ///
+    /// If the range is out of bounds or decreasing, or the array is read-only.
+    pub fn drain(&mut self, range: impl ops::RangeBounds<usize>) -> Self {
+        let len = self.len();
+        let (start, end) = to_start_end(range, len);
+        self.check_writable();
+
+        let drained = self.subarray_shallow(start, end, None);
+
+        let count = end - start;
+        if count > 0 {
+            // SAFETY: We only move around values that already exist in the array.
+            let elements = unsafe { self.as_variant_slice_mut() };
+            elements[start..].rotate_left(count);
+
+            self.shrink(len - count);
+        }
+
+        drained
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run.
+    ///
+    /// Elements are compared with Godot's `==` operator on variants. If the array is sorted, this removes all duplicates.
+    ///
+    /// # Panics
+    ///
+    /// If the array is read-only.
+    pub fn dedup(&mut self) {
+        self.check_writable();
+        if self.len() < 2 {
+            return;
+        }
+
+        // SAFETY: We only move around values that already exist in the array.
+        let elements = unsafe { self.as_variant_slice_mut() };
+
+        let mut write = 1;
+        for read in 1..elements.len() {
+            if elements[read] != elements[write - 1] {
+                elements.swap(write, read);
+                write += 1;
+            }
+        }
+
+        self.shrink(write);
+    }
+
+    /// Returns an iterator over consecutive, non-overlapping sub-arrays of `chunk_size` elements.
+    ///
+    /// The last chunk may be shorter if the length is not divisible by `chunk_size`. Each chunk is a new (shallow) array.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_size` is 0.
+    pub fn chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+
+        Chunks {
+            array: self,
+            chunk_size,
+            next_idx: 0,
+        }
+    }
+
+    /// Returns a new array with the results of calling `f` on each element, in order.
+    ///
+    /// Runs entirely on the Rust side. To map with a GDScript-provided `Callable`, see [`functional_ops()`][Self::functional_ops].
+    pub fn map<U, F>(&self, f: F) -> Array<U>
+    where
+        U: ArrayElement,
+        F: FnMut(T) -> U,
+    {
+        self.iter_shared().map(f).collect()
+    }
+
+    /// Returns a new array with all elements for which `predicate` returns `true`, in order.
+    pub fn filter<F>(&self, mut predicate: F) -> Self
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter_shared()
+            .filter(|element| predicate(element))
+            .collect()
+    }
+
+    /// Retains only the elements for which `predicate` returns `true`, removing all others in place.
+    ///
+    /// The order of retained elements is preserved. Other references to this array observe the change.
+    ///
+    /// # Panics
+    ///
+    /// If the array is read-only, or if `predicate` changes the array's length (e.g. through another reference).
+    pub fn retain<F>(&mut self, mut predicate: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.check_writable();
+
+        // Evaluate all predicates before touching the array, since `predicate` may access it through another reference.
+        // The length is snapshotted, so that a predicate growing the array cannot make this loop run forever.
+        let len = self.len();
+        let mut keep = Vec::with_capacity(len);
+        for element in self.iter_shared().take(len) {
+            keep.push(predicate(&element));
+        }
+
+        assert!(
+            keep.len() == len && self.len() == len,
+            "Array::retain(): predicate must not change the array's length"
+        );
+
+        // SAFETY: We only move around values that already exist in the array.
+        let elements = unsafe { self.as_variant_slice_mut() };
+
+        let mut write = 0;
+        for (read, keep) in keep.into_iter().enumerate() {
+            if keep {
+                elements.swap(write, read);
+                write += 1;
+            }
+        }
+
+        self.shrink(write);
+    }
+
+    /// Folds all elements into a single value, starting with `initial` and passing the accumulator and each element to `f`.
+    ///
+    /// Equivalent to Godot's `reduce()` with an initial accumulator, and to Rust's [`Iterator::fold()`].
+    #[doc(alias = "fold")]
+    pub fn reduce<A, F>(&self, initial: A, f: F) -> A
+    where
+        F: FnMut(A, T) -> A,
+    {
+        self.iter_shared().fold(initial, f)
+    }
+
+    /// Returns `true` if `predicate` returns `true` for at least one element.
+    ///
+    /// Stops at the first match. Returns `false` for an empty array.
+    pub fn any<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(T) -> bool,
+    {
+        self.iter_shared().any(predicate)
+    }
+
+    /// Returns `true` if `predicate` returns `true` for all elements.
+    ///
+    /// Stops at the first mismatch. Returns `true` for an empty array.
+    pub fn all<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(T) -> bool,
+    {
+        self.iter_shared().all(predicate)
+    }
+
+    /// Access to Godot's functional-programming APIs, which take a [`Callable`] instead of a Rust closure.
+    ///
+    /// Useful when the function is provided by GDScript. For Rust logic, prefer the closure-based [`map()`][Self::map],
+    /// [`filter()`][Self::filter], [`reduce()`][Self::reduce], [`any()`][Self::any] and [`all()`][Self::all].
+    pub fn functional_ops(&self) -> ArrayFunctionalOps<'_, T> {
+        ArrayFunctionalOps { array: self }
+    }
+
     /// Asserts that the given index refers to an existing element.
     ///
     /// # Panics
@@ -695,6 +935,36 @@ impl<T: ArrayElement> Array<T> {
         );
     }
 
+    /// Asserts that the array can be modified.
+    ///
+    /// Godot silently discards writes through element pointers of read-only arrays, so this must be checked beforehand.
+    ///
+    /// # Panics
+    ///
+    /// If the array is read-only.
+    fn check_writable(&self) {
+        assert!(
+            !self.is_read_only(),
+            "Array is read-only and cannot be modified"
+        );
+    }
+
+    /// Returns all elements as a mutable slice of variants.
+    ///
+    /// # Safety
+    ///
+    /// Only values of type `T` may be written to the slice. The array must not be read-only.
+    unsafe fn as_variant_slice_mut(&mut self) -> &mut [Variant] {
+        let len = self.len();
+        if len == 0 {
+            return &mut [];
+        }
+
+        // SAFETY: The array has `len` elements, which are all valid `Variant`s. We hold `&mut self`, so no other Rust code accesses
+        // the array while the slice exists (barring `experimental-threads`).
+        unsafe { Variant::borrow_slice_mut(self.ptr_mut(0), len) }
+    }
+
     /// Returns a pointer to the element at the given index.
     ///
     /// # Panics
@@ -782,7 +1052,7 @@ impl<T: ArrayElement> Array<T> {
     /// Note also that any `GodotType` can be written to a `Variant` array.
     ///
     /// In the current implementation, both cases will produce a panic rather than undefined behavior, but this should not be relied upon.
-    unsafe fn assume_type<U: ArrayElement>(self) -> Array<U> {
+    pub(crate) unsafe fn assume_type<U: ArrayElement>(self) -> Array<U> {
         // The memory layout of `Array<T>` does not depend on `T`.
         std::mem::transmute::<Array<T>, Array<U>>(self)
     }
@@ -794,6 +1064,14 @@ impl<T: ArrayElement> Array<T> {
         std::mem::transmute::<&Array<T>, &Array<U>>(self)
     }
 
+    /// Returns the array with elements viewed as `Variant`, to access them without conversion.
+    ///
+    /// Must only be used for reading; writing non-`T` elements through the result would break the invariant of `self`.
+    pub(crate) fn as_variant_array(&self) -> &VariantArray {
+        // SAFETY: every element is internally represented as Variant. The caller only reads from the array.
+        unsafe { self.assume_type_ref::<Variant>() }
+    }
+
     #[cfg(debug_assertions)]
     pub(crate) fn debug_validate_elements(&self) -> Result<(), ConvertError> {
         // SAFETY: every element is internally represented as Variant.
@@ -1013,6 +1291,19 @@ impl<T: ArrayElement> FromGodot for Array<T> {
     }
 }
 
+impl<T: ArrayElement> meta::ParamBorrow for Array<T> {
+    unsafe fn borrow_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        _call_type: sys::PtrcallType,
+    ) -> Result<std::mem::ManuallyDrop<Self>, ConvertError> {
+        // Shallow copy without ref-count increment. Wrapped before validation, so the caller's array is not released on error.
+        let array = std::mem::ManuallyDrop::new(Self::from_opaque(std::ptr::read(arg_ptr.cast())));
+
+        T::debug_validate_elements(&array)?;
+        Ok(array)
+    }
+}
+
 impl<T: ArrayElement> fmt::Debug for Array<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // Going through `Variant` because there doesn't seem to be a direct way.
@@ -1097,6 +1388,19 @@ where
             PropertyHintInfo::export_array_element::<T>()
         }
     }
+
+    fn as_node_class() -> Option<ClassName> {
+        // Nodes inside arrays are subject to the same restrictions as direct node exports.
+        T::as_node_class()
+    }
+
+    fn export_element_hint(hint: PropertyHintInfo) -> PropertyHintInfo {
+        if Self::has_variant_t() {
+            hint
+        } else {
+            PropertyHintInfo::export_array_element_with_hint::<T>(hint)
+        }
+    }
 }
 
 impl<T: ArrayElement> Default for Array<T> {
@@ -1275,6 +1579,64 @@ impl<T: ArrayElement + FromGodot> From<&Array<T>> for Vec<T> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+/// Godot's functional-programming methods on an [`Array`], taking [`Callable`]s.
+///
+/// Obtained via [`Array::functional_ops()`].
+pub struct ArrayFunctionalOps<'a, T: ArrayElement> {
+    array: &'a Array<T>,
+}
+
+impl<T: ArrayElement> ArrayFunctionalOps<'_, T> {
+    /// Returns a new array with all elements for which `callable` returns `true`.
+    ///
+    /// The callable receives one element and must return a `bool`.
+    ///
+    /// _Godot equivalent: `Array.filter(callable)`_
+    #[must_use]
+    pub fn filter(&self, callable: &Callable) -> Array<T> {
+        let filtered = self.array.as_inner().filter(callable);
+
+        // SAFETY: filter() returns an array with the same type as the source array.
+        unsafe { filtered.assume_type() }
+    }
+
+    /// Returns a new untyped array with the results of calling `callable` on each element.
+    ///
+    /// The callable receives one element and may return any `Variant`.
+    ///
+    /// _Godot equivalent: `Array.map(callable)`_
+    #[must_use]
+    pub fn map(&self, callable: &Callable) -> VariantArray {
+        self.array.as_inner().map(callable)
+    }
+
+    /// Folds all elements into a single value, starting with `initial`.
+    ///
+    /// The callable receives the accumulator and one element, and returns the new accumulator.
+    ///
+    /// _Godot equivalent: `Array.reduce(callable, initial)`_
+    #[must_use]
+    pub fn reduce(&self, callable: &Callable, initial: &Variant) -> Variant {
+        self.array.as_inner().reduce(callable, initial)
+    }
+
+    /// Returns `true` if `callable` returns `true` for at least one element.
+    ///
+    /// _Godot equivalent: `Array.any(callable)`_
+    pub fn any(&self, callable: &Callable) -> bool {
+        self.array.as_inner().any(callable)
+    }
+
+    /// Returns `true` if `callable` returns `true` for all elements.
+    ///
+    /// _Godot equivalent: `Array.all(callable)`_
+    pub fn all(&self, callable: &Callable) -> bool {
+        self.array.as_inner().all(callable)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// An iterator over typed elements of an [`Array`].
 pub struct Iter<'a, T: ArrayElement> {
     array: &'a Array<T>,
@@ -1307,6 +1669,64 @@ impl<T: ArrayElement + FromGodot> Iterator for Iter<'_, T> {
     }
 }
 
+/// An iterator over consecutive sub-arrays of an [`Array`], created by [`Array::chunks()`].
+pub struct Chunks<'a, T: ArrayElement> {
+    array: &'a Array<T>,
+    chunk_size: usize,
+    next_idx: usize,
+}
+
+impl<T: ArrayElement> Iterator for Chunks<'_, T> {
+    type Item = Array<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = self.array.len();
+        if self.next_idx >= len {
+            return None;
+        }
+
+        let begin = self.next_idx;
+        let end = begin.saturating_add(self.chunk_size).min(len);
+        self.next_idx = end;
+
+        Some(self.array.subarray_shallow(begin, end, None))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.array.len().saturating_sub(self.next_idx);
+        let chunks = remaining.div_ceil(self.chunk_size);
+        (chunks, Some(chunks))
+    }
+}
+
+/// Converts a Rust range into `(start, end)` indices, with `end` exclusive.
+///
+/// # Panics
+///
+/// If the range is decreasing or exceeds `len`.
+fn to_start_end(range: impl ops::RangeBounds<usize>, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        ops::Bound::Included(&n) => n,
+        ops::Bound::Excluded(&n) => n
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("range start ({n}, exclusive) out of bounds (len {len})")),
+        ops::Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        ops::Bound::Included(&n) => n
+            .checked_add(1)
+            .unwrap_or_else(|| panic!("range end ({n}, inclusive) out of bounds (len {len})")),
+        ops::Bound::Excluded(&n) => n,
+        ops::Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "range start ({start}) > end ({end})");
+    assert!(end <= len, "range end ({end}) out of bounds (len {len})");
+
+    (start, end)
+}
+
 // TODO There's a macro for this, but it doesn't support generics yet; add support and use it
 impl<T: ArrayElement> PartialEq for Array<T> {
     #[inline]
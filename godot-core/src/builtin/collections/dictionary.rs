@@ -7,20 +7,46 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, Variant, VariantArray};
-use crate::meta::{FromGodot, ToGodot};
+use crate::builtin::{inner, Array, ReadOnlyDictionary, Variant, VariantArray};
+use crate::meta;
+use crate::meta::error::{ConvertError, FromVariantError};
+use crate::meta::{
+    ArrayElement, ArrayTypeInfo, AsArg, CowArg, DictionaryArg, DictionaryTypeInfo, FromGodot,
+    GodotConvert, GodotFfiVariant, GodotType, ParamType, PropertyHintInfo, RefArg, ToGodot,
+};
+use crate::registry::property::{Export, Var};
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, GodotFfi};
 
 use std::marker::PhantomData;
 use std::{fmt, ptr};
 
+#[cfg(since_api = "4.4")]
+use crate::builtin::VariantType;
+#[cfg(since_api = "4.4")]
+use crate::meta::error::FromGodotError;
+
 /// Godot's `Dictionary` type.
 ///
-/// The keys and values of the dictionary are all `Variant`s, so they can be of different types.
-/// Variants are designed to be generally cheap to clone.
+/// Godot's `Dictionary` can be either typed or untyped.
+///
+/// An untyped dictionary can contain any kind of [`Variant`] as key or value, even different types in the same dictionary. We represent
+/// this in Rust as [`Dictionary`], which is just a type alias for `TypedDictionary<Variant, Variant>`. Its keys and values can be passed
+/// as any [`ToGodot`] type.
+///
+/// Typed dictionaries restrict keys to type `K` and values to type `V`, which must implement [`ArrayElement`]. Either of them may be
+/// `Variant`, which leaves that side untyped. Keys and values are converted from/to `K` and `V` on access. `TypedDictionary<K, V>` is to
+/// `Dictionary` what [`Array<T>`] is to [`VariantArray`].
+///
+/// # Typing in Godot
 ///
-/// # Dictionary example
+/// Since Godot 4.4, dictionaries carry their key and value types at runtime, e.g. `Dictionary[String, int]` in GDScript. New typed
+/// dictionaries are created with the corresponding runtime type, and conversions from untyped or differently typed dictionaries fail.
+///
+/// In earlier versions, Godot only knows untyped dictionaries. Conversions to typed dictionaries then check that each key and value is
+/// convertible to `K` and `V`.
+///
+/// # Untyped dictionary example
 ///
 /// ```no_run
 /// # use godot::prelude::*;
@@ -67,6 +93,22 @@ use std::{fmt, ptr};
 /// assert_eq!(dict.get("num"), None);
 /// ```
 ///
+/// # Typed dictionary example
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// let mut scores = TypedDictionary::<GString, i64>::new();
+/// scores.set("Alice", 42);
+/// scores.set("Bob", 17);
+///
+/// let alice: Option<i64> = scores.get("Alice");
+/// assert_eq!(alice, Some(42));
+///
+/// for (name, score) in scores.iter_shared() {
+///     println!("{name}: {score}");
+/// }
+/// ```
+///
 /// # Thread safety
 ///
 /// The same principles apply as for [`VariantArray`]. Consult its documentation for details.
@@ -74,13 +116,23 @@ use std::{fmt, ptr};
 /// # Godot docs
 ///
 /// [`Dictionary` (stable)](https://docs.godotengine.org/en/stable/classes/class_dictionary.html)
-pub struct Dictionary {
+pub struct TypedDictionary<K: ArrayElement, V: ArrayElement> {
+    // Safety Invariant: The types of all keys and values in `opaque` match the types `K` and `V`.
     opaque: OpaqueDictionary,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl Dictionary {
+/// A Godot `Dictionary` without assigned key and value types.
+///
+/// See [`TypedDictionary`] for the full API and examples.
+pub type Dictionary = TypedDictionary<Variant, Variant>;
+
+impl<K: ArrayElement, V: ArrayElement> TypedDictionary<K, V> {
     fn from_opaque(opaque: OpaqueDictionary) -> Self {
-        Self { opaque }
+        Self {
+            opaque,
+            _phantom: PhantomData,
+        }
     }
 
     /// Constructs an empty `Dictionary`.
@@ -95,14 +147,12 @@ impl Dictionary {
     /// # Panics
     ///
     /// If there is no value for the given key. Note that this is distinct from a `NIL` value, which is returned as `Variant::nil()`.
-    pub fn at<K: ToGodot>(&self, key: K) -> Variant {
-        // Code duplication with get(), to avoid third clone (since K: ToGodot takes ownership).
+    pub fn at<A>(&self, key: impl DictionaryArg<K, A>) -> V {
+        let key = Self::key_to_variant(key);
 
-        let key = key.to_variant();
-        if self.contains_key(key.clone()) {
-            self.get_or_nil(key)
-        } else {
-            panic!("key {key:?} missing in dictionary: {self:?}")
+        match self.get_variant(&key) {
+            Some(value) => V::from_variant(&value),
+            None => panic!("key {key:?} missing in dictionary: {self:?}"),
         }
     }
 
@@ -114,15 +164,10 @@ impl Dictionary {
     /// When you are certain that a key is present, use [`at()`][`Self::at`] instead.
     ///
     /// This can be combined with Rust's `Option` methods, e.g. `dict.get(key).unwrap_or(default)`.
-    pub fn get<K: ToGodot>(&self, key: K) -> Option<Variant> {
-        // If implementation is changed, make sure to update at().
+    pub fn get<A>(&self, key: impl DictionaryArg<K, A>) -> Option<V> {
+        let key = Self::key_to_variant(key);
 
-        let key = key.to_variant();
-        if self.contains_key(key.clone()) {
-            Some(self.get_or_nil(key))
-        } else {
-            None
-        }
+        self.get_variant(&key).map(|value| V::from_variant(&value))
     }
 
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
@@ -133,25 +178,25 @@ impl Dictionary {
     /// When you are certain that a key is present, use [`at()`][`Self::at`] instead.
     ///
     /// _Godot equivalent: `dict.get(key, null)`_
-    pub fn get_or_nil<K: ToGodot>(&self, key: K) -> Variant {
-        self.as_inner().get(&key.to_variant(), &Variant::nil())
+    pub fn get_or_nil<A>(&self, key: impl DictionaryArg<K, A>) -> Variant {
+        self.as_inner()
+            .get(&Self::key_to_variant(key), &Variant::nil())
     }
 
     /// Returns `true` if the dictionary contains the given key.
     ///
     /// _Godot equivalent: `has`_
     #[doc(alias = "has")]
-    pub fn contains_key<K: ToGodot>(&self, key: K) -> bool {
-        let key = key.to_variant();
-        self.as_inner().has(&key)
+    pub fn contains_key<A>(&self, key: impl DictionaryArg<K, A>) -> bool {
+        self.as_inner().has(&Self::key_to_variant(key))
     }
 
     /// Returns `true` if the dictionary contains all the given keys.
     ///
     /// _Godot equivalent: `has_all`_
     #[doc(alias = "has_all")]
-    pub fn contains_all_keys(&self, keys: &VariantArray) -> bool {
-        self.as_inner().has_all(keys)
+    pub fn contains_all_keys(&self, keys: &Array<K>) -> bool {
+        self.as_inner().has_all(keys.as_variant_array())
     }
 
     /// Returns the number of entries in the dictionary.
@@ -176,18 +221,23 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `find_key`_
     #[doc(alias = "find_key")]
-    pub fn find_key_by_value<V: ToGodot>(&self, value: V) -> Option<Variant> {
-        let key = self.as_inner().find_key(&value.to_variant());
+    pub fn find_key_by_value<B>(&self, value: impl DictionaryArg<V, B>) -> Option<K> {
+        let key = self.as_inner().find_key(&Self::value_to_variant(value));
 
-        if !key.is_nil() || self.contains_key(key.clone()) {
-            Some(key)
+        if !key.is_nil() || self.as_inner().has(&key) {
+            Some(K::from_variant(&key))
         } else {
             None
         }
     }
 
     /// Removes all key-value pairs from the dictionary.
+    ///
+    /// # Panics
+    ///
+    /// If the dictionary is read-only.
     pub fn clear(&mut self) {
+        self.check_writable();
         self.as_inner().clear()
     }
 
@@ -196,23 +246,35 @@ impl Dictionary {
     /// If you are interested in the previous value, use [`insert()`][Self::insert] instead.
     ///
     /// _Godot equivalent: `dict[key] = value`_
-    pub fn set<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) {
-        let key = key.to_variant();
+    ///
+    /// # Panics
+    ///
+    /// If the dictionary is read-only.
+    pub fn set<A, B>(&mut self, key: impl DictionaryArg<K, A>, value: impl DictionaryArg<V, B>) {
+        self.check_writable();
+        let key = Self::key_to_variant(key);
 
-        // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
-        unsafe {
-            value.to_variant().move_into_var_ptr(self.get_ptr_mut(key));
-        }
+        self.set_variant(&key, Self::value_to_variant(value));
     }
 
     /// Insert a value at the given key, returning the previous value for that key (if available).
     ///
     /// If you don't need the previous value, use [`set()`][Self::set] instead.
+    ///
+    /// # Panics
+    ///
+    /// If the dictionary is read-only.
     #[must_use]
-    pub fn insert<K: ToGodot, V: ToGodot>(&mut self, key: K, value: V) -> Option<Variant> {
-        let key = key.to_variant();
-        let old_value = self.get(key.clone());
-        self.set(key, value);
+    pub fn insert<A, B>(
+        &mut self,
+        key: impl DictionaryArg<K, A>,
+        value: impl DictionaryArg<V, B>,
+    ) -> Option<V> {
+        self.check_writable();
+        let key = Self::key_to_variant(key);
+        let old_value = self.get_variant(&key).map(|value| V::from_variant(&value));
+
+        self.set_variant(&key, Self::value_to_variant(value));
         old_value
     }
 
@@ -220,10 +282,15 @@ impl Dictionary {
     /// the key if the key was in the dictionary.
     ///
     /// _Godot equivalent: `erase`_
+    ///
+    /// # Panics
+    ///
+    /// If the dictionary is read-only.
     #[doc(alias = "erase")]
-    pub fn remove<K: ToGodot>(&mut self, key: K) -> Option<Variant> {
-        let key = key.to_variant();
-        let old_value = self.get(key.clone());
+    pub fn remove<A>(&mut self, key: impl DictionaryArg<K, A>) -> Option<V> {
+        self.check_writable();
+        let key = Self::key_to_variant(key);
+        let old_value = self.get_variant(&key).map(|value| V::from_variant(&value));
         self.as_inner().erase(&key);
         old_value
     }
@@ -238,16 +305,16 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `keys`_
     #[doc(alias = "keys")]
-    pub fn keys_array(&self) -> VariantArray {
-        self.as_inner().keys()
+    pub fn keys_array(&self) -> Array<K> {
+        typed_array(self.as_inner().keys())
     }
 
     /// Creates a new `Array` containing all the values currently in the dictionary.
     ///
     /// _Godot equivalent: `values`_
     #[doc(alias = "values")]
-    pub fn values_array(&self) -> VariantArray {
-        self.as_inner().values()
+    pub fn values_array(&self) -> Array<V> {
+        typed_array(self.as_inner().values())
     }
 
     /// Copies all keys and values from `other` into `self`.
@@ -255,9 +322,14 @@ impl Dictionary {
     /// If `overwrite` is true, it will overwrite pre-existing keys.
     ///
     /// _Godot equivalent: `merge`_
+    ///
+    /// # Panics
+    ///
+    /// If the dictionary is read-only.
     #[doc(alias = "merge")]
     pub fn extend_dictionary(&mut self, other: &Self, overwrite: bool) {
-        self.as_inner().merge(other, overwrite)
+        self.check_writable();
+        self.as_inner().merge(other.as_untyped(), overwrite)
     }
 
     /// Deep copy, duplicating nested collections.
@@ -270,7 +342,10 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(true)`_
     pub fn duplicate_deep(&self) -> Self {
-        self.as_inner().duplicate(true)
+        let duplicate = self.as_inner().duplicate(true);
+
+        // SAFETY: duplicate() returns a dictionary with the same keys, values and runtime type as `self`.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Shallow copy, copying elements but sharing nested collections.
@@ -283,50 +358,238 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(false)`_
     pub fn duplicate_shallow(&self) -> Self {
-        self.as_inner().duplicate(false)
+        let duplicate = self.as_inner().duplicate(false);
+
+        // SAFETY: duplicate() returns a dictionary with the same keys, values and runtime type as `self`.
+        unsafe { duplicate.assume_type() }
+    }
+
+    /// Returns `true` if the dictionary is read-only. See [`make_read_only()`][Self::make_read_only].
+    pub fn is_read_only(&self) -> bool {
+        self.as_inner().is_read_only()
+    }
+
+    /// Makes the dictionary read-only, i.e. disables modification of its keys and values.
+    ///
+    /// This cannot be undone; however, duplicates of the dictionary are writable again. Methods modifying the dictionary panic
+    /// afterwards.
+    ///
+    /// In GDScript, dictionaries are automatically read-only if declared with the `const` keyword.
+    pub fn make_read_only(&mut self) {
+        self.as_inner().make_read_only()
+    }
+
+    /// Makes the dictionary read-only and returns it as a [`ReadOnlyDictionary`], which statically prevents modification.
+    ///
+    /// Other references to the same dictionary (obtained through [`clone()`][Clone::clone]) observe the read-only state as well.
+    pub fn into_read_only(mut self) -> ReadOnlyDictionary<K, V> {
+        self.make_read_only();
+        ReadOnlyDictionary::from_read_only(self)
     }
 
     /// Returns an iterator over the key-value pairs of the `Dictionary`.
     ///
-    /// The pairs are each of type `(Variant, Variant)`. Each pair references the original `Dictionary`, but instead of a `&`-reference
-    /// to key-value pairs as you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
+    /// The pairs are each of type `(K, V)`, i.e. `(Variant, Variant)` for untyped dictionaries. Each pair references the original
+    /// `Dictionary`, but instead of a `&`-reference to key-value pairs as you might expect, the iterator returns a (cheap, shallow) copy
+    /// of each key-value pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
-    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over `(K, V)` pairs instead.
-    pub fn iter_shared(&self) -> Iter<'_> {
+    /// Use `dict.iter_shared().typed::<K, V>()` to iterate over untyped dictionaries as `(K, V)` pairs instead.
+    pub fn iter_shared(&self) -> Iter<'_, K, V> {
         Iter::new(self)
     }
 
     /// Returns an iterator over the keys in a `Dictionary`.
     ///
-    /// The keys are each of type `Variant`. Each key references the original `Dictionary`, but instead of a `&`-reference to keys pairs
-    /// as you might expect, the iterator returns a (cheap, shallow) copy of each key pair.
+    /// The keys are each of type `K`, i.e. `Variant` for untyped dictionaries. Each key references the original `Dictionary`, but
+    /// instead of a `&`-reference to keys pairs as you might expect, the iterator returns a (cheap, shallow) copy of each key pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating over it. This will not result in
     /// unsoundness or crashes, but will cause the iterator to behave in an unspecified way.
     ///
-    /// Use `dict.keys_shared().typed::<K>()` to iterate over `K` keys instead.
-    pub fn keys_shared(&self) -> Keys<'_> {
+    /// Use `dict.keys_shared().typed::<K>()` to iterate over keys of untyped dictionaries as `K` instead.
+    pub fn keys_shared(&self) -> Keys<'_, K> {
         Keys::new(self)
     }
 
+    /// Returns the dictionary with keys and values viewed as `Variant`, sharing the same data.
+    ///
+    /// Writing entries of other types than `K` and `V` through it is a logic error and causes panics on later access through `self`.
+    /// Since Godot 4.4, the engine itself rejects such entries.
+    pub fn as_untyped(&self) -> &Dictionary {
+        // SAFETY: the memory layout of `TypedDictionary<K, V>` does not depend on `K` and `V`; all entries are internally stored as `Variant`.
+        unsafe { self.assume_type_ref() }
+    }
+
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerDictionary {
-        inner::InnerDictionary::from_outer(self)
+        inner::InnerDictionary::from_outer(self.as_untyped())
+    }
+
+    /// Changes the generic types of this dictionary, without changing its contents.
+    ///
+    /// # Safety
+    /// Keys and values of the dictionary must be convertible to `K2` and `V2`, and its runtime type must match them.
+    unsafe fn assume_type<K2: ArrayElement, V2: ArrayElement>(self) -> TypedDictionary<K2, V2> {
+        // The memory layout of `TypedDictionary<K, V>` does not depend on `K` and `V`.
+        std::mem::transmute::<TypedDictionary<K, V>, TypedDictionary<K2, V2>>(self)
+    }
+
+    /// # Safety
+    /// See [`assume_type`](Self::assume_type).
+    unsafe fn assume_type_ref<K2: ArrayElement, V2: ArrayElement>(
+        &self,
+    ) -> &TypedDictionary<K2, V2> {
+        // The memory layout of `TypedDictionary<K, V>` does not depend on `K` and `V`.
+        std::mem::transmute::<&TypedDictionary<K, V>, &TypedDictionary<K2, V2>>(self)
+    }
+
+    /// Panics if the dictionary is read-only. Godot would otherwise silently discard writes through `get_ptr_mut()`.
+    fn check_writable(&self) {
+        assert!(
+            !self.is_read_only(),
+            "Dictionary is read-only and cannot be modified"
+        );
+    }
+
+    fn get_variant(&self, key: &Variant) -> Option<Variant> {
+        let inner = self.as_inner();
+
+        if inner.has(key) {
+            Some(inner.get(key, &Variant::nil()))
+        } else {
+            None
+        }
+    }
+
+    fn set_variant(&mut self, key: &Variant, value: Variant) {
+        // SAFETY: `self.get_ptr_mut(key)` always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
+        unsafe {
+            value.move_into_var_ptr(self.get_ptr_mut(key));
+        }
     }
 
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
     /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
-    fn get_ptr_mut<K: ToGodot>(&mut self, key: K) -> sys::GDExtensionVariantPtr {
-        let key = key.to_variant();
-
+    fn get_ptr_mut(&mut self, key: &Variant) -> sys::GDExtensionVariantPtr {
         // Never a null pointer, since entry either existed already or was inserted above.
         // SAFETY: accessing an unknown key _mutably_ creates that entry in the dictionary, with value `NIL`.
         unsafe { interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys()) }
     }
+
+    fn key_to_variant<A>(key: impl DictionaryArg<K, A>) -> Variant {
+        key.into_dictionary_variant()
+    }
+
+    fn value_to_variant<B>(value: impl DictionaryArg<V, B>) -> Variant {
+        value.into_dictionary_variant()
+    }
+
+    /// Whether both keys and values are `Variant` (compile-time check).
+    fn has_variant_kv() -> bool {
+        !DictionaryTypeInfo::of::<K, V>().is_typed()
+    }
+
+    /// Sets the key and value types of the inner dictionary.
+    ///
+    /// # Safety
+    ///
+    /// Must only be called once, directly after creation.
+    #[cfg(since_api = "4.4")]
+    unsafe fn init_inner_type(&mut self) {
+        use crate::builtin::StringName;
+
+        debug_assert!(self.is_empty());
+
+        let type_info = DictionaryTypeInfo::of::<K, V>();
+        if !type_info.is_typed() {
+            return;
+        }
+
+        let script = Variant::nil();
+        let empty_class_name = StringName::default();
+        let key_class = type_info.key.class_name().unwrap_or(&empty_class_name);
+        let value_class = type_info.value.class_name().unwrap_or(&empty_class_name);
+
+        // SAFETY: The dictionary is a newly created, empty and untyped dictionary. Class names are empty for non-object types.
+        unsafe {
+            interface_fn!(dictionary_set_typed)(
+                self.sys_mut(),
+                type_info.key.variant_type().sys(),
+                key_class.string_sys(),
+                script.var_sys(),
+                type_info.value.variant_type().sys(),
+                value_class.string_sys(),
+                script.var_sys(),
+            );
+        }
+    }
+
+    // Godot does not support typed dictionaries before 4.4.
+    #[cfg(before_api = "4.4")]
+    unsafe fn init_inner_type(&mut self) {}
+
+    /// Checks that this dictionary can be used with keys `K` and values `V`.
+    #[cfg(since_api = "4.4")]
+    fn check_type(&self) -> Result<(), ConvertError> {
+        // Untyped dictionaries accept typed ones, which can be read and written through `Variant`.
+        if Self::has_variant_kv() {
+            return Ok(());
+        }
+
+        let actual = self.type_info();
+        let expected = DictionaryTypeInfo::of::<K, V>();
+
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(FromGodotError::BadDictionaryType { expected, actual }.into_error(self.clone()))
+        }
+    }
+
+    /// Checks that this dictionary can be used with keys `K` and values `V`.
+    ///
+    /// Without runtime type information, every entry is converted.
+    #[cfg(before_api = "4.4")]
+    fn check_type(&self) -> Result<(), ConvertError> {
+        if Self::has_variant_kv() {
+            return Ok(());
+        }
+
+        for (key, value) in self.as_untyped().iter_shared() {
+            key.try_to::<K>()?;
+            value.try_to::<V>()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the runtime type info of this dictionary.
+    #[cfg(since_api = "4.4")]
+    fn type_info(&self) -> DictionaryTypeInfo {
+        let inner = self.as_inner();
+
+        let key_type =
+            VariantType::from_sys(inner.get_typed_key_builtin() as sys::GDExtensionVariantType);
+        let value_type =
+            VariantType::from_sys(inner.get_typed_value_builtin() as sys::GDExtensionVariantType);
+
+        DictionaryTypeInfo {
+            key: ArrayTypeInfo {
+                variant_type: key_type,
+                class_name: (key_type == VariantType::OBJECT)
+                    .then(|| inner.get_typed_key_class_name()),
+            },
+            value: ArrayTypeInfo {
+                variant_type: value_type,
+                class_name: (value_type == VariantType::OBJECT)
+                    .then(|| inner.get_typed_value_class_name()),
+            },
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -341,7 +604,7 @@ impl Dictionary {
 //   Dictionaries are properly initialized through a `from_sys` call, but the ref-count should be
 //   incremented as that is the callee's responsibility. Which we do by calling
 //   `std::mem::forget(dictionary.clone())`.
-unsafe impl GodotFfi for Dictionary {
+unsafe impl<K: ArrayElement, V: ArrayElement> GodotFfi for TypedDictionary<K, V> {
     fn variant_type() -> sys::VariantType {
         sys::VariantType::DICTIONARY
     }
@@ -349,29 +612,75 @@ unsafe impl GodotFfi for Dictionary {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque; .. }
 }
 
-crate::meta::impl_godot_as_self!(Dictionary);
+// Only implement for untyped dictionaries; typed dictionaries cannot be nested in Godot.
+impl ArrayElement for Dictionary {}
+
+impl<'r, K: ArrayElement, V: ArrayElement> AsArg<TypedDictionary<K, V>>
+    for &'r TypedDictionary<K, V>
+{
+    fn into_arg<'cow>(self) -> CowArg<'cow, TypedDictionary<K, V>>
+    where
+        'r: 'cow, // Original reference must be valid for at least as long as the returned cow.
+    {
+        CowArg::Borrowed(self)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> ParamType for TypedDictionary<K, V> {
+    type Arg<'v> = CowArg<'v, Self>;
 
-impl_builtin_traits! {
-    for Dictionary {
-        Default => dictionary_construct_default;
-        Drop => dictionary_destroy;
-        PartialEq => dictionary_operator_equal;
-        // No < operator for dictionaries.
-        // Hash could be added, but without Eq it's not that useful.
+    fn owned_to_arg<'v>(self) -> Self::Arg<'v> {
+        CowArg::Owned(self)
     }
+
+    fn arg_to_ref<'r>(arg: &'r Self::Arg<'_>) -> &'r Self {
+        arg.cow_as_ref()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotConvert for TypedDictionary<K, V> {
+    type Via = Self;
 }
 
-impl fmt::Debug for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> ToGodot for TypedDictionary<K, V> {
+    type ToVia<'v> = Self::Via;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.clone()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromGodot for TypedDictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        via.check_type()?;
+        Ok(via)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> meta::ParamBorrow for TypedDictionary<K, V> {
+    unsafe fn borrow_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        _call_type: sys::PtrcallType,
+    ) -> Result<std::mem::ManuallyDrop<Self>, ConvertError> {
+        // Shallow copy without ref-count increment. Wrapped before validation, so the caller's dictionary is not released on error.
+        let dict = std::mem::ManuallyDrop::new(Self::from_opaque(std::ptr::read(arg_ptr.cast())));
+
+        dict.check_type()?;
+        Ok(dict)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Debug for TypedDictionary<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.to_variant().stringify())
     }
 }
 
-impl fmt::Display for Dictionary {
+impl<K: ArrayElement, V: ArrayElement> fmt::Display for TypedDictionary<K, V> {
     /// Formats `Dictionary` to match Godot's string representation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{{ ")?;
-        for (count, (key, value)) in self.iter_shared().enumerate() {
+        for (count, (key, value)) in self.as_untyped().iter_shared().enumerate() {
             if count != 0 {
                 write!(f, ", ")?;
             }
@@ -384,9 +693,9 @@ impl fmt::Display for Dictionary {
 /// Creates a new reference to the data in this dictionary. Changes to the original dictionary will be
 /// reflected in the copy and vice versa.
 ///
-/// To create a (mostly) independent copy instead, see [`Dictionary::duplicate_shallow()`] and
-/// [`Dictionary::duplicate_deep()`].
-impl Clone for Dictionary {
+/// To create a (mostly) independent copy instead, see [`TypedDictionary::duplicate_shallow()`] and
+/// [`TypedDictionary::duplicate_deep()`].
+impl<K: ArrayElement, V: ArrayElement> Clone for TypedDictionary<K, V> {
     fn clone(&self) -> Self {
         // SAFETY: `self` is a valid dictionary, since we have a reference that keeps it alive.
         unsafe {
@@ -399,6 +708,149 @@ impl Clone for Dictionary {
     }
 }
 
+impl<K: ArrayElement, V: ArrayElement> PartialEq for TypedDictionary<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            sys::builtin_call! {
+                dictionary_operator_equal(self.sys(), other.sys(), result.sys_mut())
+            }
+            result
+        }
+    }
+}
+
+// No < operator for dictionaries.
+// Hash could be added, but without Eq it's not that useful.
+
+impl<K: ArrayElement, V: ArrayElement> Var for TypedDictionary<K, V> {
+    fn get_property(&self) -> Self::Via {
+        self.to_godot()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value);
+    }
+
+    fn var_hint() -> PropertyHintInfo {
+        // For dictionary #[var], the hint string is "String;int" etc. for typed dictionaries, and "" for untyped ones.
+        if Self::has_variant_kv() {
+            PropertyHintInfo::none()
+        } else {
+            PropertyHintInfo::var_dictionary_element::<K, V>()
+        }
+    }
+}
+
+impl<K, V> Export for TypedDictionary<K, V>
+where
+    K: ArrayElement + Export,
+    V: ArrayElement + Export,
+{
+    fn export_hint() -> PropertyHintInfo {
+        // If K == V == Variant, then we return "Dictionary" builtin type hint.
+        if Self::has_variant_kv() {
+            PropertyHintInfo::type_name::<Dictionary>()
+        } else {
+            PropertyHintInfo::export_dictionary_element::<K, V>()
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Default for TypedDictionary<K, V> {
+    #[inline]
+    fn default() -> Self {
+        let mut dict = unsafe {
+            Self::new_with_uninit(|self_ptr| {
+                let ctor = sys::builtin_fn!(dictionary_construct_default);
+                ctor(self_ptr, std::ptr::null_mut())
+            })
+        };
+
+        // SAFETY: We just created this dictionary, and haven't called `init_inner_type` before.
+        unsafe { dict.init_inner_type() };
+        dict
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Drop for TypedDictionary<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let dictionary_destroy = sys::builtin_fn!(dictionary_destroy);
+            dictionary_destroy(self.sys_mut());
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotType for TypedDictionary<K, V> {
+    type Ffi = Self;
+
+    type ToFfi<'f>
+        = RefArg<'f, TypedDictionary<K, V>>
+    where
+        Self: 'f;
+
+    fn to_ffi(&self) -> Self::ToFfi<'_> {
+        RefArg::new(self)
+    }
+
+    fn into_ffi(self) -> Self::Ffi {
+        self
+    }
+
+    fn try_from_ffi(ffi: Self::Ffi) -> Result<Self, ConvertError> {
+        Ok(ffi)
+    }
+
+    fn godot_type_name() -> String {
+        "Dictionary".to_string()
+    }
+
+    fn property_hint_info() -> PropertyHintInfo {
+        // Untyped dictionaries have no hints; typed ones use the dictionary type hint since Godot 4.4.
+        if Self::has_variant_kv() {
+            PropertyHintInfo::none()
+        } else {
+            PropertyHintInfo::var_dictionary_element::<K, V>()
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotFfiVariant for TypedDictionary<K, V> {
+    fn ffi_to_variant(&self) -> Variant {
+        unsafe {
+            Variant::new_with_var_uninit(|variant_ptr| {
+                let dictionary_to_variant = sys::builtin_fn!(dictionary_to_variant);
+                dictionary_to_variant(variant_ptr, sys::SysPtr::force_mut(self.sys()));
+            })
+        }
+    }
+
+    fn ffi_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        // First check if the variant is a dictionary. The dictionary conversion shouldn't be called otherwise.
+        if variant.get_type() != Self::variant_type() {
+            return Err(FromVariantError::BadType {
+                expected: Self::variant_type(),
+                actual: variant.get_type(),
+            }
+            .into_error(variant.clone()));
+        }
+
+        let dict = unsafe {
+            Self::new_with_uninit(|self_ptr| {
+                let dictionary_from_variant = sys::builtin_fn!(dictionary_from_variant);
+                dictionary_from_variant(self_ptr, sys::SysPtr::force_mut(variant.var_sys()));
+            })
+        };
+
+        // Then, check the key and value types of the dictionary.
+        dict.check_type()?;
+        Ok(dict)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion traits
 
@@ -423,17 +875,38 @@ where
 ///
 /// Inserts all key-value pairs from the iterator into the dictionary. Previous values for keys appearing
 /// in `iter` will be overwritten.
-impl<K: ToGodot, V: ToGodot> Extend<(K, V)> for Dictionary {
-    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
-        for (k, v) in iter.into_iter() {
-            self.set(k.to_variant(), v.to_variant())
+///
+/// Keys and values can be any `ToGodot` types. For typed dictionaries, they are converted to `K` and `V` before insertion.
+///
+/// # Panics
+///
+/// If the dictionary is read-only, or if a key or value is not convertible to `K` or `V`, respectively.
+impl<K, V, Q, R> Extend<(Q, R)> for TypedDictionary<K, V>
+where
+    K: ArrayElement,
+    V: ArrayElement,
+    Q: ToGodot,
+    R: ToGodot,
+{
+    fn extend<I: IntoIterator<Item = (Q, R)>>(&mut self, iter: I) {
+        self.check_writable();
+
+        for (key, value) in iter.into_iter() {
+            let key = entry_to_variant::<K>(key);
+            self.set_variant(&key, entry_to_variant::<V>(value));
         }
     }
 }
 
-impl<K: ToGodot, V: ToGodot> FromIterator<(K, V)> for Dictionary {
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut dict = Dictionary::new();
+impl<K, V, Q, R> FromIterator<(Q, R)> for TypedDictionary<K, V>
+where
+    K: ArrayElement,
+    V: ArrayElement,
+    Q: ToGodot,
+    R: ToGodot,
+{
+    fn from_iter<I: IntoIterator<Item = (Q, R)>>(iter: I) -> Self {
+        let mut dict = Self::new();
         dict.extend(iter);
         dict
     }
@@ -551,32 +1024,36 @@ impl<'a> DictionaryIter<'a> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Iterator over key-value pairs in a [`Dictionary`].
+/// Iterator over key-value pairs in a [`TypedDictionary`].
 ///
-/// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
-pub struct Iter<'a> {
+/// See [`TypedDictionary::iter_shared()`] for more information about iteration over dictionaries.
+pub struct Iter<'a, K: ArrayElement = Variant, V: ArrayElement = Variant> {
     iter: DictionaryIter<'a>,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl<'a> Iter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: ArrayElement, V: ArrayElement> Iter<'a, K, V> {
+    fn new(dictionary: &'a TypedDictionary<K, V>) -> Self {
         Self {
-            iter: DictionaryIter::new(dictionary),
+            iter: DictionaryIter::new(dictionary.as_untyped()),
+            _phantom: PhantomData,
         }
     }
 
-    /// Creates an iterator that converts each `(Variant, Variant)` key-value pair into a `(K, V)` key-value
+    /// Creates an iterator that converts each key-value pair into a `(K2, V2)` key-value
     /// pair, panicking upon conversion failure.
-    pub fn typed<K: FromGodot, V: FromGodot>(self) -> TypedIter<'a, K, V> {
-        TypedIter::from_untyped(self)
+    pub fn typed<K2: FromGodot, V2: FromGodot>(self) -> TypedIter<'a, K2, V2> {
+        TypedIter::from_untyped(self.iter)
     }
 }
 
-impl Iterator for Iter<'_> {
-    type Item = (Variant, Variant);
+impl<K: ArrayElement, V: ArrayElement> Iterator for Iter<'_, K, V> {
+    type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key_value()
+        self.iter
+            .next_key_value()
+            .map(|(key, value)| (K::from_variant(&key), V::from_variant(&value)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -586,39 +1063,41 @@ impl Iterator for Iter<'_> {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Iterator over keys in a [`Dictionary`].
+/// Iterator over keys in a [`TypedDictionary`].
 ///
-/// See [`Dictionary::keys_shared()`] for more information about iteration over dictionaries.
-pub struct Keys<'a> {
+/// See [`TypedDictionary::keys_shared()`] for more information about iteration over dictionaries.
+pub struct Keys<'a, K: ArrayElement = Variant> {
     iter: DictionaryIter<'a>,
+    _phantom: PhantomData<K>,
 }
 
-impl<'a> Keys<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K: ArrayElement> Keys<'a, K> {
+    fn new<V: ArrayElement>(dictionary: &'a TypedDictionary<K, V>) -> Self {
         Self {
-            iter: DictionaryIter::new(dictionary),
+            iter: DictionaryIter::new(dictionary.as_untyped()),
+            _phantom: PhantomData,
         }
     }
 
-    /// Creates an iterator that will convert each `Variant` key into a key of type `K`,
+    /// Creates an iterator that will convert each key into a key of type `K2`,
     /// panicking upon failure to convert.
-    pub fn typed<K: FromGodot>(self) -> TypedKeys<'a, K> {
-        TypedKeys::from_untyped(self)
+    pub fn typed<K2: FromGodot>(self) -> TypedKeys<'a, K2> {
+        TypedKeys::from_untyped(self.iter)
     }
 
     /// Returns an array of the keys.
-    pub fn array(self) -> VariantArray {
+    pub fn array(self) -> Array<K> {
         // Can only be called
         assert!(self.iter.is_first);
-        self.iter.dictionary.keys_array()
+        typed_array(self.iter.dictionary.as_inner().keys())
     }
 }
 
-impl Iterator for Keys<'_> {
-    type Item = Variant;
+impl<K: ArrayElement> Iterator for Keys<'_, K> {
+    type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key()
+        self.iter.next_key().map(|key| K::from_variant(&key))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -630,7 +1109,7 @@ impl Iterator for Keys<'_> {
 
 /// [`Dictionary`] iterator that converts each key-value pair into a typed `(K, V)`.
 ///
-/// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
+/// See [`TypedDictionary::iter_shared()`] for more information about iteration over dictionaries.
 pub struct TypedIter<'a, K, V> {
     iter: DictionaryIter<'a>,
     _k: PhantomData<K>,
@@ -638,9 +1117,9 @@ pub struct TypedIter<'a, K, V> {
 }
 
 impl<'a, K, V> TypedIter<'a, K, V> {
-    fn from_untyped(value: Iter<'a>) -> Self {
+    fn from_untyped(iter: DictionaryIter<'a>) -> Self {
         Self {
-            iter: value.iter,
+            iter,
             _k: PhantomData,
             _v: PhantomData,
         }
@@ -665,16 +1144,16 @@ impl<K: FromGodot, V: FromGodot> Iterator for TypedIter<'_, K, V> {
 
 /// [`Dictionary`] iterator that converts each key into a typed `K`.
 ///
-/// See [`Dictionary::iter_shared()`] for more information about iteration over dictionaries.
+/// See [`TypedDictionary::iter_shared()`] for more information about iteration over dictionaries.
 pub struct TypedKeys<'a, K> {
     iter: DictionaryIter<'a>,
     _k: PhantomData<K>,
 }
 
 impl<'a, K> TypedKeys<'a, K> {
-    fn from_untyped(value: Keys<'a>) -> Self {
+    fn from_untyped(iter: DictionaryIter<'a>) -> Self {
         Self {
-            iter: value.iter,
+            iter,
             _k: PhantomData,
         }
     }
@@ -695,6 +1174,46 @@ impl<K: FromGodot> Iterator for TypedKeys<'_, K> {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper functions
 
+/// Converts the keys or values returned by Godot into an array of their static type.
+#[cfg(since_api = "4.4")]
+fn typed_array<T: ArrayElement>(array: VariantArray) -> Array<T> {
+    // SAFETY: keys() and values() of a typed dictionary return arrays typed like its keys and values, respectively.
+    unsafe { array.assume_type() }
+}
+
+/// Converts the keys or values returned by Godot into an array of their static type.
+#[cfg(before_api = "4.4")]
+fn typed_array<T: ArrayElement>(array: VariantArray) -> Array<T> {
+    if !ArrayTypeInfo::of::<T>().is_typed() {
+        // SAFETY: untyped arrays hold Variant elements.
+        return unsafe { array.assume_type() };
+    }
+
+    // Godot returns untyped arrays; elements are known to be convertible to `T`.
+    array
+        .iter_shared()
+        .map(|element| T::from_variant(&element))
+        .collect()
+}
+
+/// Converts a key or value to `Variant`, checking that it can be stored as `T`.
+///
+/// # Panics
+///
+/// If `value` is not convertible to `T`.
+fn entry_to_variant<T: ArrayElement>(value: impl ToGodot) -> Variant {
+    let variant = value.to_variant();
+    if !ArrayTypeInfo::of::<T>().is_typed() {
+        return variant;
+    }
+
+    // Store the canonical representation of `T`, e.g. a `StringName` key even if a string was passed.
+    match variant.try_to::<T>() {
+        Ok(typed) => typed.to_variant(),
+        Err(err) => panic!("cannot insert {variant:?} into typed dictionary: {err}"),
+    }
+}
+
 fn u8_to_bool(u: u8) -> bool {
     match u {
         0 => false,
@@ -705,6 +1224,37 @@ fn u8_to_bool(u: u8) -> bool {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Dictionaries are serialized as a sequence of `(key, value)` pairs, since keys can be arbitrary variants, which many formats
+    /// (e.g. JSON) don't support as map keys.
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl Serialize for Dictionary {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_seq(self.iter_shared())
+        }
+    }
+
+    #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+    impl<'de> Deserialize<'de> for Dictionary {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let pairs = Vec::<(Variant, Variant)>::deserialize(deserializer)?;
+            Ok(pairs.into_iter().collect())
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Constructs [`Dictionary`] literals, close to Godot's own syntax.
 ///
 /// Any value can be used as a key, but to use an expression you need to surround it
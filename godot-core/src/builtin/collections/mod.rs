@@ -8,16 +8,21 @@
 mod array;
 mod dictionary;
 mod packed_array;
+mod packed_byte_io;
+mod read_only;
 
 // Re-export in godot::builtin.
 pub(crate) mod containers {
-    pub use super::array::{Array, VariantArray};
-    pub use super::dictionary::Dictionary;
+    pub use super::array::{Array, ArrayFunctionalOps, VariantArray};
+    pub use super::dictionary::{Dictionary, TypedDictionary};
     pub use super::packed_array::*;
+    pub use super::packed_byte_io::{PackedByteReader, PackedByteWriter};
+    pub use super::read_only::{ReadOnlyArray, ReadOnlyDictionary};
 }
 
 // Re-export in godot::builtin::iter.
 pub(crate) mod iterators {
+    pub use super::array::Chunks as ArrayChunks;
     pub use super::array::Iter as ArrayIter;
     pub use super::dictionary::Iter as DictIter;
     pub use super::dictionary::Keys as DictKeys;
@@ -7,8 +7,12 @@
 
 use godot_ffi as sys;
 
+use crate::builtin::variant::impl_ffi_variant;
 use crate::builtin::*;
-use crate::meta::{AsArg, ToGodot};
+use crate::classes::file_access::CompressionMode;
+use crate::meta::error::{ByteArrayError, ConvertError, FromVariantError};
+use crate::meta::{ArrayElement, AsArg, FromGodot, GodotFfiVariant, GodotType, RefArg, ToGodot};
+use crate::obj::EngineEnum;
 use std::{fmt, ops, ptr};
 use sys::types::*;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -22,6 +26,10 @@ use sys::{__GdextString, __GdextType};
 
 /// Defines and implements a single packed array type. This macro is not hygienic and is meant to
 /// be used only in the current module.
+///
+/// Besides the type itself, this provides all the plumbing for it: `Variant` and `Array` conversions, registration as property,
+/// serde support, etc. Supporting a new packed array type from Godot thus only requires another invocation of this macro, gated
+/// behind the API version that introduced it.
 macro_rules! impl_packed_array {
     (
         // Name of the type to define, e.g. `PackedByteArray`.
@@ -40,6 +48,12 @@ macro_rules! impl_packed_array {
         return_type: $IndexRetType:ty,
         // Name of constructor function from `Array` from FFI, e.g. `packed_byte_array_from_array`.
         from_array: $from_array:ident,
+        // Name of constructor function of `Array` from FFI, e.g. `array_from_packed_byte_array`.
+        to_array: $to_array:ident,
+        // Name of conversion function to `Variant` from FFI, e.g. `packed_byte_array_to_variant`.
+        to_variant: $to_variant:ident,
+        // Name of conversion function from `Variant` from FFI, e.g. `packed_byte_array_from_variant`.
+        from_variant: $from_variant:ident,
         // Name of index operator from FFI, e.g. `packed_byte_array_operator_index`.
         operator_index: $operator_index:ident,
         // Name of const index operator from FFI, e.g. `packed_byte_array_operator_index_const`.
@@ -372,6 +386,29 @@ macro_rules! impl_packed_array {
                 array
             }
 
+            /// Creates an array holding `len` elements moved from `src`, which are copied into Godot's storage exactly once.
+            ///
+            /// GDExtension cannot allocate a packed array without resizing it. Elements of plain data types (numbers, vectors,
+            /// colors) are not initialized by the resize, so they are only written by the copy. Strings and variants are
+            /// constructed by Godot and dropped before being overwritten.
+            ///
+            /// # Safety
+            ///
+            /// * Pointer must be valid slice of data with `len` size.
+            /// * Source data must not be dropped later.
+            unsafe fn from_moved_elements(src: *const $Element, len: usize) -> Self {
+                let mut array = Self::new();
+                array.resize(len);
+
+                let dst = array.ptr_mut(0);
+                if std::mem::needs_drop::<$Element>() {
+                    // Drop impl must not panic.
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(dst, len));
+                }
+                ptr::copy_nonoverlapping(src, dst, len);
+                array
+            }
+
             /// Drops all elements in `self` and replaces them with data from an array of values.
             ///
             /// # Safety
@@ -462,15 +499,13 @@ macro_rules! impl_packed_array {
                     return Self::new();
                 }
                 let len = vec.len();
-                let mut array = Self::default_with_size(len);
 
-                // SAFETY: The packed array and vector contain exactly `len` elements.
-                // The vector is forcibly set to empty, so its contents are forgotten.
+                // SAFETY: The vector contains exactly `len` elements. It is forcibly set to empty, so its contents are forgotten,
+                // while its buffer is still deallocated.
                 unsafe {
                     vec.set_len(0);
-                    array.move_from_slice(vec.as_ptr(), len);
+                    Self::from_moved_elements(vec.as_ptr(), len)
                 }
-                array
             }
         }
 
@@ -498,6 +533,7 @@ macro_rules! impl_packed_array {
         }
 
         impl_builtin_froms!($PackedArray; VariantArray => $from_array);
+        impl_builtin_froms!(VariantArray; $PackedArray => $to_array);
 
         impl fmt::Debug for $PackedArray {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -529,6 +565,21 @@ macro_rules! impl_packed_array {
         }
 
         $crate::meta::impl_godot_as_self!($PackedArray);
+        $crate::meta::impl_param_borrow!(opaque $PackedArray);
+
+        impl_ffi_variant!(ref $PackedArray, $to_variant, $from_variant);
+
+        impl $crate::meta::sealed::Sealed for $PackedArray {}
+
+        impl $crate::registry::property::Var for $PackedArray {
+            fn get_property(&self) -> Self::Via {
+                self.to_godot()
+            }
+
+            fn set_property(&mut self, value: Self::Via) {
+                *self = FromGodot::from_godot(value);
+            }
+        }
 
         impl $crate::registry::property::Export for $PackedArray {
             fn export_hint() -> $crate::meta::PropertyHintInfo {
@@ -539,13 +590,188 @@ macro_rules! impl_packed_array {
                     $crate::meta::PropertyHintInfo::type_name::<$PackedArray>()
                 }
             }
+
+            fn export_element_hint(hint: $crate::meta::PropertyHintInfo) -> $crate::meta::PropertyHintInfo {
+                if sys::GdextBuild::since_api("4.3") {
+                    $crate::meta::PropertyHintInfo::export_packed_array_element_with_hint::<$Element>(hint)
+                } else {
+                    hint
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+        impl serde::Serialize for $PackedArray {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.as_slice())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+        impl<'de> serde::Deserialize<'de> for $PackedArray {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <Vec<$Element> as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+            }
         }
     }
 }
 
+// Declares `encode_*` and `decode_*` methods of `PackedByteArray`, which delegate to Godot after checking bounds.
+macro_rules! declare_encode_decode {
+    // $Via is the type used by Godot's inner method; it could be inferred, but ensures correct type expectations.
+    ($Ty:ty, $bytes:literal, $encode_fn:ident, $decode_fn:ident, $Via:ty) => {
+        #[doc = concat!("Encodes `", stringify!($Ty), "` as ", stringify!($bytes), " byte(s) at position `byte_offset`.")]
+        ///
+        /// Returns `Err` if there is not enough space left to write the value; the array is unchanged in that case.
+        /// The array is never resized -- use [`resize()`][Self::resize] or [`writer()`][Self::writer] to grow it.
+        ///
+        #[doc = concat!("_Godot equivalent: `", stringify!($encode_fn), "`_")]
+        pub fn $encode_fn(&mut self, byte_offset: usize, value: $Ty) -> Result<(), ByteArrayError> {
+            self.check_bytes_at(byte_offset, $bytes)?;
+
+            self.as_inner().$encode_fn(to_i64(byte_offset), value as $Via);
+            Ok(())
+        }
+
+        #[doc = concat!("Decodes `", stringify!($Ty), "` from ", stringify!($bytes), " byte(s) at position `byte_offset`.")]
+        ///
+        /// Returns `Err` if there are not enough bytes left to read the value.
+        ///
+        #[doc = concat!("_Godot equivalent: `", stringify!($decode_fn), "`_")]
+        pub fn $decode_fn(&self, byte_offset: usize) -> Result<$Ty, ByteArrayError> {
+            self.check_bytes_at(byte_offset, $bytes)?;
+
+            let decoded: $Via = self.as_inner().$decode_fn(to_i64(byte_offset));
+            Ok(decoded as $Ty)
+        }
+    };
+}
+
 // Helper macro to only include specific functions in the code if the Packed*Array provides the function.
 macro_rules! impl_specific_packed_array_functions {
     (PackedByteArray) => {
+        declare_encode_decode!(u8, 1, encode_u8, decode_u8, i64);
+        declare_encode_decode!(i8, 1, encode_s8, decode_s8, i64);
+        declare_encode_decode!(u16, 2, encode_u16, decode_u16, i64);
+        declare_encode_decode!(i16, 2, encode_s16, decode_s16, i64);
+        declare_encode_decode!(u32, 4, encode_u32, decode_u32, i64);
+        declare_encode_decode!(i32, 4, encode_s32, decode_s32, i64);
+        declare_encode_decode!(u64, 8, encode_u64, decode_u64, i64);
+        declare_encode_decode!(i64, 8, encode_s64, decode_s64, i64);
+        declare_encode_decode!(f32, 2, encode_half, decode_half, f64);
+        declare_encode_decode!(f32, 4, encode_float, decode_float, f64);
+        declare_encode_decode!(f64, 8, encode_double, decode_double, f64);
+
+        /// Returns a reader over the bytes of this array, implementing [`Read`][std::io::Read], [`BufRead`][std::io::BufRead]
+        /// and [`Seek`][std::io::Seek].
+        ///
+        /// The reader borrows the array's storage directly; no bytes are copied until they are read.
+        pub fn reader(&self) -> PackedByteReader<'_> {
+            PackedByteReader::new(self)
+        }
+
+        /// Returns a writer into this array, implementing [`Write`][std::io::Write] and [`Seek`][std::io::Seek].
+        ///
+        /// The writer starts at position 0 and overwrites existing bytes; writing past the end grows the array.
+        /// Call [`clear()`][Self::clear] first if you want to replace the contents.
+        pub fn writer(&mut self) -> PackedByteWriter<'_> {
+            PackedByteWriter::new(self)
+        }
+
+        /// Returns a new array with the contents compressed using `compression_mode`.
+        ///
+        /// Returns `Err` if Godot could not compress the data. Note that the compressed array does not store the original size;
+        /// you need to keep track of it to use [`decompress()`][Self::decompress]. An empty array compresses to an empty array.
+        ///
+        /// _Godot equivalent: `compress`_
+        pub fn compress(
+            &self,
+            compression_mode: CompressionMode,
+        ) -> Result<PackedByteArray, ByteArrayError> {
+            if self.is_empty() {
+                return Ok(PackedByteArray::new());
+            }
+
+            // Compressing non-empty data never yields an empty result; Godot returns an empty array on failure.
+            let compressed = self.as_inner().compress(compression_mode.ord() as i64);
+            if compressed.is_empty() {
+                Err(ByteArrayError::compression_failed(compression_mode))
+            } else {
+                Ok(compressed)
+            }
+        }
+
+        /// Returns a new array with the contents decompressed, given the exact size of the uncompressed data.
+        ///
+        /// `compression_mode` must be the same as the one used for compression. Returns `Err` if decompression failed.
+        /// A `buffer_size` of 0 yields an empty array.
+        ///
+        /// _Godot equivalent: `decompress`_
+        pub fn decompress(
+            &self,
+            buffer_size: usize,
+            compression_mode: CompressionMode,
+        ) -> Result<PackedByteArray, ByteArrayError> {
+            // Godot rejects a zero buffer size, although the result is well-defined.
+            if buffer_size == 0 {
+                return Ok(PackedByteArray::new());
+            }
+
+            // On success, Godot returns exactly `buffer_size` bytes; on failure, an empty array.
+            let decompressed = self
+                .as_inner()
+                .decompress(to_i64(buffer_size), compression_mode.ord() as i64);
+            if decompressed.len() == buffer_size {
+                Ok(decompressed)
+            } else {
+                Err(ByteArrayError::decompression_failed(compression_mode))
+            }
+        }
+
+        /// Returns a new array with the contents decompressed, when the uncompressed size is not known.
+        ///
+        /// `max_output_size` limits the size of the result; `None` means no limit. This is not supported for
+        /// [`CompressionMode::FASTLZ`], which requires [`decompress()`][Self::decompress]. Returns `Err` if decompression failed.
+        ///
+        /// An empty array decompresses to an empty array, mirroring [`compress()`][Self::compress]. Godot reports failure only through an
+        /// empty result, so a non-empty stream that legitimately decompresses to zero bytes is also treated as an error.
+        ///
+        /// _Godot equivalent: `decompress_dynamic`_
+        pub fn decompress_dynamic(
+            &self,
+            max_output_size: Option<usize>,
+            compression_mode: CompressionMode,
+        ) -> Result<PackedByteArray, ByteArrayError> {
+            if self.is_empty() {
+                return Ok(PackedByteArray::new());
+            }
+
+            let max_output_size = max_output_size.map(to_i64).unwrap_or(-1);
+            let decompressed = self
+                .as_inner()
+                .decompress_dynamic(max_output_size, compression_mode.ord() as i64);
+            if decompressed.is_empty() {
+                Err(ByteArrayError::decompression_failed(compression_mode))
+            } else {
+                Ok(decompressed)
+            }
+        }
+
+        /// Returns `Err` unless `byte_count` bytes starting at `byte_offset` lie within the array.
+        fn check_bytes_at(
+            &self,
+            byte_offset: usize,
+            byte_count: usize,
+        ) -> Result<(), ByteArrayError> {
+            let len = self.len();
+            match byte_offset.checked_add(byte_count) {
+                Some(end) if end <= len => Ok(()),
+                _ => Err(ByteArrayError::out_of_bounds(byte_offset, byte_count, len)),
+            }
+        }
+
         /// Returns a copy of the data converted to a `PackedFloat32Array`, where each block of 4 bytes has been converted to a 32-bit float.
         ///
         /// The size of the input array must be a multiple of 4 (size of 32-bit float). The size of the new array will be `byte_array.size() / 4`.
@@ -599,6 +825,9 @@ impl_packed_array!(
     argument_type: i64,
     return_type: u8,
     from_array: packed_byte_array_from_array,
+    to_array: array_from_packed_byte_array,
+    to_variant: packed_byte_array_to_variant,
+    from_variant: packed_byte_array_from_variant,
     operator_index: packed_byte_array_operator_index,
     operator_index_const: packed_byte_array_operator_index_const,
     trait_impls: {
@@ -618,6 +847,9 @@ impl_packed_array!(
     argument_type: i64,
     return_type: i32,
     from_array: packed_int32_array_from_array,
+    to_array: array_from_packed_int32_array,
+    to_variant: packed_int32_array_to_variant,
+    from_variant: packed_int32_array_from_variant,
     operator_index: packed_int32_array_operator_index,
     operator_index_const: packed_int32_array_operator_index_const,
     trait_impls: {
@@ -637,6 +869,9 @@ impl_packed_array!(
     argument_type: i64,
     return_type: i64,
     from_array: packed_int64_array_from_array,
+    to_array: array_from_packed_int64_array,
+    to_variant: packed_int64_array_to_variant,
+    from_variant: packed_int64_array_from_variant,
     operator_index: packed_int64_array_operator_index,
     operator_index_const: packed_int64_array_operator_index_const,
     trait_impls: {
@@ -656,6 +891,9 @@ impl_packed_array!(
     argument_type: f64,
     return_type: f32,
     from_array: packed_float32_array_from_array,
+    to_array: array_from_packed_float32_array,
+    to_variant: packed_float32_array_to_variant,
+    from_variant: packed_float32_array_from_variant,
     operator_index: packed_float32_array_operator_index,
     operator_index_const: packed_float32_array_operator_index_const,
     trait_impls: {
@@ -675,6 +913,9 @@ impl_packed_array!(
     argument_type: f64,
     return_type: f64,
     from_array: packed_float64_array_from_array,
+    to_array: array_from_packed_float64_array,
+    to_variant: packed_float64_array_to_variant,
+    from_variant: packed_float64_array_from_variant,
     operator_index: packed_float64_array_operator_index,
     operator_index_const: packed_float64_array_operator_index_const,
     trait_impls: {
@@ -694,6 +935,9 @@ impl_packed_array!(
     argument_type: GString,
     return_type: __GdextString,
     from_array: packed_string_array_from_array,
+    to_array: array_from_packed_string_array,
+    to_variant: packed_string_array_to_variant,
+    from_variant: packed_string_array_from_variant,
     operator_index: packed_string_array_operator_index,
     operator_index_const: packed_string_array_operator_index_const,
     trait_impls: {
@@ -713,6 +957,9 @@ impl_packed_array!(
     argument_type: Vector2,
     return_type: __GdextType,
     from_array: packed_vector2_array_from_array,
+    to_array: array_from_packed_vector2_array,
+    to_variant: packed_vector2_array_to_variant,
+    from_variant: packed_vector2_array_from_variant,
     operator_index: packed_vector2_array_operator_index,
     operator_index_const: packed_vector2_array_operator_index_const,
     trait_impls: {
@@ -732,6 +979,9 @@ impl_packed_array!(
     argument_type: Vector3,
     return_type: __GdextType,
     from_array: packed_vector3_array_from_array,
+    to_array: array_from_packed_vector3_array,
+    to_variant: packed_vector3_array_to_variant,
+    from_variant: packed_vector3_array_from_variant,
     operator_index: packed_vector3_array_operator_index,
     operator_index_const: packed_vector3_array_operator_index_const,
     trait_impls: {
@@ -752,6 +1002,9 @@ impl_packed_array!(
     argument_type: Vector4,
     return_type: __GdextType,
     from_array: packed_vector4_array_from_array,
+    to_array: array_from_packed_vector4_array,
+    to_variant: packed_vector4_array_to_variant,
+    from_variant: packed_vector4_array_from_variant,
     operator_index: packed_vector4_array_operator_index,
     operator_index_const: packed_vector4_array_operator_index_const,
     trait_impls: {
@@ -771,6 +1024,9 @@ impl_packed_array!(
     argument_type: Color,
     return_type: __GdextType,
     from_array: packed_color_array_from_array,
+    to_array: array_from_packed_color_array,
+    to_variant: packed_color_array_to_variant,
+    from_variant: packed_color_array_from_variant,
     operator_index: packed_color_array_operator_index,
     operator_index_const: packed_color_array_operator_index_const,
     trait_impls: {
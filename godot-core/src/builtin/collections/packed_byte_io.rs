@@ -0,0 +1,158 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! `std::io` adapters for `PackedByteArray`.
+
+use std::io;
+
+use crate::builtin::PackedByteArray;
+
+/// Reads bytes from a borrowed [`PackedByteArray`], implementing [`Read`][io::Read], [`BufRead`][io::BufRead] and [`Seek`][io::Seek].
+///
+/// Created by [`PackedByteArray::reader()`]. Behaves like [`std::io::Cursor<&[u8]>`][io::Cursor]: reads advance the position, and
+/// seeking past the end is allowed, after which reads return 0 bytes.
+#[derive(Clone, Debug)]
+pub struct PackedByteReader<'a> {
+    array: &'a PackedByteArray,
+    position: u64,
+}
+
+impl<'a> PackedByteReader<'a> {
+    pub(super) fn new(array: &'a PackedByteArray) -> Self {
+        Self { array, position: 0 }
+    }
+
+    /// Current position in bytes, counted from the start of the array.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Sets the position in bytes, counted from the start of the array.
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+
+    /// Bytes from the current position until the end of the array.
+    fn remaining_slice(&self) -> &'a [u8] {
+        let slice = self.array.as_slice();
+        let start = clamp_position(self.position, slice.len());
+        &slice[start..]
+    }
+}
+
+impl io::Read for PackedByteReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.remaining_slice();
+        let count = remaining.len().min(buf.len());
+
+        buf[..count].copy_from_slice(&remaining[..count]);
+        self.position += count as u64;
+        Ok(count)
+    }
+}
+
+impl io::BufRead for PackedByteReader<'_> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.remaining_slice())
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.position += amount as u64;
+    }
+}
+
+impl io::Seek for PackedByteReader<'_> {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.position = seek_position(self.position, self.array.len(), pos)?;
+        Ok(self.position)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Writes bytes into a borrowed [`PackedByteArray`], implementing [`Write`][io::Write] and [`Seek`][io::Seek].
+///
+/// Created by [`PackedByteArray::writer()`]. Behaves like [`std::io::Cursor<&mut Vec<u8>>`][io::Cursor]: existing bytes at the current
+/// position are overwritten, and the array grows when writing past its end. If the position has been moved beyond the end, the gap is
+/// filled with zeroes.
+#[derive(Debug)]
+pub struct PackedByteWriter<'a> {
+    array: &'a mut PackedByteArray,
+    position: u64,
+}
+
+impl<'a> PackedByteWriter<'a> {
+    pub(super) fn new(array: &'a mut PackedByteArray) -> Self {
+        Self { array, position: 0 }
+    }
+
+    /// Current position in bytes, counted from the start of the array.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Sets the position in bytes, counted from the start of the array.
+    pub fn set_position(&mut self, position: u64) {
+        self.position = position;
+    }
+}
+
+impl io::Write for PackedByteWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let start = usize::try_from(self.position).map_err(|_| position_overflow())?;
+        let end = start.checked_add(buf.len()).ok_or_else(position_overflow)?;
+
+        if end > self.array.len() {
+            self.array.resize(end);
+        }
+
+        self.array.as_mut_slice()[start..end].copy_from_slice(buf);
+        self.position = end as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl io::Seek for PackedByteWriter<'_> {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        self.position = seek_position(self.position, self.array.len(), pos)?;
+        Ok(self.position)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+fn clamp_position(position: u64, len: usize) -> usize {
+    usize::try_from(position).map_or(len, |position| position.min(len))
+}
+
+/// Computes a new position like `std::io::Cursor` does: seeking before the start is an error, seeking past the end is allowed.
+fn seek_position(current: u64, len: usize, pos: io::SeekFrom) -> io::Result<u64> {
+    let (base, offset) = match pos {
+        io::SeekFrom::Start(position) => return Ok(position),
+        io::SeekFrom::End(offset) => (len as u64, offset),
+        io::SeekFrom::Current(offset) => (current, offset),
+    };
+
+    base.checked_add_signed(offset).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )
+    })
+}
+
+fn position_overflow() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "position exceeds addressable memory",
+    )
+}
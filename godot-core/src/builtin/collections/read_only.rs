@@ -0,0 +1,176 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::ops::Deref;
+
+use crate::builtin::{Array, TypedDictionary, Variant};
+use crate::meta::error::ConvertError;
+use crate::meta::{ArrayElement, FromGodot, GodotConvert, ToGodot};
+
+/// Read-only [`Array`], with modification prevented by the type system.
+///
+/// Obtained through [`Array::into_read_only()`], or by receiving it from Godot (e.g. as a `#[func]` parameter).
+///
+/// `ReadOnlyArray<T>` dereferences to `Array<T>`, giving access to all methods taking `&self`: [`get()`][Array::get],
+/// [`len()`][Array::len], [`iter_shared()`][Array::iter_shared], etc. Methods taking `&mut self` are not reachable. To obtain a writable
+/// copy, use [`duplicate_shallow()`][Array::duplicate_shallow] or [`duplicate_deep()`][Array::duplicate_deep].
+///
+/// # Conversion from Godot
+/// Arrays coming from Godot which are already read-only (e.g. GDScript `const` arrays) are referenced directly. Any other array is first
+/// copied shallowly and the copy is made read-only, so that the caller's array stays writable.
+pub struct ReadOnlyArray<T: ArrayElement> {
+    array: Array<T>,
+}
+
+impl<T: ArrayElement> ReadOnlyArray<T> {
+    /// Wraps an array that has been made read-only already.
+    pub(super) fn from_read_only(array: Array<T>) -> Self {
+        debug_assert!(array.is_read_only(), "array must be read-only");
+        Self { array }
+    }
+}
+
+impl<T: ArrayElement> Deref for ReadOnlyArray<T> {
+    type Target = Array<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.array
+    }
+}
+
+impl<T: ArrayElement> Clone for ReadOnlyArray<T> {
+    fn clone(&self) -> Self {
+        Self {
+            array: self.array.clone(),
+        }
+    }
+}
+
+impl<T: ArrayElement> PartialEq for ReadOnlyArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.array == other.array
+    }
+}
+
+impl<T: ArrayElement> fmt::Debug for ReadOnlyArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.array, f)
+    }
+}
+
+impl<T: ArrayElement + fmt::Display> fmt::Display for ReadOnlyArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.array, f)
+    }
+}
+
+impl<T: ArrayElement> GodotConvert for ReadOnlyArray<T> {
+    type Via = Array<T>;
+}
+
+impl<T: ArrayElement> ToGodot for ReadOnlyArray<T> {
+    type ToVia<'v> = Array<T>;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.array.to_godot()
+    }
+}
+
+impl<T: ArrayElement> FromGodot for ReadOnlyArray<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let array = Array::try_from_godot(via)?;
+        if array.is_read_only() {
+            Ok(Self::from_read_only(array))
+        } else {
+            Ok(array.duplicate_shallow().into_read_only())
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Read-only [`Dictionary`][crate::builtin::Dictionary], with modification prevented by the type system.
+///
+/// Obtained through [`TypedDictionary::into_read_only()`], or by receiving it from Godot (e.g. as a `#[func]` parameter).
+///
+/// `ReadOnlyDictionary<K, V>` dereferences to `TypedDictionary<K, V>`, giving access to all methods taking `&self`:
+/// [`get()`][TypedDictionary::get], [`len()`][TypedDictionary::len], [`iter_shared()`][TypedDictionary::iter_shared], etc. Methods taking
+/// `&mut self` are not reachable. To obtain a writable copy, use [`duplicate_shallow()`][TypedDictionary::duplicate_shallow] or
+/// [`duplicate_deep()`][TypedDictionary::duplicate_deep].
+///
+/// # Conversion from Godot
+/// Dictionaries coming from Godot which are already read-only (e.g. GDScript `const` dictionaries) are referenced directly. Any other
+/// dictionary is first copied shallowly and the copy is made read-only, so that the caller's dictionary stays writable.
+pub struct ReadOnlyDictionary<K: ArrayElement = Variant, V: ArrayElement = Variant> {
+    dict: TypedDictionary<K, V>,
+}
+
+impl<K: ArrayElement, V: ArrayElement> ReadOnlyDictionary<K, V> {
+    /// Wraps a dictionary that has been made read-only already.
+    pub(super) fn from_read_only(dict: TypedDictionary<K, V>) -> Self {
+        debug_assert!(dict.is_read_only(), "dictionary must be read-only");
+        Self { dict }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Deref for ReadOnlyDictionary<K, V> {
+    type Target = TypedDictionary<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dict
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> Clone for ReadOnlyDictionary<K, V> {
+    fn clone(&self) -> Self {
+        Self {
+            dict: self.dict.clone(),
+        }
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> PartialEq for ReadOnlyDictionary<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.dict == other.dict
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Debug for ReadOnlyDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.dict, f)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> fmt::Display for ReadOnlyDictionary<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.dict, f)
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> GodotConvert for ReadOnlyDictionary<K, V> {
+    type Via = TypedDictionary<K, V>;
+}
+
+impl<K: ArrayElement, V: ArrayElement> ToGodot for ReadOnlyDictionary<K, V> {
+    type ToVia<'v> = TypedDictionary<K, V>;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.dict.to_godot()
+    }
+}
+
+impl<K: ArrayElement, V: ArrayElement> FromGodot for ReadOnlyDictionary<K, V> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        let dict = TypedDictionary::try_from_godot(via)?;
+        if dict.is_read_only() {
+            Ok(Self::from_read_only(dict))
+        } else {
+            Ok(dict.duplicate_shallow().into_read_only())
+        }
+    }
+}
@@ -82,6 +82,53 @@ impl Signal {
         Error::from_godot(error as i32)
     }
 
+    /// Connects this signal to a Rust function or closure with typed parameters.
+    ///
+    /// The arguments passed on emission are converted to the declared parameter types; see [`Callable::from_typed_fn()`] for details.
+    /// `flags` are the same as in [`connect()`][Self::connect]. The created callable must be invoked from the current thread.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn some_signal() -> Signal { unimplemented!() }
+    /// let signal: Signal = some_signal();
+    /// signal.connect_fn(|amount: i32, source: GString| {
+    ///     godot_print!("Received {amount} from {source}");
+    /// }, 0);
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn connect_fn<F, Ps>(&self, rust_function: F, flags: i64) -> Error
+    where
+        F: meta::TypedFn<Ps>,
+    {
+        let name = self.name().to_string();
+        let callable = Callable::from_typed_fn(&name, rust_function);
+
+        self.connect(&callable, flags)
+    }
+
+    /// Returns a future that resolves when this signal is next emitted, yielding the signal arguments as tuple `R`.
+    ///
+    /// The future is meant to be awaited inside a task started with [`task::spawn()`][crate::task::spawn]. For signals declared with
+    /// `#[signal]`, prefer [`TypedSignal::to_future()`][crate::registry::signal::TypedSignal::to_future], which infers `R`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # fn some_signal() -> Signal { unimplemented!() }
+    /// let signal: Signal = some_signal();
+    /// godot::task::spawn(async move {
+    ///     let (amount,): (i32,) = signal.to_future().await;
+    ///     godot_print!("Received {amount}");
+    /// });
+    /// ```
+    #[cfg(since_api = "4.2")]
+    pub fn to_future<R: crate::registry::signal::ParamTuple>(
+        &self,
+    ) -> crate::task::SignalFuture<R> {
+        crate::task::SignalFuture::new(self.clone())
+    }
+
     /// Disconnects this signal from the specified [`Callable`].
     ///
     /// If the connection does not exist, generates an error. Use [`Self::is_connected`] to make sure that the connection exists.
@@ -195,6 +242,7 @@ impl_builtin_traits! {
 }
 
 crate::meta::impl_godot_as_self!(Signal);
+crate::meta::impl_param_borrow!(opaque Signal);
 
 impl fmt::Debug for Signal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -201,6 +201,7 @@ unsafe impl GodotFfi for GString {
 }
 
 meta::impl_godot_as_self!(GString);
+meta::impl_param_borrow!(transparent GString);
 
 impl_builtin_traits! {
     for GString {
@@ -199,6 +199,7 @@ unsafe impl GodotFfi for NodePath {
 }
 
 crate::meta::impl_godot_as_self!(NodePath);
+crate::meta::impl_param_borrow!(opaque NodePath);
 
 impl_builtin_traits! {
     for NodePath {
@@ -185,6 +185,7 @@ unsafe impl GodotFfi for StringName {
 }
 
 meta::impl_godot_as_self!(StringName);
+meta::impl_param_borrow!(opaque StringName);
 
 impl_builtin_traits! {
     for StringName {
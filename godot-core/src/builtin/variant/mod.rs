@@ -17,6 +17,8 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 
 mod impls;
 
+pub(crate) use impls::impl_ffi_variant;
+
 /// Godot variant type, able to store a variety of different types.
 ///
 /// While Godot variants do not appear very frequently in Rust due to their lack of compile-time type-safety, they are central to all sorts of
@@ -421,6 +423,7 @@ unsafe impl GodotFfi for Variant {
 }
 
 crate::meta::impl_godot_as_self!(Variant);
+crate::meta::impl_param_borrow!(transparent Variant);
 
 impl Default for Variant {
     fn default() -> Self {
@@ -481,3 +484,214 @@ impl fmt::Debug for Variant {
         }
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use crate::builtin::*;
+    use crate::obj::EngineEnum;
+    use serde::de::{EnumAccess, Error as _, VariantAccess, Visitor};
+    use serde::ser::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Name of the enum through which variants are represented in serde's data model.
+    const VARIANT_ENUM: &str = "Variant";
+
+    /// Declares the serde representation of `Variant`: an externally tagged enum, with the variant type as tag and the value as payload.
+    ///
+    /// The variant index is the ordinal of `VariantType`, so that compact formats (e.g. bincode) stay stable across Godot versions.
+    macro_rules! impl_variant_serde {
+        ( $( $(#[$attr:meta])* $VariantType:ident => $T:ty as $name:literal, )* ) => {
+            const VARIANT_TAGS: &[&str] = &["Nil", "Array", $( $name ),*];
+
+            fn tag_name(variant_type: VariantType) -> Option<&'static str> {
+                let name = match variant_type {
+                    VariantType::NIL => "Nil",
+                    VariantType::ARRAY => "Array",
+                    $(
+                        $(#[$attr])*
+                        VariantType::$VariantType => $name,
+                    )*
+                    _ => return None,
+                };
+
+                Some(name)
+            }
+
+            fn tag_from_name(name: &str) -> Option<VariantType> {
+                let variant_type = match name {
+                    "Nil" => VariantType::NIL,
+                    "Array" => VariantType::ARRAY,
+                    $(
+                        $(#[$attr])*
+                        $name => VariantType::$VariantType,
+                    )*
+                    _ => return None,
+                };
+
+                Some(variant_type)
+            }
+
+            #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+            impl Serialize for Variant {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    let variant_type = self.get_type();
+                    let Some(name) = tag_name(variant_type) else {
+                        return Err(S::Error::custom(format_args!(
+                            "cannot serialize Variant of type {variant_type:?}; only value types are supported"
+                        )));
+                    };
+                    let index = variant_type.ord() as u32;
+
+                    match variant_type {
+                        VariantType::NIL => serializer.serialize_unit_variant(VARIANT_ENUM, index, name),
+                        VariantType::ARRAY => {
+                            // Typed arrays cannot be converted to `VariantArray`; they are serialized (and deserialized) as untyped arrays.
+                            // SAFETY: type is checked, and the array is only read from.
+                            let array = unsafe { VariantArray::from_variant_unchecked(self) };
+                            serializer.serialize_newtype_variant(VARIANT_ENUM, index, name, &array)
+                        }
+                        $(
+                            $(#[$attr])*
+                            VariantType::$VariantType => {
+                                serializer.serialize_newtype_variant(VARIANT_ENUM, index, name, &self.to::<$T>())
+                            }
+                        )*
+                        _ => unreachable!("variant type without tag: {variant_type:?}"),
+                    }
+                }
+            }
+
+            #[cfg_attr(published_docs, doc(cfg(feature = "serde")))]
+            impl<'de> Deserialize<'de> for Variant {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    struct VariantVisitor;
+
+                    impl<'de> Visitor<'de> for VariantVisitor {
+                        type Value = Variant;
+
+                        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                            formatter.write_str("a Variant, tagged with its type")
+                        }
+
+                        fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+                        where
+                            A: EnumAccess<'de>,
+                        {
+                            let (Tag(variant_type), access) = data.variant()?;
+
+                            match variant_type {
+                                VariantType::NIL => access.unit_variant().map(|()| Variant::nil()),
+                                VariantType::ARRAY => {
+                                    access.newtype_variant::<VariantArray>().map(|value| value.to_variant())
+                                }
+                                $(
+                                    $(#[$attr])*
+                                    VariantType::$VariantType => {
+                                        access.newtype_variant::<$T>().map(|value| value.to_variant())
+                                    }
+                                )*
+                                _ => unreachable!("tag without variant type: {variant_type:?}"),
+                            }
+                        }
+                    }
+
+                    deserializer.deserialize_enum(VARIANT_ENUM, VARIANT_TAGS, VariantVisitor)
+                }
+            }
+        };
+    }
+
+    impl_variant_serde! {
+        BOOL => bool as "Bool",
+        INT => i64 as "Int",
+        FLOAT => f64 as "Float",
+        STRING => GString as "String",
+        VECTOR2 => Vector2 as "Vector2",
+        VECTOR2I => Vector2i as "Vector2i",
+        RECT2 => Rect2 as "Rect2",
+        RECT2I => Rect2i as "Rect2i",
+        VECTOR3 => Vector3 as "Vector3",
+        VECTOR3I => Vector3i as "Vector3i",
+        TRANSFORM2D => Transform2D as "Transform2D",
+        VECTOR4 => Vector4 as "Vector4",
+        VECTOR4I => Vector4i as "Vector4i",
+        PLANE => Plane as "Plane",
+        QUATERNION => Quaternion as "Quaternion",
+        AABB => Aabb as "AABB",
+        BASIS => Basis as "Basis",
+        TRANSFORM3D => Transform3D as "Transform3D",
+        PROJECTION => Projection as "Projection",
+        COLOR => Color as "Color",
+        STRING_NAME => StringName as "StringName",
+        NODE_PATH => NodePath as "NodePath",
+        DICTIONARY => Dictionary as "Dictionary",
+        PACKED_BYTE_ARRAY => PackedByteArray as "PackedByteArray",
+        PACKED_INT32_ARRAY => PackedInt32Array as "PackedInt32Array",
+        PACKED_INT64_ARRAY => PackedInt64Array as "PackedInt64Array",
+        PACKED_FLOAT32_ARRAY => PackedFloat32Array as "PackedFloat32Array",
+        PACKED_FLOAT64_ARRAY => PackedFloat64Array as "PackedFloat64Array",
+        PACKED_STRING_ARRAY => PackedStringArray as "PackedStringArray",
+        PACKED_VECTOR2_ARRAY => PackedVector2Array as "PackedVector2Array",
+        PACKED_VECTOR3_ARRAY => PackedVector3Array as "PackedVector3Array",
+        #[cfg(since_api = "4.3")]
+        PACKED_VECTOR4_ARRAY => PackedVector4Array as "PackedVector4Array",
+        PACKED_COLOR_ARRAY => PackedColorArray as "PackedColorArray",
+    }
+
+    /// Identifies the variant type, either by name (self-describing formats) or by index (compact formats).
+    struct Tag(VariantType);
+
+    impl<'de> Deserialize<'de> for Tag {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct TagVisitor;
+
+            impl Visitor<'_> for TagVisitor {
+                type Value = Tag;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a Variant type name or index")
+                }
+
+                fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    tag_from_name(name)
+                        .map(Tag)
+                        .ok_or_else(|| E::unknown_variant(name, VARIANT_TAGS))
+                }
+
+                fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+                where
+                    E: serde::de::Error,
+                {
+                    i32::try_from(index)
+                        .ok()
+                        .and_then(VariantType::try_from_ord)
+                        .filter(|variant_type| tag_name(*variant_type).is_some())
+                        .map(Tag)
+                        .ok_or_else(|| {
+                            E::invalid_value(
+                                serde::de::Unexpected::Unsigned(index),
+                                &"index of a serializable Variant type",
+                            )
+                        })
+                }
+            }
+
+            deserializer.deserialize_identifier(TagVisitor)
+        }
+    }
+}
@@ -163,8 +163,20 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    // User code has cleaned up by now; report before classes are unregistered, so that class names are still meaningful.
+    #[cfg(feature = "leak-diagnostics")]
+    if level == InitLevel::Scene {
+        crate::obj::leak_diagnostics::report_leaks();
+    }
+
     crate::registry::class::unregister_classes(level);
 
+    // Tasks may hold objects and callables; drop them while the engine is still available.
+    #[cfg(since_api = "4.2")]
+    if level == InitLevel::Scene {
+        crate::task::cleanup();
+    }
+
     if level == InitLevel::Core {
         // If lowest level is unloaded, call global deinitialization.
         // No business logic by itself, but ensures consistency if re-initialization (hot-reload on Linux) occurs.
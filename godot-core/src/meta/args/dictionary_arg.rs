@@ -0,0 +1,54 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Variant;
+use crate::meta::{arg_into_ref, ArrayElement, AsArg, ToGodot};
+
+/// Keys and values passed to [`TypedDictionary`][crate::builtin::TypedDictionary] methods.
+///
+/// An `impl DictionaryArg<T, _>` parameter accepts:
+/// - for typed keys/values `T`: everything that can be passed to an [`impl AsArg<T>`][AsArg] parameter.
+/// - for untyped keys/values (`T` being `Variant`): any [`ToGodot`] type, as well as `&Variant`.
+///
+/// The latter keeps the untyped [`Dictionary`][crate::builtin::Dictionary] API working with arbitrary keys and values, without widening
+/// `AsArg<Variant>` in other APIs.
+///
+/// The second type parameter only distinguishes the two cases and is always inferred; you never need to name it.
+#[diagnostic::on_unimplemented(
+    message = "Argument of type `{Self}` cannot be passed as a `{T}` dictionary key or value",
+    note = "Typed dictionaries accept the same arguments as `impl AsArg<{T}>` parameters."
+)]
+pub trait DictionaryArg<T, Via>: Sized {
+    #[doc(hidden)]
+    fn into_dictionary_variant(self) -> Variant;
+}
+
+/// Marker for [`DictionaryArg`] arguments passed like [`AsArg<T>`].
+#[doc(hidden)]
+pub enum ViaAsArg {}
+
+/// Marker for [`DictionaryArg`] arguments converted to `Variant` through [`ToGodot`].
+#[doc(hidden)]
+pub enum ViaToGodot {}
+
+impl<T, A> DictionaryArg<T, ViaAsArg> for A
+where
+    T: ArrayElement,
+    A: AsArg<T>,
+{
+    fn into_dictionary_variant(self) -> Variant {
+        let arg = self;
+        arg_into_ref!(arg: T);
+        arg.to_variant()
+    }
+}
+
+impl<A: ToGodot> DictionaryArg<Variant, ViaToGodot> for A {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
@@ -7,14 +7,18 @@
 
 mod as_arg;
 mod cow_arg;
+mod dictionary_arg;
 mod object_arg;
+mod param_ref;
 mod ref_arg;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Public APIs
 
 pub use as_arg::{AsArg, ParamType};
+pub use dictionary_arg::DictionaryArg;
 pub use object_arg::AsObjectArg;
+pub use param_ref::ParamBorrow;
 pub use ref_arg::RefArg;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -27,9 +31,19 @@ pub use cow_arg::CowArg;
 #[cfg(not(feature = "trace"))]
 pub(crate) use cow_arg::CowArg;
 
+// Markers only distinguishing the impls of DictionaryArg.
+#[doc(hidden)]
+pub use dictionary_arg::{ViaAsArg, ViaToGodot};
+
 #[allow(unused_imports)] // ObjectCow is used in generated code.
 pub(crate) use object_arg::{ObjectArg, ObjectCow, ObjectNullArg};
 
+// Used in generated code of #[godot_api].
+#[doc(hidden)]
+pub use param_ref::{FuncParam, ParamRef};
+
+pub(crate) use param_ref::impl_param_borrow;
+
 // #[doc(hidden)]
 // pub use cow_arg::*;
 //
@@ -0,0 +1,176 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Variant;
+use crate::meta::error::ConvertError;
+use crate::meta::godot_convert::try_from_ffi;
+use crate::meta::{FromGodot, GodotConvert, GodotType, ToGodot};
+use crate::sys;
+use godot_ffi::{GodotFfi, PtrcallType};
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+
+/// Types which `#[func]` methods can accept by shared reference, e.g. `&GString` instead of `GString`.
+///
+/// When Godot calls a method through ptrcall (e.g. from statically typed GDScript), such parameters borrow the caller's value directly,
+/// without incrementing a reference count or copying a string. Dynamic calls (varcall), for example through `Object::call()` or untyped
+/// GDScript, still convert the argument to an owned value first.
+///
+/// Besides regular `#[func]` methods, this applies to `#[func(virtual)]` methods and to the engine's `I*` virtual traits, which declare
+/// such parameters as `&T`. Nullable objects in virtual methods remain `Option<Gd<T>>` and are passed by value.
+///
+/// Implemented for [`Gd<T>`][crate::obj::Gd], [`Array<T>`][crate::builtin::Array], [`Dictionary`][crate::builtin::Dictionary],
+/// [`Variant`][crate::builtin::Variant], string types, [`Callable`][crate::builtin::Callable], [`Signal`][crate::builtin::Signal]
+/// and packed arrays. `Copy` types such as `i64` or `Vector2` are cheap to pass by value and don't need this.
+pub trait ParamBorrow: FromGodot + ToGodot + fmt::Debug {
+    /// Creates a shallow copy of the argument behind `arg_ptr`, which must not be dropped.
+    ///
+    /// # Safety
+    /// `arg_ptr` must point to a valid argument of type `Self`, passed according to `call_type`. The value must stay alive while the
+    /// returned copy is in use.
+    #[doc(hidden)]
+    unsafe fn borrow_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        call_type: PtrcallType,
+    ) -> Result<ManuallyDrop<Self>, ConvertError>;
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Conversion of incoming call arguments into parameters of user-defined functions.
+///
+/// Implemented for all [`FromGodot`] types, which receive an owned value, and for [`ParamRef<T>`], which borrows the argument if possible.
+#[doc(hidden)]
+pub trait FuncParam: Sized {
+    /// # Safety
+    /// `arg_ptr` must point to a valid argument of type `Self`, passed according to `call_type`. The value must outlive `Self`.
+    unsafe fn from_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        call_type: PtrcallType,
+    ) -> Result<Self, ConvertError>;
+
+    fn from_varcall_arg(variant: &Variant) -> Result<Self, ConvertError>;
+}
+
+impl<T: FromGodot> FuncParam for T {
+    unsafe fn from_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        call_type: PtrcallType,
+    ) -> Result<Self, ConvertError> {
+        let ffi = <T::Via as GodotType>::Ffi::from_arg_ptr(sys::force_mut_ptr(arg_ptr), call_type);
+
+        try_from_ffi(ffi)
+    }
+
+    fn from_varcall_arg(variant: &Variant) -> Result<Self, ConvertError> {
+        T::try_from_variant(variant)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Parameter of a `#[func]` declared as `&T`.
+///
+/// Used by generated code of `#[godot_api]`; in the user-facing function, it appears as `&T`. Holds either a borrowed shallow copy of
+/// the caller's value (ptrcall), or an owned value (varcall).
+#[doc(hidden)]
+pub struct ParamRef<T: ParamBorrow> {
+    value: ManuallyDrop<T>,
+
+    /// Whether `value` was converted from a variant and must be dropped, as opposed to borrowed from the caller.
+    is_owned: bool,
+}
+
+impl<T: ParamBorrow> Drop for ParamRef<T> {
+    fn drop(&mut self) {
+        if self.is_owned {
+            // SAFETY: value is owned and not accessed after this point.
+            unsafe { ManuallyDrop::drop(&mut self.value) };
+        }
+    }
+}
+
+impl<T: ParamBorrow> Deref for ParamRef<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: ParamBorrow> FuncParam for ParamRef<T> {
+    unsafe fn from_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        call_type: PtrcallType,
+    ) -> Result<Self, ConvertError> {
+        let value = T::borrow_ptrcall_arg(arg_ptr, call_type)?;
+
+        Ok(Self {
+            value,
+            is_owned: false,
+        })
+    }
+
+    fn from_varcall_arg(variant: &Variant) -> Result<Self, ConvertError> {
+        let value = T::try_from_variant(variant)?;
+
+        Ok(Self {
+            value: ManuallyDrop::new(value),
+            is_owned: true,
+        })
+    }
+}
+
+impl<T: ParamBorrow> GodotConvert for ParamRef<T> {
+    type Via = T::Via;
+}
+
+impl<T: ParamBorrow> ToGodot for ParamRef<T> {
+    type ToVia<'v>
+        = T::ToVia<'v>
+    where
+        Self: 'v;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.value.to_godot()
+    }
+}
+
+impl<T: ParamBorrow> fmt::Debug for ParamRef<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "&{:?}", *self.value)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Implements `ParamBorrow` for a builtin type, by bitwise-copying the value behind the argument pointer.
+///
+/// The `opaque` form uses the type's private `from_opaque()` constructor, so it must be invoked in the module that defines the type.
+/// The `transparent` form is for `#[repr(transparent)]` types whose sys pointer refers to `Self`.
+macro_rules! impl_param_borrow {
+    (opaque $T:ty) => {
+        $crate::meta::impl_param_borrow!(@impl $T, |ptr| Self::from_opaque(std::ptr::read(ptr.cast())));
+    };
+    (transparent $T:ty) => {
+        $crate::meta::impl_param_borrow!(@impl $T, |ptr| std::ptr::read(ptr.cast::<Self>()));
+    };
+    (@impl $T:ty, |$ptr:ident| $read:expr) => {
+        impl $crate::meta::ParamBorrow for $T {
+            unsafe fn borrow_ptrcall_arg(
+                $ptr: $crate::sys::GDExtensionConstTypePtr,
+                _call_type: $crate::sys::PtrcallType,
+            ) -> Result<std::mem::ManuallyDrop<Self>, $crate::meta::error::ConvertError> {
+                // Shallow copy without ref-count increment or string copy; ManuallyDrop prevents the destructor from running.
+                Ok(std::mem::ManuallyDrop::new($read))
+            }
+        }
+    };
+}
+
+pub(crate) use impl_param_borrow;
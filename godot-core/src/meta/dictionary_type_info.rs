@@ -0,0 +1,39 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::meta::{ArrayElement, ArrayTypeInfo};
+use std::fmt;
+
+/// Represents the type information of a Godot dictionary, i.e. the runtime types of its keys and values.
+///
+/// Key and value are each described like an array element. Godot supports typed dictionaries since version 4.4; before that,
+/// dictionaries are always untyped.
+#[derive(Eq, PartialEq)]
+pub(crate) struct DictionaryTypeInfo {
+    pub key: ArrayTypeInfo,
+    pub value: ArrayTypeInfo,
+}
+
+impl DictionaryTypeInfo {
+    pub fn of<K: ArrayElement, V: ArrayElement>() -> Self {
+        Self {
+            key: ArrayTypeInfo::of::<K>(),
+            value: ArrayTypeInfo::of::<V>(),
+        }
+    }
+
+    /// Whether either the key or the value is typed.
+    pub fn is_typed(&self) -> bool {
+        self.key.is_typed() || self.value.is_typed()
+    }
+}
+
+impl fmt::Debug for DictionaryTypeInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} => {:?}", self.key, self.value)
+    }
+}
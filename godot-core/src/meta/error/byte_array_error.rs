@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::classes::file_access::CompressionMode;
+
+/// Error returned by the encoding, decoding and compression methods of [`PackedByteArray`][crate::builtin::PackedByteArray].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ByteArrayError {
+    kind: ErrorKind,
+}
+
+impl ByteArrayError {
+    pub(crate) fn out_of_bounds(byte_offset: usize, byte_count: usize, len: usize) -> Self {
+        Self {
+            kind: ErrorKind::OutOfBounds {
+                byte_offset,
+                byte_count,
+                len,
+            },
+        }
+    }
+
+    pub(crate) fn compression_failed(mode: CompressionMode) -> Self {
+        Self {
+            kind: ErrorKind::Compression(mode),
+        }
+    }
+
+    pub(crate) fn decompression_failed(mode: CompressionMode) -> Self {
+        Self {
+            kind: ErrorKind::Decompression(mode),
+        }
+    }
+
+    /// Whether the error was caused by an access outside the array's bounds, as opposed to a failed (de)compression.
+    pub fn is_out_of_bounds(&self) -> bool {
+        matches!(self.kind, ErrorKind::OutOfBounds { .. })
+    }
+}
+
+impl fmt::Display for ByteArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::OutOfBounds {
+                byte_offset,
+                byte_count,
+                len,
+            } => write!(
+                f,
+                "cannot access {byte_count} byte(s) at offset {byte_offset} in array of length {len}"
+            ),
+            ErrorKind::Compression(mode) => write!(f, "failed to compress data with {mode:?}"),
+            ErrorKind::Decompression(mode) => {
+                write!(f, "failed to decompress data with {mode:?}")
+            }
+        }
+    }
+}
+
+impl Error for ByteArrayError {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ErrorKind {
+    OutOfBounds {
+        byte_offset: usize,
+        byte_count: usize,
+        len: usize,
+    },
+    Compression(CompressionMode),
+    Decompression(CompressionMode),
+}
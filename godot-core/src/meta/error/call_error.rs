@@ -112,16 +112,47 @@ impl CallError {
         arg_count: usize,
         param_count: usize,
     ) -> Result<(), Self> {
-        // This will need to be adjusted once optional parameters are supported in #[func].
-        if arg_count == param_count {
+        Self::check_arg_count_with_defaults(call_ctx, arg_count, 0, param_count)
+    }
+
+    /// Checks whether number of arguments is valid for `param_count` parameters, of which the last `default_count` are optional.
+    pub(crate) fn check_arg_count_with_defaults(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        default_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count <= param_count && arg_count + default_count >= param_count {
             return Ok(());
         }
 
-        let call_error = Self::failed_param_count(call_ctx, arg_count, param_count);
+        let call_error = Self::failed_param_count(call_ctx, arg_count, default_count, param_count);
 
         Err(call_error)
     }
 
+    /// Checks whether there are at least as many arguments as (fixed) parameters of a vararg function.
+    pub(crate) fn check_min_arg_count(
+        call_ctx: &CallContext,
+        arg_count: usize,
+        param_count: usize,
+    ) -> Result<(), Self> {
+        if arg_count >= param_count {
+            return Ok(());
+        }
+
+        let param_plural = plural(param_count);
+        let arg_plural = plural(arg_count);
+
+        Err(Self::new(
+            call_ctx,
+            format!(
+                "function has {param_count} fixed parameter{param_plural} and varargs, but received {arg_count} argument{arg_plural}"
+            ),
+            None,
+        ))
+    }
+
     /// Checks the Godot side of a varcall (low-level `sys::GDExtensionCallError`).
     pub(crate) fn check_out_varcall<T: ToGodot>(
         call_ctx: &CallContext,
@@ -194,6 +225,11 @@ impl CallError {
         Self::new(call_ctx, reason, None)
     }
 
+    /// Returns an error for signal arguments which cannot be converted to the parameters of a connected function.
+    pub(crate) fn failed_signal_args(call_ctx: &CallContext, convert_error: ConvertError) -> Self {
+        Self::new(call_ctx, "signal arguments conversion", Some(convert_error))
+    }
+
     /// Returns an error for a failed return type conversion.
     ///
     /// **Note:** There are probably no practical scenarios where this occurs. Different calls:
@@ -218,15 +254,21 @@ impl CallError {
     fn failed_param_count(
         call_ctx: &CallContext,
         arg_count: usize,
+        default_count: usize,
         param_count: usize,
     ) -> CallError {
         let param_plural = plural(param_count);
         let arg_plural = plural(arg_count);
+        let optional = if default_count == 0 {
+            String::new()
+        } else {
+            format!(" ({default_count} optional)")
+        };
 
         Self::new(
             call_ctx,
             format!(
-                "function has {param_count} parameter{param_plural}, but received {arg_count} argument{arg_plural}"
+                "function has {param_count} parameter{param_plural}{optional}, but received {arg_count} argument{arg_plural}"
             ),
             None,
         )
@@ -11,7 +11,7 @@ use std::fmt;
 use godot_ffi::VariantType;
 
 use crate::builtin::Variant;
-use crate::meta::{ArrayTypeInfo, ClassName, ToGodot};
+use crate::meta::{ArrayTypeInfo, ClassName, DictionaryTypeInfo, ToGodot};
 
 type Cause = Box<dyn Error + Send + Sync>;
 
@@ -82,6 +82,19 @@ impl ConvertError {
         self.value.as_ref()
     }
 
+    /// Wraps this error, which occurred while converting a single element of a collection.
+    ///
+    /// `position` describes where the element is located, e.g. `"at index 2"` or `"with key \"name\""`.
+    pub(crate) fn in_element(self, position: impl Into<String>) -> Self {
+        Self {
+            kind: ErrorKind::Element {
+                position: position.into(),
+                source: Box::new(self),
+            },
+            value: None,
+        }
+    }
+
     /// Converts error into generic error type. It is useful to send error across thread.
     /// Do note that some data might get lost during conversion.
     pub fn into_erased(self) -> impl Error + Send + Sync {
@@ -103,7 +116,7 @@ impl fmt::Display for ConvertError {
 
 impl Error for ConvertError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        self.cause().map(|v| v as &(dyn Error + 'static))
+        self.kind.source()
     }
 }
 
@@ -140,10 +153,7 @@ impl fmt::Display for ErasedConvertError {
 
 impl Error for ErasedConvertError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        match &self.kind {
-            ErrorKind::Custom(Some(cause)) => Some(&**cause),
-            _ => None,
-        }
+        self.kind.source()
     }
 }
 
@@ -153,6 +163,22 @@ pub(crate) enum ErrorKind {
     FromFfi(FromFfiError),
     FromVariant(FromVariantError),
     Custom(Option<Cause>),
+
+    /// An element of a collection (array, dictionary, tuple, ...) failed to convert.
+    Element {
+        position: String,
+        source: Box<ConvertError>,
+    },
+}
+
+impl ErrorKind {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Custom(Some(cause)) => Some(&**cause),
+            Self::Element { source, .. } => Some(&**source),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ErrorKind {
@@ -162,6 +188,9 @@ impl fmt::Display for ErrorKind {
             Self::FromVariant(from_variant) => write!(f, "{from_variant}"),
             Self::FromFfi(from_ffi) => write!(f, "{from_ffi}"),
             Self::Custom(cause) => write!(f, "{cause:?}"),
+            Self::Element { position, source } => {
+                write!(f, "element {position} failed to convert: {source}")
+            }
         }
     }
 }
@@ -179,6 +208,12 @@ pub(crate) enum FromGodotError {
     #[cfg(debug_assertions)]
     BadArrayTypeInt { expected: ArrayTypeInfo, value: i64 },
 
+    /// Destination `TypedDictionary<K, V>` has different key/value types than source's runtime type.
+    BadDictionaryType {
+        expected: DictionaryTypeInfo,
+        actual: DictionaryTypeInfo,
+    },
+
     /// InvalidEnum is also used by bitfields.
     InvalidEnum,
 
@@ -244,6 +279,12 @@ impl fmt::Display for FromGodotError {
                     "integer value {value} does not fit into Array of type {expected:?}"
                 )
             }
+            Self::BadDictionaryType { expected, actual } => {
+                write!(
+                    f,
+                    "expected dictionary of type {expected:?}, got dictionary of type {actual:?}"
+                )
+            }
             Self::InvalidEnum => write!(f, "invalid engine enum value"),
             Self::ZeroInstanceId => write!(f, "`InstanceId` cannot be 0"),
             Self::UnimplementedDynTrait {
@@ -7,10 +7,12 @@
 
 //! Errors in the gdext library.
 
+mod byte_array_error;
 mod call_error;
 mod convert_error;
 mod io_error;
 
+pub use byte_array_error::*;
 pub use call_error::*;
 pub use convert_error::*;
 pub use io_error::*;
@@ -5,7 +5,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::{Array, Variant};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
+
+use crate::builtin::{Array, Dictionary, Variant, VariantArray, VariantType};
 use crate::meta::error::{ConvertError, ErrorKind, FromFfiError, FromVariantError};
 use crate::meta::{
     ArrayElement, ClassName, FromGodot, GodotConvert, GodotNullableFfi, GodotType,
@@ -374,7 +377,11 @@ impl<T: ArrayElement> ToGodot for Vec<T> {
 
 impl<T: ArrayElement> FromGodot for Vec<T> {
     fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
-        Ok(via.iter_shared().collect())
+        try_collect_array_elements(via.as_variant_array())
+    }
+
+    fn try_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        try_collect_array_elements(&variant_to_any_array(variant)?)
     }
 }
 
@@ -392,29 +399,11 @@ impl<T: ArrayElement, const LEN: usize> ToGodot for [T; LEN] {
 
 impl<T: ArrayElement, const LEN: usize> FromGodot for [T; LEN] {
     fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
-        let via_len = via.len(); // Caching this avoids an FFI call
-        if via_len != LEN {
-            let message =
-                format!("Array<T> of length {via_len} cannot be stored in [T; {LEN}] Rust array");
-            return Err(ConvertError::with_kind_value(
-                ErrorKind::Custom(Some(message.into())),
-                via,
-            ));
-        }
-
-        let mut option_array = [const { None }; LEN];
-
-        for (element, destination) in via.iter_shared().zip(&mut option_array) {
-            *destination = Some(element);
-        }
-
-        let array = option_array.map(|some| {
-            some.expect(
-                "Elements were removed from Array during `iter_shared()`, this is not allowed",
-            )
-        });
+        try_collect_fixed_array(via.as_variant_array())
+    }
 
-        Ok(array)
+    fn try_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        try_collect_fixed_array(&variant_to_any_array(variant)?)
     }
 }
 
@@ -433,6 +422,195 @@ impl<T: ArrayElement> ToGodot for &[T] {
     }
 }
 
+impl<T: ArrayElement + Eq + Hash, S> GodotConvert for HashSet<T, S> {
+    type Via = Array<T>;
+}
+
+impl<T: ArrayElement + Eq + Hash + Clone, S> ToGodot for HashSet<T, S> {
+    type ToVia<'v>
+        = Array<T>
+    where
+        Self: 'v;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: ArrayElement + Eq + Hash, S: BuildHasher + Default> FromGodot for HashSet<T, S> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        try_collect_array_elements(via.as_variant_array())
+    }
+
+    fn try_from_variant(variant: &Variant) -> Result<Self, ConvertError> {
+        try_collect_array_elements(&variant_to_any_array(variant)?)
+    }
+}
+
+/// Returns an array variant as `VariantArray`, regardless of its runtime element type.
+///
+/// Collections like `Vec<T>` convert element by element, so they accept untyped arrays as well. `Array<T>` would reject those before any
+/// element is looked at, losing the position of the offending element.
+fn variant_to_any_array(variant: &Variant) -> Result<VariantArray, ConvertError> {
+    if variant.get_type() != VariantType::ARRAY {
+        return Err(FromVariantError::BadType {
+            expected: VariantType::ARRAY,
+            actual: variant.get_type(),
+        }
+        .into_error(variant.clone()));
+    }
+
+    // SAFETY: `variant` holds an array. The result is only read from and its elements are converted individually, so the runtime type
+    // of the array does not matter.
+    Ok(unsafe { VariantArray::from_variant_unchecked(variant) })
+}
+
+/// Converts each element of `array`, reporting the index of the first element that cannot be converted.
+fn try_collect_array_elements<T, C>(array: &VariantArray) -> Result<C, ConvertError>
+where
+    T: ArrayElement,
+    C: FromIterator<T>,
+{
+    array
+        .iter_shared()
+        .enumerate()
+        .map(|(index, element)| {
+            T::try_from_variant(&element).map_err(|err| err.in_element(format!("at index {index}")))
+        })
+        .collect()
+}
+
+/// Converts `array` into a Rust array of length `LEN`, reporting the index of the first element that cannot be converted.
+fn try_collect_fixed_array<T: ArrayElement, const LEN: usize>(
+    array: &VariantArray,
+) -> Result<[T; LEN], ConvertError> {
+    let array_len = array.len(); // Caching this avoids an FFI call
+    if array_len != LEN {
+        let message =
+            format!("Array<T> of length {array_len} cannot be stored in [T; {LEN}] Rust array");
+        return Err(ConvertError::with_kind_value(
+            ErrorKind::Custom(Some(message.into())),
+            array.clone(),
+        ));
+    }
+
+    let mut option_array = [const { None }; LEN];
+
+    let elements = array.iter_shared().enumerate();
+    for ((index, element), destination) in elements.zip(&mut option_array) {
+        let element = T::try_from_variant(&element)
+            .map_err(|err| err.in_element(format!("at index {index}")))?;
+
+        *destination = Some(element);
+    }
+
+    let array = option_array.map(|some| {
+        some.expect("Elements were removed from Array during `iter_shared()`, this is not allowed")
+    });
+
+    Ok(array)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Maps
+
+macro_rules! impl_map_convert {
+    ($Map:ident<K, V $(, $S:ident)?> where K: $($KeyBound:path)|+ $(, $SBound:path)?) => {
+        impl<K, V $(, $S)?> GodotConvert for $Map<K, V $(, $S)?> {
+            type Via = Dictionary;
+        }
+
+        impl<K: ToGodot, V: ToGodot $(, $S)?> ToGodot for $Map<K, V $(, $S)?> {
+            type ToVia<'v>
+                = Dictionary
+            where
+                Self: 'v;
+
+            fn to_godot(&self) -> Self::ToVia<'_> {
+                self.iter()
+                    .map(|(key, value)| (key.to_variant(), value.to_variant()))
+                    .collect()
+            }
+        }
+
+        impl<K, V $(, $S)?> FromGodot for $Map<K, V $(, $S)?>
+        where
+            K: FromGodot $(+ $KeyBound)+,
+            V: FromGodot,
+            $($S: $SBound + Default,)?
+        {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                via.iter_shared()
+                    .map(|(key_variant, value)| {
+                        let key = K::try_from_variant(&key_variant)
+                            .map_err(|err| err.in_element(format!("with key {key_variant}")))?;
+                        let value = V::try_from_variant(&value)
+                            .map_err(|err| err.in_element(format!("with key {key_variant}")))?;
+
+                        Ok((key, value))
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_map_convert!(HashMap<K, V, S> where K: Eq | Hash, BuildHasher);
+impl_map_convert!(BTreeMap<K, V> where K: Ord);
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tuples
+
+macro_rules! impl_tuple_convert {
+    ($LEN:literal; $($T:ident : $n:tt),+) => {
+        impl<$($T),+> GodotConvert for ($($T,)+) {
+            type Via = VariantArray;
+        }
+
+        impl<$($T: ToGodot),+> ToGodot for ($($T,)+) {
+            type ToVia<'v>
+                = VariantArray
+            where
+                Self: 'v;
+
+            fn to_godot(&self) -> Self::ToVia<'_> {
+                let mut array = VariantArray::new();
+                $(
+                    array.push(&self.$n.to_variant());
+                )+
+                array
+            }
+        }
+
+        impl<$($T: FromGodot),+> FromGodot for ($($T,)+) {
+            fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+                let via_len = via.len();
+                if via_len != $LEN {
+                    let message = format!("Array of length {via_len} cannot be converted to a tuple of length {}", $LEN);
+                    return Err(ConvertError::with_kind_value(
+                        ErrorKind::Custom(Some(message.into())),
+                        via,
+                    ));
+                }
+
+                Ok(($(
+                    $T::try_from_variant(&via.at($n))
+                        .map_err(|err| err.in_element(format!("at index {}", $n)))?,
+                )+))
+            }
+        }
+    };
+}
+
+impl_tuple_convert!(1; T0: 0);
+impl_tuple_convert!(2; T0: 0, T1: 1);
+impl_tuple_convert!(3; T0: 0, T1: 1, T2: 2);
+impl_tuple_convert!(4; T0: 0, T1: 1, T2: 2, T3: 3);
+impl_tuple_convert!(5; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4);
+impl_tuple_convert!(6; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5);
+impl_tuple_convert!(7; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6);
+impl_tuple_convert!(8; T0: 0, T1: 1, T2: 2, T3: 3, T4: 4, T5: 5, T6: 6, T7: 7);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Raw pointers
 
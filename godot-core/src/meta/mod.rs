@@ -46,10 +46,11 @@
 mod args;
 mod array_type_info;
 mod class_name;
+mod dictionary_type_info;
 mod godot_convert;
 mod method_info;
 mod property_info;
-mod sealed;
+pub(crate) mod sealed;
 mod signature;
 mod traits;
 
@@ -58,9 +59,12 @@ pub mod error;
 pub use args::*;
 pub use class_name::ClassName;
 pub use godot_convert::{FromGodot, GodotConvert, ToGodot};
+pub use signature::TypedFn;
 pub use traits::{ArrayElement, GodotType, PackedArrayElement};
 
 pub(crate) use array_type_info::ArrayTypeInfo;
+pub(crate) use dictionary_type_info::DictionaryTypeInfo;
+pub(crate) use signature::{ptrcall_arg, ptrcall_return, varcall_arg_ref};
 pub(crate) use traits::{
     element_godot_type_name, element_variant_type, GodotFfiVariant, GodotNullableFfi,
 };
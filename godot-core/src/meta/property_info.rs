@@ -8,7 +8,8 @@
 use crate::builtin::{GString, StringName};
 use crate::global::{PropertyHint, PropertyUsageFlags};
 use crate::meta::{
-    element_godot_type_name, ArrayElement, ClassName, GodotType, PackedArrayElement,
+    element_godot_type_name, element_variant_type, ArrayElement, ClassName, GodotType,
+    PackedArrayElement,
 };
 use crate::registry::property::{Export, Var};
 use crate::sys;
@@ -132,6 +133,20 @@ impl PropertyInfo {
         }
     }
 
+    /// Create a new `PropertyInfo` representing a category in Godot.
+    ///
+    /// See [`EditorInspector`](https://docs.godotengine.org/en/latest/classes/class_editorinspector.html#class-editorinspector) in Godot for
+    /// more information.
+    pub fn new_category(category_name: &str) -> Self {
+        Self {
+            variant_type: VariantType::NIL,
+            class_name: ClassName::none(),
+            property_name: category_name.into(),
+            hint_info: PropertyHintInfo::none(),
+            usage: PropertyUsageFlags::CATEGORY,
+        }
+    }
+
     /// Converts to the FFI type. Keep this object allocated while using that!
     pub fn property_sys(&self) -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineBitfield as _;
@@ -255,4 +270,83 @@ impl PropertyHintInfo {
             hint_string: GString::from(T::element_type_string()),
         }
     }
+
+    /// Use for `#[var]` properties of typed dictionaries -- [`PROPERTY_HINT_DICTIONARY_TYPE`](PropertyHint::DICTIONARY_TYPE) with
+    /// `"{key};{value}"` type names as hint string.
+    ///
+    /// Godot only supports typed dictionaries since 4.4; before that, this returns no hint.
+    pub fn var_dictionary_element<K: ArrayElement, V: ArrayElement>() -> Self {
+        #[cfg(since_api = "4.4")]
+        {
+            let hint_string = format!(
+                "{};{}",
+                element_godot_type_name::<K>(),
+                element_godot_type_name::<V>()
+            );
+
+            Self {
+                hint: PropertyHint::DICTIONARY_TYPE,
+                hint_string: GString::from(hint_string),
+            }
+        }
+
+        #[cfg(before_api = "4.4")]
+        {
+            Self::none()
+        }
+    }
+
+    /// Use for `#[export]` properties of typed dictionaries -- [`PROPERTY_HINT_TYPE_STRING`](PropertyHint::TYPE_STRING) with the key and
+    /// value type strings, separated by `;`.
+    ///
+    /// Godot only supports typed dictionaries since 4.4; before that, this returns the hint of an untyped dictionary.
+    pub fn export_dictionary_element<K: ArrayElement, V: ArrayElement>() -> Self {
+        #[cfg(since_api = "4.4")]
+        {
+            let hint_string = format!("{};{}", K::element_type_string(), V::element_type_string());
+
+            Self {
+                hint: PropertyHint::TYPE_STRING,
+                hint_string: GString::from(hint_string),
+            }
+        }
+
+        #[cfg(before_api = "4.4")]
+        {
+            Self::type_name::<crate::builtin::Dictionary>()
+        }
+    }
+
+    /// Like [`export_array_element()`](Self::export_array_element), but with an explicit hint applied to each element.
+    ///
+    /// For example, `#[export(range = (0.0, 10.0))]` on `Array<f64>` results in the hint string `"3/1:0,10"`.
+    pub fn export_array_element_with_hint<T: ArrayElement>(element_hint: PropertyHintInfo) -> Self {
+        Self::export_element_with_hint(element_variant_type::<T>(), element_hint)
+    }
+
+    /// Like [`export_packed_array_element()`](Self::export_packed_array_element), but with an explicit hint applied to each element.
+    pub fn export_packed_array_element_with_hint<T: PackedArrayElement>(
+        element_hint: PropertyHintInfo,
+    ) -> Self {
+        use crate::sys::GodotFfi as _;
+
+        Self::export_element_with_hint(T::Ffi::variant_type(), element_hint)
+    }
+
+    fn export_element_with_hint(element_type: VariantType, element_hint: PropertyHintInfo) -> Self {
+        use crate::obj::EngineEnum as _;
+
+        // Format of nested type hints: "{variant_type}/{hint}:{hint_string}".
+        let hint_string = format!(
+            "{}/{}:{}",
+            element_type.ord(),
+            element_hint.hint.ord(),
+            element_hint.hint_string
+        );
+
+        Self {
+            hint: PropertyHint::TYPE_STRING,
+            hint_string: GString::from(hint_string),
+        }
+    }
 }
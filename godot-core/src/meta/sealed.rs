@@ -28,17 +28,6 @@ impl Sealed for Color {}
 impl Sealed for GString {}
 impl Sealed for StringName {}
 impl Sealed for NodePath {}
-impl Sealed for PackedByteArray {}
-impl Sealed for PackedInt32Array {}
-impl Sealed for PackedInt64Array {}
-impl Sealed for PackedFloat32Array {}
-impl Sealed for PackedFloat64Array {}
-impl Sealed for PackedStringArray {}
-impl Sealed for PackedVector2Array {}
-impl Sealed for PackedVector3Array {}
-#[cfg(since_api = "4.3")]
-impl Sealed for PackedVector4Array {}
-impl Sealed for PackedColorArray {}
 impl Sealed for Plane {}
 impl Sealed for Projection {}
 impl Sealed for Rid {}
@@ -47,7 +36,7 @@ impl Sealed for Rect2i {}
 impl Sealed for Signal {}
 impl Sealed for Transform2D {}
 impl Sealed for Transform3D {}
-impl Sealed for Dictionary {}
+impl<K: ArrayElement, V: ArrayElement> Sealed for TypedDictionary<K, V> {}
 impl Sealed for bool {}
 impl Sealed for i64 {}
 impl Sealed for i32 {}
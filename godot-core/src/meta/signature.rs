@@ -14,7 +14,7 @@ use sys::{BuiltinMethodBind, ClassMethodBind, GodotFfi, UtilityFunctionBind};
 
 use crate::builtin::Variant;
 use crate::meta::error::{CallError, ConvertError};
-use crate::meta::godot_convert::{into_ffi_variant, try_from_ffi};
+use crate::meta::godot_convert::into_ffi_variant;
 use crate::meta::*;
 use crate::obj::{GodotClass, InstanceId};
 
@@ -33,16 +33,34 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     // TODO(uninit) - can we use this for varcall/ptrcall?
     // ret: sys::GDExtensionUninitializedVariantPtr
     // ret: sys::GDExtensionUninitializedTypePtr
+    /// Inbound varcall from Godot.
+    ///
+    /// `default_args` holds the values of the last `default_args.len()` parameters. They are used in place of arguments that the caller
+    /// omitted, since Godot does not fill in default arguments of extension methods for varcalls.
     unsafe fn in_varcall(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         call_ctx: &CallContext,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
         arg_count: i64,
+        default_args: &[Variant],
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
     ) -> Result<(), CallError>;
 
+    /// Inbound varcall from Godot to a vararg method.
+    ///
+    /// The first `PARAM_COUNT` arguments are converted to `Self::Params`; all remaining ones are passed to `func` as variadic arguments.
+    unsafe fn in_varcall_vararg(
+        instance_ptr: sys::GDExtensionClassInstancePtr,
+        call_ctx: &CallContext,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: i64,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+        func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[&Variant]) -> Self::Ret,
+    ) -> Result<(), CallError>;
+
     unsafe fn out_class_varcall(
         method_bind: ClassMethodBind,
         // Separate parameters to reduce tokens in generated class API.
@@ -86,6 +104,21 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     fn format_args(args: &Self::Params) -> String;
 }
 
+/// Rust function or closure with statically typed parameters, which can be invoked dynamically with `Variant` arguments.
+///
+/// Implemented for all `FnMut(P0, P1, ...) -> R`, where each parameter implements `FromGodot` and the return type implements `ToGodot`.
+/// `Ps` is the parameter list as a tuple. Used by [`Callable::from_typed_fn()`][crate::builtin::Callable::from_typed_fn].
+pub trait TypedFn<Ps>: 'static {
+    /// Converts `args` to the parameter types, invokes the function and converts its return value to `Variant`.
+    ///
+    /// Returns an error if the number of arguments or any of their types do not match.
+    fn call_varargs(
+        &mut self,
+        call_ctx: &CallContext,
+        args: &[&Variant],
+    ) -> Result<Variant, CallError>;
+}
+
 #[doc(hidden)]
 pub trait PtrcallSignatureTuple {
     type Params;
@@ -139,7 +172,7 @@ macro_rules! impl_varcall_signature_for_tuple {
             where
                 $R: ToGodot + FromGodot + Debug,
                 $(
-                    $Pn: ToGodot + FromGodot + Debug,
+                    $Pn: ToGodot + FuncParam + Debug,
                 )*
         {
             const PARAM_COUNT: usize = $PARAM_COUNT;
@@ -175,12 +208,32 @@ macro_rules! impl_varcall_signature_for_tuple {
                 call_ctx: &CallContext,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
                 arg_count: i64,
+                default_args: &[Variant],
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
             ) -> Result<(), CallError> {
                 //$crate::out!("in_varcall: {call_ctx}");
-                CallError::check_arg_count(call_ctx, arg_count as usize, $PARAM_COUNT)?;
+                let arg_count = arg_count as usize;
+                CallError::check_arg_count_with_defaults(call_ctx, arg_count, default_args.len(), $PARAM_COUNT)?;
+
+                // Append default values for omitted trailing arguments. Must outlive the conversions below.
+                let missing_count = $PARAM_COUNT - arg_count;
+                let all_args_ptrs: Vec<sys::GDExtensionConstVariantPtr>;
+                let args_ptr = if missing_count > 0 {
+                    let first_default = default_args.len() - missing_count;
+
+                    all_args_ptrs = Variant::borrow_ref_slice(args_ptr, arg_count)
+                        .iter()
+                        .copied()
+                        .chain(&default_args[first_default..])
+                        .map(Variant::var_sys)
+                        .collect();
+
+                    all_args_ptrs.as_ptr()
+                } else {
+                    args_ptr
+                };
 
                 #[cfg(feature = "trace")]
                 trace::push(true, false, &call_ctx);
@@ -194,6 +247,34 @@ macro_rules! impl_varcall_signature_for_tuple {
                 Ok(())
             }
 
+            #[inline]
+            unsafe fn in_varcall_vararg(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                call_ctx: &CallContext,
+                args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: i64,
+                ret: sys::GDExtensionVariantPtr,
+                err: *mut sys::GDExtensionCallError,
+                func: fn(sys::GDExtensionClassInstancePtr, Self::Params, &[&Variant]) -> Self::Ret,
+            ) -> Result<(), CallError> {
+                let arg_count = arg_count as usize;
+                CallError::check_min_arg_count(call_ctx, arg_count, $PARAM_COUNT)?;
+
+                #[cfg(feature = "trace")]
+                trace::push(true, false, &call_ctx);
+
+                let args = ($(
+                    unsafe { varcall_arg::<$Pn, $n>(args_ptr, call_ctx)? },
+                )*) ;
+
+                let all_args = Variant::borrow_ref_slice(args_ptr, arg_count);
+                let varargs = &all_args[$PARAM_COUNT..];
+
+                let rust_result = func(instance_ptr, args, varargs);
+                varcall_return::<$R>(rust_result, ret, err);
+                Ok(())
+            }
+
             #[inline]
             unsafe fn out_class_varcall(
                 method_bind: ClassMethodBind,
@@ -354,6 +435,37 @@ macro_rules! impl_varcall_signature_for_tuple {
     };
 }
 
+macro_rules! impl_typed_fn_for_tuple {
+    (
+        $PARAM_COUNT:literal
+        $(, ($pn:ident, $n:tt) : $Pn:ident)*
+    ) => {
+        impl<F, R, $($Pn,)*> TypedFn<($($Pn,)*)> for F
+            where
+                F: FnMut($($Pn,)*) -> R + 'static,
+                R: ToGodot,
+                $(
+                    $Pn: FromGodot,
+                )*
+        {
+            fn call_varargs(
+                &mut self,
+                call_ctx: &CallContext,
+                args: &[&Variant],
+            ) -> Result<Variant, CallError> {
+                CallError::check_arg_count(call_ctx, args.len(), $PARAM_COUNT)?;
+
+                $(
+                    let $pn = varcall_arg_ref::<$Pn>(args[$n], $n, call_ctx)?;
+                )*
+
+                let rust_result = self($($pn,)*);
+                Ok(rust_result.to_variant())
+            }
+        }
+    };
+}
+
 macro_rules! marshal_args {
     (
         let $out:ident = $( $pn:ident: $n:tt )* ;
@@ -387,7 +499,7 @@ macro_rules! impl_ptrcall_signature_for_tuple {
         #[allow(unused_variables)]
         impl<$R, $($Pn,)*> PtrcallSignatureTuple for ($R, $($Pn,)*)
             where $R: ToGodot + FromGodot + Debug,
-               $( $Pn: ToGodot + FromGodot + Debug, )*
+               $( $Pn: ToGodot + FuncParam + Debug, )*
         {
             type Params = ($($Pn,)*);
             type Ret = $R;
@@ -511,14 +623,23 @@ macro_rules! impl_ptrcall_signature_for_tuple {
 ///
 /// # Safety
 /// - It must be safe to dereference the pointer at `args_ptr.offset(N)` .
-unsafe fn varcall_arg<P: FromGodot, const N: isize>(
+unsafe fn varcall_arg<P: FuncParam, const N: isize>(
     args_ptr: *const sys::GDExtensionConstVariantPtr,
     call_ctx: &CallContext,
 ) -> Result<P, CallError> {
     let variant_ref = Variant::borrow_var_sys(*args_ptr.offset(N));
 
-    P::try_from_variant(variant_ref)
-        .map_err(|err| CallError::failed_param_conversion::<P>(call_ctx, N, err))
+    varcall_arg_ref(variant_ref, N, call_ctx)
+}
+
+/// Convert the argument at position `index` (already borrowed as `Variant`) into a value of type `P`.
+pub(crate) fn varcall_arg_ref<P: FuncParam>(
+    variant_ref: &Variant,
+    index: isize,
+    call_ctx: &CallContext,
+) -> Result<P, CallError> {
+    P::from_varcall_arg(variant_ref)
+        .map_err(|err| CallError::failed_param_conversion::<P>(call_ctx, index, err))
 }
 
 /// Moves `ret_val` into `ret`.
@@ -561,17 +682,13 @@ pub(crate) unsafe fn varcall_return_checked<R: ToGodot>(
 /// - It must be safe to dereference the address at `args_ptr.offset(N)` .
 /// - The pointer at `args_ptr.offset(N)` must follow the safety requirements as laid out in
 ///   [`GodotFuncMarshal::try_from_arg`][sys::GodotFuncMarshal::try_from_arg].
-unsafe fn ptrcall_arg<P: FromGodot, const N: isize>(
+pub(crate) unsafe fn ptrcall_arg<P: FuncParam, const N: isize>(
     args_ptr: *const sys::GDExtensionConstTypePtr,
     call_ctx: &CallContext,
     call_type: sys::PtrcallType,
 ) -> P {
-    let ffi = <P::Via as GodotType>::Ffi::from_arg_ptr(
-        sys::force_mut_ptr(*args_ptr.offset(N)),
-        call_type,
-    );
-
-    try_from_ffi(ffi).unwrap_or_else(|err| param_error::<P>(call_ctx, N as i32, err))
+    P::from_ptrcall_arg(*args_ptr.offset(N), call_type)
+        .unwrap_or_else(|err| param_error::<P>(call_ctx, N as i32, err))
 }
 
 /// Moves `ret_val` into `ret`.
@@ -579,7 +696,7 @@ unsafe fn ptrcall_arg<P: FromGodot, const N: isize>(
 /// # Safety
 /// `ret_val`, `ret`, and `call_type` must follow the safety requirements as laid out in
 /// [`GodotFuncMarshal::try_return`](sys::GodotFuncMarshal::try_return).
-unsafe fn ptrcall_return<R: ToGodot>(
+pub(crate) unsafe fn ptrcall_return<R: ToGodot>(
     ret_val: R,
     ret: sys::GDExtensionTypePtr,
     _call_ctx: &CallContext,
@@ -631,6 +748,18 @@ impl_varcall_signature_for_tuple!(12; R, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2,
 impl_varcall_signature_for_tuple!(13; R, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9, (p10, 10): P10, (p11, 11): P11, (p12, 12): P12);
 impl_varcall_signature_for_tuple!(14; R, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9, (p10, 10): P10, (p11, 11): P11, (p12, 12): P12, (p13, 13): P13);
 
+impl_typed_fn_for_tuple!(0);
+impl_typed_fn_for_tuple!(1, (p0, 0): P0);
+impl_typed_fn_for_tuple!(2, (p0, 0): P0, (p1, 1): P1);
+impl_typed_fn_for_tuple!(3, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2);
+impl_typed_fn_for_tuple!(4, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3);
+impl_typed_fn_for_tuple!(5, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4);
+impl_typed_fn_for_tuple!(6, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5);
+impl_typed_fn_for_tuple!(7, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6);
+impl_typed_fn_for_tuple!(8, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7);
+impl_typed_fn_for_tuple!(9, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8);
+impl_typed_fn_for_tuple!(10, (p0, 0): P0, (p1, 1): P1, (p2, 2): P2, (p3, 3): P3, (p4, 4): P4, (p5, 5): P5, (p6, 6): P6, (p7, 7): P7, (p8, 8): P8, (p9, 9): P9);
+
 impl_ptrcall_signature_for_tuple!(R);
 impl_ptrcall_signature_for_tuple!(R, (p0, 0): P0);
 impl_ptrcall_signature_for_tuple!(R, (p0, 0): P0, (p1, 1): P1);
@@ -6,6 +6,7 @@
  */
 
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::mem::ManuallyDrop;
 use std::ops::{Deref, DerefMut};
 
 use godot_ffi as sys;
@@ -16,11 +17,11 @@ use crate::global::PropertyHint;
 use crate::meta::error::{ConvertError, FromFfiError};
 use crate::meta::{
     ArrayElement, AsArg, CallContext, ClassName, CowArg, FromGodot, GodotConvert, GodotType,
-    ParamType, PropertyHintInfo, RefArg, ToGodot,
+    ParamBorrow, ParamType, PropertyHintInfo, RefArg, ToGodot,
 };
 use crate::obj::{
     bounds, cap, Bounds, DynGd, EngineEnum, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits,
-    InstanceId, RawGd,
+    InstanceId, RawGd, WeakGd, WithSignals,
 };
 use crate::private::callbacks;
 use crate::registry::property::{Export, Var};
@@ -138,18 +139,25 @@ where
     ///     MyClass { my_base, other_field: 732 }
     /// });
     /// ```
+    #[cfg_attr(feature = "leak-diagnostics", track_caller)]
     pub fn from_init_fn<F>(init: F) -> Self
     where
         F: FnOnce(crate::obj::Base<T::Base>) -> T,
     {
         let object_ptr = callbacks::create_custom(init);
-        unsafe { Gd::from_obj_sys(object_ptr) }
+        let gd = unsafe { Gd::from_obj_sys(object_ptr) };
+
+        #[cfg(feature = "leak-diagnostics")]
+        crate::obj::leak_diagnostics::track(&gd, std::panic::Location::caller());
+
+        gd
     }
 
     /// Moves a user-created object into this smart pointer, submitting ownership to the Godot engine.
     ///
     /// This is only useful for types `T` which do not store their base objects (if they have a base,
     /// you cannot construct them standalone).
+    #[cfg_attr(feature = "leak-diagnostics", track_caller)]
     pub fn from_object(user_object: T) -> Self {
         Self::from_init_fn(move |_base| user_object)
     }
@@ -277,6 +285,13 @@ impl<T: GodotClass> Gd<T> {
         self.raw.is_instance_valid()
     }
 
+    /// Creates a weak pointer to this object, which does not keep it alive.
+    ///
+    /// See [`WeakGd`] for details. If this object is already dead, the weak pointer will never upgrade.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd::new(self.instance_id_unchecked())
+    }
+
     /// Returns the dynamic class name of the object as `StringName`.
     ///
     /// This method retrieves the class name of the object at runtime, which can be different from [`T::class_name()`] if derived
@@ -695,6 +710,32 @@ where
     }
 }
 
+/// _The methods in this impl block are only available for classes `T` that provide type-safe signals._ <br><br>
+impl<T> Gd<T>
+where
+    T: WithSignals,
+{
+    /// Access signals of this object.
+    ///
+    /// For user classes that have at least one `#[signal]` defined, returns a collection of signal names. Each returned signal has a
+    /// specialized API for connecting and emitting signals in a type-safe way. This method is the equivalent of
+    /// [`WithUserSignals::signals()`], but when called externally (not from `self`). If you are within the `impl` of a class, use
+    /// `self.signals()` directly instead.
+    ///
+    /// For engine classes, the collection contains both the class' own signals and inherited ones, for example:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::Timer;
+    /// let mut timer = Timer::new_alloc();
+    /// timer.signals().timeout().connect(|| godot_print!("Time's up!"));
+    /// ```
+    ///
+    /// [`WithUserSignals::signals()`]: crate::obj::WithUserSignals::signals()
+    pub fn signals(&self) -> T::SignalCollection<'_> {
+        T::__signals_from_external(self)
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
@@ -735,6 +776,21 @@ impl<T: GodotClass> FromGodot for Gd<T> {
     }
 }
 
+impl<T: GodotClass> ParamBorrow for Gd<T> {
+    unsafe fn borrow_ptrcall_arg(
+        arg_ptr: sys::GDExtensionConstTypePtr,
+        call_type: sys::PtrcallType,
+    ) -> Result<ManuallyDrop<Self>, ConvertError> {
+        // Weak pointer: the caller holds a reference for the duration of the call, so the ref-count is not incremented.
+        let raw = RawGd::borrow_arg_ptr(arg_ptr, call_type);
+        if raw.is_null() {
+            return Err(FromFfiError::NullRawGd.into_error(raw));
+        }
+
+        Ok(ManuallyDrop::new(Self { raw }))
+    }
+}
+
 // Keep in sync with DynGd.
 impl<T: GodotClass> GodotType for Gd<T> {
     // Some #[doc(hidden)] are repeated despite already declared in trait; some IDEs suggest in auto-complete otherwise.
@@ -875,8 +931,14 @@ where
     /// This trait is only implemented for reference-counted classes. Classes with manually-managed memory (e.g. `Node`) are not covered,
     /// because they need explicit memory management, and deriving `Default` has a high chance of the user forgetting to call `free()` on those.
     /// `T::new_alloc()` should be used for those instead.
+    #[cfg_attr(feature = "leak-diagnostics", track_caller)]
     fn default() -> Self {
-        T::__godot_default()
+        let gd = T::__godot_default();
+
+        #[cfg(feature = "leak-diagnostics")]
+        crate::obj::leak_diagnostics::track(&gd, std::panic::Location::caller());
+
+        gd
     }
 }
 
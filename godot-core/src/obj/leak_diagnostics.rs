@@ -0,0 +1,157 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Diagnostics for objects allocated from Rust, to find leaks and inspect lifetimes.
+//!
+//! Available with the `leak-diagnostics` Cargo feature. When enabled, every object created from Rust through [`NewAlloc::new_alloc()`],
+//! [`NewGd::new_gd()`], `Gd::default()` or [`Gd::from_init_fn()`] is recorded, together with the source location of the call and a
+//! backtrace. Backtraces follow the rules of [`Backtrace::capture()`], i.e. they are only collected if the `RUST_BACKTRACE` or
+//! `RUST_LIB_BACKTRACE` environment variables are set.
+//!
+//! When the `Scene` init level is unloaded, all recorded objects which are still alive are reported as leaks, grouped by class. At runtime,
+//! [`live_count()`] and [`live_counts()`] can be used to query how many recorded objects are currently alive.
+//!
+//! Objects created by Godot (e.g. by GDScript or scene instantiation) are not tracked, even if they are of a Rust class.
+//!
+//! [`NewAlloc::new_alloc()`]: crate::obj::NewAlloc::new_alloc
+//! [`NewGd::new_gd()`]: crate::obj::NewGd::new_gd
+//! [`Gd::from_init_fn()`]: crate::obj::Gd::from_init_fn
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::panic::Location;
+
+use godot_ffi as sys;
+use sys::{Global, GlobalGuard};
+
+use crate::classes;
+use crate::meta::ClassName;
+use crate::obj::{Gd, GodotClass, InstanceId};
+
+/// Returns how many objects of class `T`, created from Rust, are currently alive.
+///
+/// Only counts objects whose dynamic class at creation was exactly `T`, not subclasses.
+pub fn live_count<T: GodotClass>() -> usize {
+    live_counts()
+        .get(&T::class_name())
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Returns the number of currently alive objects created from Rust, by class.
+///
+/// Classes without live objects are not contained in the map.
+pub fn live_counts() -> HashMap<ClassName, usize> {
+    let mut registry = lock();
+    registry.prune();
+
+    let mut counts = HashMap::new();
+    for allocation in registry.allocations.values() {
+        *counts.entry(allocation.class_name).or_default() += 1;
+    }
+    counts
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal API
+
+/// Records a newly created object.
+pub(crate) fn track<T: GodotClass>(gd: &Gd<T>, location: &'static Location<'static>) {
+    let allocation = Allocation {
+        class_name: T::class_name(),
+        location,
+        backtrace: Backtrace::capture(),
+    };
+
+    let mut registry = lock();
+    registry
+        .allocations
+        .insert(gd.instance_id_unchecked(), allocation);
+
+    // Dead entries are only removed lazily, as there is no destruction hook for engine classes. Prune when the registry has grown
+    // considerably since the last time, to keep memory bounded while amortizing the cost.
+    if registry.allocations.len() >= registry.prune_threshold {
+        registry.prune();
+        registry.prune_threshold = (registry.allocations.len() * 2).max(MIN_PRUNE_THRESHOLD);
+    }
+}
+
+/// Reports all objects still alive as leaks, and resets the registry.
+///
+/// Called when the `Scene` level is deinitialized, after user code has run.
+pub(crate) fn report_leaks() {
+    let mut registry = lock();
+    registry.prune();
+
+    let allocations = std::mem::take(&mut registry.allocations);
+    registry.prune_threshold = MIN_PRUNE_THRESHOLD;
+    drop(registry);
+
+    if allocations.is_empty() {
+        return;
+    }
+
+    // Sort by class name and ID, for deterministic output.
+    let mut by_class = BTreeMap::<String, Vec<(InstanceId, Allocation)>>::new();
+    for (id, allocation) in allocations {
+        by_class
+            .entry(allocation.class_name.to_string())
+            .or_default()
+            .push((id, allocation));
+    }
+
+    let total: usize = by_class.values().map(Vec::len).sum();
+    let mut message = format!("{total} object(s) created from Rust were leaked:");
+
+    for (class_name, mut leaked) in by_class {
+        leaked.sort_by_key(|(id, _)| id.to_i64());
+
+        let _ = write!(message, "\n  {class_name} ({}):", leaked.len());
+        for (id, allocation) in leaked {
+            let _ = write!(message, "\n    {id} created at {}", allocation.location);
+            if allocation.backtrace.status() == BacktraceStatus::Captured {
+                let _ = write!(message, "\n{}", allocation.backtrace);
+            }
+        }
+    }
+
+    crate::godot_warn!("{message}");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+const MIN_PRUNE_THRESHOLD: usize = 256;
+
+struct Allocation {
+    class_name: ClassName,
+    location: &'static Location<'static>,
+    backtrace: Backtrace,
+}
+
+struct Registry {
+    allocations: HashMap<InstanceId, Allocation>,
+    prune_threshold: usize,
+}
+
+impl Registry {
+    /// Removes allocations whose objects have been destroyed in the meantime.
+    fn prune(&mut self) {
+        self.allocations
+            .retain(|&id, _| !classes::object_ptr_from_id(id).is_null());
+    }
+}
+
+fn lock() -> GlobalGuard<'static, Registry> {
+    static REGISTRY: Global<Registry> = Global::new(|| Registry {
+        allocations: HashMap::new(),
+        prune_threshold: MIN_PRUNE_THRESHOLD,
+    });
+
+    REGISTRY.lock()
+}
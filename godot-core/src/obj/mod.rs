@@ -17,8 +17,10 @@ mod gd;
 mod guards;
 mod instance_id;
 mod onready;
+mod owned_gd;
 mod raw_gd;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
@@ -28,10 +30,15 @@ pub use gd::*;
 pub use guards::{BaseMut, BaseRef, DynGdMut, DynGdRef, GdMut, GdRef};
 pub use instance_id::*;
 pub use onready::*;
+pub use owned_gd::OwnedGd;
 pub use raw_gd::*;
 pub use traits::*;
+pub use weak_gd::WeakGd;
 
 pub mod bounds;
+#[cfg(feature = "leak-diagnostics")]
+#[cfg_attr(published_docs, doc(cfg(feature = "leak-diagnostics")))]
+pub mod leak_diagnostics;
 pub mod script;
 pub use bounds::private::Bounds;
 
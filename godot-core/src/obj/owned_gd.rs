@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::ops::{Deref, DerefMut};
+
+use crate::classes;
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, NewAlloc};
+
+/// Owning smart pointer to a manually managed object, which frees it when dropped.
+///
+/// Manually managed objects (anything not inheriting `RefCounted`, e.g. `Node`) leak unless [`Gd::free()`] is called, or their ownership
+/// is handed over -- for nodes, typically to the scene tree. `OwnedGd<T>` takes care of this in RAII fashion, which makes it easy to build
+/// temporary objects and node hierarchies without leaks, even if a panic occurs midway.
+///
+/// `OwnedGd<T>` dereferences to `Gd<T>`, so it can be used in place of the latter, e.g. passed to `add_child(&*owned)`.
+///
+/// # Behavior on drop
+/// - If the object has already been destroyed (e.g. through `free()` on another `Gd` pointer), nothing happens.
+/// - If the object is a `Node` which has a parent, nothing happens: the node is owned by its parent, which will free it together with
+///   itself. This means that adding a node to the scene tree with `add_child()` releases ownership automatically.
+/// - Otherwise, the object is freed immediately. For nodes, this also frees all their children.
+/// - Nodes owned through [`new_deferred()`][Self::new_deferred] are instead queued for deletion with `Node::queue_free()`, which frees
+///   them at the end of the current frame. This is safe while the node is still in use, e.g. when dropped during its own signal handler.
+///
+/// To keep the object alive regardless, call [`release()`][Self::release] to turn it back into a plain `Gd<T>`.
+///
+/// # Panics
+/// Dropping panics under the same conditions as [`Gd::free()`], e.g. if the object is currently bound through `bind()` or `bind_mut()`.
+/// During panic unwinding, an error is printed instead.
+///
+/// # Example
+/// ```no_run
+/// # use godot::prelude::*;
+/// let mut parent = OwnedGd::<Node>::new_alloc();
+/// let child = OwnedGd::<Node>::new_alloc();
+/// parent.add_child(&*child);
+///
+/// // `child` is now owned by `parent`; dropping it does not free it.
+/// drop(child);
+///
+/// // Frees `parent` and, with it, all children.
+/// drop(parent);
+/// ```
+pub struct OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    // Only None after release() or during drop.
+    gd: Option<Gd<T>>,
+
+    // Whether to use queue_free() instead of free() on drop. Only true for nodes.
+    deferred: bool,
+}
+
+impl<T> OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    /// Takes ownership of an existing object, freeing it when the `OwnedGd` is dropped.
+    ///
+    /// Other `Gd` pointers to the same object remain usable as long as the object is alive, but will become invalid once it is freed.
+    pub fn new(gd: Gd<T>) -> Self {
+        Self {
+            gd: Some(gd),
+            deferred: false,
+        }
+    }
+
+    /// Takes ownership of an existing node, queueing it for deletion with `Node::queue_free()` when the `OwnedGd` is dropped.
+    ///
+    /// Unlike [`new()`][Self::new], the node stays valid until the end of the current frame.
+    pub fn new_deferred(gd: Gd<T>) -> Self
+    where
+        T: Inherits<classes::Node>,
+    {
+        Self {
+            gd: Some(gd),
+            deferred: true,
+        }
+    }
+
+    /// Allocates a new, default-constructed object owned by the returned pointer.
+    ///
+    /// Equivalent to `OwnedGd::new(T::new_alloc())`.
+    #[cfg_attr(feature = "leak-diagnostics", track_caller)]
+    pub fn new_alloc() -> Self
+    where
+        T: NewAlloc,
+    {
+        Self::new(T::new_alloc())
+    }
+
+    /// Gives up ownership and returns the underlying `Gd<T>`, without freeing the object.
+    ///
+    /// Afterward, the object needs to be managed manually again.
+    #[must_use]
+    pub fn release(mut self) -> Gd<T> {
+        self.gd.take().expect("OwnedGd already released")
+    }
+
+    fn gd(&self) -> &Gd<T> {
+        self.gd.as_ref().expect("OwnedGd already released")
+    }
+}
+
+impl<T> Drop for OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn drop(&mut self) {
+        let Some(gd) = self.gd.take() else {
+            return;
+        };
+
+        if !gd.is_instance_valid() {
+            return;
+        }
+
+        // Nodes attached to a parent are owned by the tree. Checks dynamic type, so this also works for OwnedGd<Object>.
+        if let Ok(mut node) = Gd::<classes::Node>::try_from_instance_id(gd.instance_id_unchecked())
+        {
+            if node.get_parent().is_some() {
+                return;
+            }
+
+            if self.deferred {
+                node.queue_free();
+                return;
+            }
+        }
+
+        gd.free();
+    }
+}
+
+impl<T> Deref for OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    type Target = Gd<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self.gd()
+    }
+}
+
+impl<T> DerefMut for OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.gd.as_mut().expect("OwnedGd already released")
+    }
+}
+
+impl<T> From<Gd<T>> for OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn from(gd: Gd<T>) -> Self {
+        Self::new(gd)
+    }
+}
+
+impl<T> Debug for OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("OwnedGd").field(self.gd()).finish()
+    }
+}
+
+impl<T> Display for OwnedGd<T>
+where
+    T: GodotClass + Bounds<Memory = bounds::MemManual>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(self.gd(), f)
+    }
+}
@@ -79,6 +79,32 @@ impl<T: GodotClass> RawGd<T> {
         Self::from_obj_sys_weak(obj).with_inc_refcount()
     }
 
+    /// Weakly borrows the object passed as a ptrcall argument, i.e. without incrementing its ref-count.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a null pointer or a valid ptrcall argument for an object of type `T`, passed according to `call_type`.
+    pub(super) unsafe fn borrow_arg_ptr(
+        ptr: sys::GDExtensionConstTypePtr,
+        call_type: PtrcallType,
+    ) -> Self {
+        if ptr.is_null() {
+            return Self::null();
+        }
+
+        let obj_ptr = if T::DynMemory::pass_as_ref(call_type) {
+            // ptr is `Ref<T>*`
+            // See the docs for `PtrcallType::Virtual` for more info on `Ref<T>`.
+            interface_fn!(ref_get_object)(ptr as sys::GDExtensionConstRefPtr)
+        } else {
+            // ptr is `T**` from Godot 4.1 onwards, also in virtual functions.
+            *(ptr as *const sys::GDExtensionObjectPtr)
+        };
+
+        // obj_ptr is `T*`
+        Self::from_obj_sys_weak(obj_ptr)
+    }
+
     /// Returns `self` but with initialized ref-count.
     fn with_inc_refcount(mut self) -> Self {
         // Note: use init_ref and not inc_ref, since this might be the first reference increment.
@@ -494,21 +520,7 @@ where
     }
 
     unsafe fn from_arg_ptr(ptr: sys::GDExtensionTypePtr, call_type: PtrcallType) -> Self {
-        if ptr.is_null() {
-            return Self::null();
-        }
-
-        let obj_ptr = if T::DynMemory::pass_as_ref(call_type) {
-            // ptr is `Ref<T>*`
-            // See the docs for `PtrcallType::Virtual` for more info on `Ref<T>`.
-            interface_fn!(ref_get_object)(ptr as sys::GDExtensionRefPtr)
-        } else {
-            // ptr is `T**` from Godot 4.1 onwards, also in virtual functions.
-            *(ptr as *mut sys::GDExtensionObjectPtr)
-        };
-
-        // obj_ptr is `T*`
-        Self::from_obj_sys(obj_ptr)
+        Self::borrow_arg_ptr(ptr, call_type).with_inc_refcount()
     }
 
     unsafe fn move_return_ptr(self, ptr: sys::GDExtensionTypePtr, call_type: PtrcallType) {
@@ -425,6 +425,44 @@ pub trait WithBaseField: GodotClass + Bounds<Declarer = bounds::DeclUser> {
     }
 }
 
+/// Implemented for classes that provide type-safe signals.
+///
+/// For user classes, this is automatically implemented by `#[godot_api]` if the class declares at least one `#[signal]`.
+/// Engine classes implement it with a collection that includes inherited signals, e.g. `button.signals().pressed()`.
+/// The signal collection can be accessed from outside the class via [`Gd::signals()`].
+pub trait WithSignals: GodotClass {
+    /// The associated struct listing all signals of this class.
+    ///
+    /// `'c` denotes the lifetime during which the class instance is borrowed and its signals can be modified.
+    type SignalCollection<'c>
+    where
+        Self: 'c;
+
+    /// Object through which signals are emitted and connected; differs for user and engine classes.
+    #[doc(hidden)]
+    type __SignalObj<'c>;
+
+    /// Create from existing `Gd`, to enable `Gd::signals()`.
+    #[doc(hidden)]
+    fn __signals_from_external(external: &Gd<Self>) -> Self::SignalCollection<'_>;
+}
+
+/// Implemented for user classes that declare signals; provides access to them from within `self`.
+///
+/// Emitting or connecting signals requires a `Base<T>` field, since this happens through [`WithBaseField::base_mut()`], allowing
+/// connected handlers to call back into the same object.
+pub trait WithUserSignals: WithSignals + Bounds<Declarer = bounds::DeclUser> {
+    /// Access user-defined signals of the current object `self`.
+    ///
+    /// For classes that have at least one `#[signal]` defined, returns a collection of signal names. Each returned signal has a specialized
+    /// API for connecting and emitting signals in a type-safe way. If you need to access signals from outside (given a `Gd` pointer), use
+    /// [`Gd::signals()`] instead.
+    ///
+    /// If you haven't already, read the [book chapter about signals](https://godot-rust.github.io/book/register/signals.html) for a
+    /// walkthrough.
+    fn signals(&mut self) -> Self::SignalCollection<'_>;
+}
+
 /// Extension trait for all reference-counted classes.
 pub trait NewGd: GodotClass {
     /// Return a new, ref-counted `Gd` containing a default-constructed instance.
@@ -437,6 +475,7 @@ impl<T> NewGd for T
 where
     T: cap::GodotDefault + Bounds<Memory = bounds::MemRefCounted>,
 {
+    #[cfg_attr(feature = "leak-diagnostics", track_caller)]
     fn new_gd() -> Gd<Self> {
         Gd::default()
     }
@@ -456,10 +495,16 @@ impl<T> NewAlloc for T
 where
     T: cap::GodotDefault + Bounds<Memory = bounds::MemManual>,
 {
+    #[cfg_attr(feature = "leak-diagnostics", track_caller)]
     fn new_alloc() -> Gd<Self> {
         use crate::obj::bounds::Declarer as _;
 
-        <Self as Bounds>::Declarer::create_gd()
+        let gd = <Self as Bounds>::Declarer::create_gd();
+
+        #[cfg(feature = "leak-diagnostics")]
+        crate::obj::leak_diagnostics::track(&gd, std::panic::Location::caller());
+
+        gd
     }
 }
 
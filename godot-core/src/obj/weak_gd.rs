@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use crate::classes;
+use crate::obj::{Gd, GodotClass, InstanceId, RawGd};
+
+/// Weak pointer to a Godot object, which does not keep it alive.
+///
+/// Obtained through [`Gd::downgrade()`]. Use [`upgrade()`][Self::upgrade] to get a strong `Gd<T>` back, as long as the object still exists.
+///
+/// This is useful for caches, observer lists or back-references, where a strong `Gd<T>` would keep `RefCounted` objects alive or create
+/// reference cycles.
+///
+/// # Liveness
+/// `WeakGd` only stores the object's instance ID, which Godot never reuses for another object.
+/// - For reference-counted objects, upgrading fails once the last strong reference is gone, like Godot's `WeakRef`.
+/// - For manually managed objects, upgrading fails once the object has been freed.
+///
+/// # Equality and hashing
+/// Two `WeakGd` pointers are equal if they refer to the same instance ID, regardless of whether the object is still alive.
+/// Unlike `Gd<T>`, comparing or hashing never panics.
+pub struct WeakGd<T: GodotClass> {
+    instance_id: InstanceId,
+
+    // Like Gd<T>, not Send/Sync: upgrading yields a Gd<T>, which is bound to a thread.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(super) fn new(instance_id: InstanceId) -> Self {
+        Self {
+            instance_id,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong pointer to the object, or `None` if it no longer exists.
+    ///
+    /// Also returns `None` if a reference-counted object is currently being destroyed, as the object must not be revived.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let object_ptr = classes::object_ptr_from_id(self.instance_id);
+        if object_ptr.is_null() {
+            return None;
+        }
+
+        if self.instance_id.is_ref_counted() {
+            // SAFETY: pointer was just looked up in the object DB, so it refers to a live RefCounted object. Weak, i.e. no ref-count
+            // increment, and never dropped, so the count is left untouched.
+            let raw = ManuallyDrop::new(unsafe {
+                RawGd::<classes::RefCounted>::from_obj_sys_weak(object_ptr)
+            });
+
+            // Same check as Godot's `WeakRef::get_ref()`: with ref-count 0, the object is about to be destroyed.
+            if raw.with_ref_counted(|refc| refc.get_reference_count()) == 0 {
+                return None;
+            }
+        }
+
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
+
+    /// Returns the instance ID of the referred-to object, which may no longer be alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance_id
+    }
+}
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: GodotClass> Copy for WeakGd<T> {}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id == other.instance_id
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> Hash for WeakGd<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.instance_id.hash(state);
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "WeakGd<{}>(id={})", T::class_name(), self.instance_id)
+    }
+}
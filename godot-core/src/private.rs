@@ -17,9 +17,10 @@ pub use sys::out;
 #[cfg(feature = "trace")]
 pub use crate::meta::trace;
 
+use crate::builtin::Variant;
 use crate::global::godot_error;
-use crate::meta::error::CallError;
-use crate::meta::CallContext;
+use crate::meta::error::{CallError, ConvertError};
+use crate::meta::{CallContext, FromGodot, ToGodot};
 use crate::sys;
 use std::sync::atomic;
 #[cfg(debug_assertions)]
@@ -217,6 +218,19 @@ pub fn is_class_runtime(is_tool: bool) -> bool {
     global_config.tool_only_in_editor
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversion helpers for #[derive(GodotConvert)]
+
+/// Converts a single field of a derived compound type, attaching `position` (e.g. `"with key name"`) to the error.
+pub fn convert_field<T: FromGodot>(value: &Variant, position: &str) -> Result<T, ConvertError> {
+    T::try_from_variant(value).map_err(|err| err.in_element(position))
+}
+
+/// Error for a field that is not present in the Godot representation and has no default.
+pub fn missing_field_error<V: ToGodot>(value: V, position: &str) -> ConvertError {
+    ConvertError::with_error_value(format!("missing element {position}"), value)
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Panic handling
 
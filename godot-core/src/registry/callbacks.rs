@@ -331,26 +331,17 @@ pub fn erased_init<T: cap::GodotDefault>(base: Box<dyn Any>) -> Box<dyn Any> {
     Box::new(instance)
 }
 
-pub fn register_class_by_builder<T: cap::GodotRegisterClass>(_class_builder: &mut dyn Any) {
-    // TODO use actual argument, once class builder carries state
-    // let class_builder = class_builder
-    //     .downcast_mut::<ClassBuilder<T>>()
-    //     .expect("bad type erasure");
-
-    let mut class_builder = ClassBuilder::new();
+pub fn register_class_by_builder<T: cap::GodotRegisterClass>() {
+    // The builder registers members immediately, so it only exists for the duration of the user's register_class().
+    let mut class_builder = ClassBuilder::<T>::new();
     T::__godot_register_class(&mut class_builder);
 }
 
-pub fn register_user_properties<T: cap::ImplementsGodotExports>(_class_builder: &mut dyn Any) {
+pub fn register_user_properties<T: cap::ImplementsGodotExports>() {
     T::__register_exports();
 }
 
-pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>(_class_builder: &mut dyn Any) {
-    // let class_builder = class_builder
-    //     .downcast_mut::<ClassBuilder<T>>()
-    //     .expect("bad type erasure");
-
-    //T::register_methods(class_builder);
+pub fn register_user_methods_constants<T: cap::ImplementsGodotApi>() {
     T::__register_methods();
     T::__register_constants();
 }
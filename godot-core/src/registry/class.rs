@@ -543,23 +543,21 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
 
     // ...then custom symbols
 
-    //let mut class_builder = crate::builder::ClassBuilder::<?>::new();
-    let mut class_builder = 0; // TODO dummy argument; see callbacks
-
     // Order of the following registrations is crucial:
     // 1. Methods and constants.
     // 2. Properties (they may depend on get/set methods).
     // 3. User-defined registration function (intuitively, user expects their own code to run after proc-macro generated code).
     if let Some(register_fn) = info.register_methods_constants_fn {
-        (register_fn.raw)(&mut class_builder);
+        (register_fn.raw)();
     }
 
     if let Some(register_fn) = info.register_properties_fn {
-        (register_fn.raw)(&mut class_builder);
+        (register_fn.raw)();
     }
 
+    // User-defined registration function receives a ClassBuilder<T>, created inside the type-erased callback.
     if let Some(register_fn) = info.user_register_fn {
-        (register_fn.raw)(&mut class_builder);
+        (register_fn.raw)();
     }
 
     if info.is_editor_plugin {
@@ -7,7 +7,7 @@
 
 //! Internal registration machinery used by proc-macro APIs.
 
-use crate::builtin::StringName;
+use crate::builtin::{GString, StringName};
 use crate::global::PropertyUsageFlags;
 use crate::meta::{ClassName, GodotConvert, GodotType, PropertyHintInfo, PropertyInfo};
 use crate::obj::GodotClass;
@@ -25,6 +25,13 @@ pub fn register_export<C: GodotClass, T: Export>(
 ) {
     // Note: if the user manually specifies `hint`, `hint_string` or `usage` keys, and thus is routed to `register_var()` instead,
     // they can bypass this validation.
+    validate_export::<C, T>();
+
+    register_var::<C, T>(property_name, getter_name, setter_name, hint_info, usage);
+}
+
+/// Panics if `T` is a node type, but `C` is not a node class.
+pub(crate) fn validate_export<C: GodotClass, T: Export>() {
     if !C::inherits::<classes::Node>() {
         if let Some(class) = T::as_node_class() {
             panic!(
@@ -34,8 +41,6 @@ pub fn register_export<C: GodotClass, T: Export>(
             );
         }
     }
-
-    register_var::<C, T>(property_name, getter_name, setter_name, hint_info, usage);
 }
 
 pub fn register_var<C: GodotClass, T: Var>(
@@ -58,6 +63,69 @@ pub fn register_var<C: GodotClass, T: Var>(
     register_var_or_export_inner(info, class_name, getter_name, setter_name);
 }
 
+/// Starts a group of properties in the editor inspector, like GDScript's `@export_group`.
+///
+/// All properties registered afterwards belong to the group (if their name starts with `prefix`), until the next group or category.
+/// An empty `group_name` ends the current group.
+pub fn register_group<C: GodotClass>(group_name: &str, prefix: &str) {
+    let group_name = GString::from(group_name);
+    let prefix = GString::from(prefix);
+    let class_name = C::class_name();
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_property_group)(
+            sys::get_library(),
+            class_name.string_sys(),
+            group_name.string_sys(),
+            prefix.string_sys(),
+        );
+    }
+}
+
+/// Starts a subgroup within the current group, like GDScript's `@export_subgroup`.
+///
+/// An empty `subgroup_name` ends the current subgroup.
+pub fn register_subgroup<C: GodotClass>(subgroup_name: &str, prefix: &str) {
+    let subgroup_name = GString::from(subgroup_name);
+    let prefix = GString::from(prefix);
+    let class_name = C::class_name();
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_property_subgroup)(
+            sys::get_library(),
+            class_name.string_sys(),
+            subgroup_name.string_sys(),
+            prefix.string_sys(),
+        );
+    }
+}
+
+/// Starts a category of properties in the editor inspector, like GDScript's `@export_category`.
+pub fn register_category<C: GodotClass>(category_name: &str) {
+    // Godot has no dedicated registration function for categories; they are properties without accessors.
+    let info = PropertyInfo::new_category(category_name);
+
+    register_var_or_export_inner(info, C::class_name(), "", "");
+}
+
+pub fn register_signal(class_name: ClassName, signal_name: &str, parameters: &[PropertyInfo]) {
+    // Pointers in the sys infos refer to `parameters`, which outlives the FFI call.
+    let parameters_sys: Vec<sys::GDExtensionPropertyInfo> =
+        parameters.iter().map(|info| info.property_sys()).collect();
+
+    let signal_name = StringName::from(signal_name);
+
+    unsafe {
+        sys::interface_fn!(classdb_register_extension_class_signal)(
+            sys::get_library(),
+            class_name.string_sys(),
+            signal_name.string_sys(),
+            parameters_sys.as_ptr(),
+            sys::GDExtensionInt::from(parameters_sys.len() as i64),
+        );
+    }
+}
+
 fn register_var_or_export_inner(
     info: PropertyInfo,
     class_name: ClassName,
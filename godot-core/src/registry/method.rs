@@ -35,6 +35,7 @@ pub struct ClassMethodInfo {
     return_value: Option<MethodParamOrReturnInfo>,
     arguments: Vec<MethodParamOrReturnInfo>,
     default_arguments: Vec<Variant>,
+    method_userdata: *mut std::ffi::c_void,
 }
 
 impl ClassMethodInfo {
@@ -47,7 +48,8 @@ impl ClassMethodInfo {
     ///
     /// `call_func`, if provided, must:
     ///
-    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s.
+    /// - Interpret its parameters as a list of `S::PARAM_COUNT` `Variant`s, of which the last `default_arguments.len()` may be omitted.
+    ///   If `method_flags` contains `VARARG`, any number of additional `Variant`s may follow.
     /// - Return a `Variant`.
     ///
     /// `call_func` and `ptrcall_func`, if provided, must:
@@ -59,7 +61,7 @@ impl ClassMethodInfo {
         ptrcall_func: sys::GDExtensionClassMethodPtrCall,
         method_flags: MethodFlags,
         param_names: &[&str],
-        // default_arguments: Vec<Variant>, - not yet implemented
+        default_arguments: Vec<Variant>,
     ) -> Self {
         let return_value = S::return_info();
         let mut arguments = Vec::new();
@@ -79,7 +81,6 @@ impl ClassMethodInfo {
             }))
         }
 
-        let default_arguments = vec![]; // not yet implemented.
         assert!(
             default_arguments.len() <= arguments.len(),
             "cannot have more default arguments than arguments"
@@ -94,9 +95,22 @@ impl ClassMethodInfo {
             return_value,
             arguments,
             default_arguments,
+            method_userdata: std::ptr::null_mut(),
         }
     }
 
+    /// Sets the pointer that Godot passes as first argument to `call_func` and `ptrcall_func`.
+    ///
+    /// # Safety
+    /// `method_userdata` must stay valid for as long as the method is registered, and both functions must interpret it correctly.
+    pub(crate) unsafe fn with_method_userdata(
+        mut self,
+        method_userdata: *mut std::ffi::c_void,
+    ) -> Self {
+        self.method_userdata = method_userdata;
+        self
+    }
+
     pub fn register_extension_class_method(&self) {
         use crate::obj::EngineBitfield as _;
 
@@ -127,7 +141,7 @@ impl ClassMethodInfo {
 
         let method_info_sys = sys::GDExtensionClassMethodInfo {
             name: sys::SysPtr::force_mut(self.method_name.string_sys()),
-            method_userdata: std::ptr::null_mut(),
+            method_userdata: self.method_userdata,
             call_func: self.call_func,
             ptrcall_func: self.ptrcall_func,
             method_flags: self.method_flags.ord() as u32,
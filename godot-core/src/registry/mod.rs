@@ -14,6 +14,7 @@ pub mod constant;
 pub mod method;
 pub mod plugin;
 pub mod property;
+pub mod signal;
 
 // RpcConfig uses MultiplayerPeer::TransferMode and MultiplayerApi::RpcMode, which are only enabled in `codegen-full` feature.
 #[cfg(feature = "codegen-full")]
@@ -31,12 +31,12 @@ pub struct ClassPlugin {
 }
 
 /// Type-erased function object, holding a `register_class` function.
+///
+/// Each function is instantiated for one class `T`, which thus does not appear in the signature. `register_class` functions create the
+/// `ClassBuilder<T>` themselves.
 #[derive(Copy, Clone)]
 pub struct ErasedRegisterFn {
-    // Wrapper needed because Debug can't be derived on function pointers with reference parameters, so this won't work:
-    // pub type ErasedRegisterFn = fn(&mut dyn std::any::Any);
-    // (see https://stackoverflow.com/q/53380040)
-    pub raw: fn(&mut dyn Any),
+    pub raw: fn(),
 }
 
 impl fmt::Debug for ErasedRegisterFn {
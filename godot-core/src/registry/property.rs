@@ -75,6 +75,14 @@ pub trait Export: Var {
     fn as_node_class() -> Option<ClassName> {
         None
     }
+
+    /// Applies an explicitly specified hint, such as `#[export(range = ...)]`, to this type.
+    ///
+    /// For most types, this returns `hint` unchanged. Containers like `Array<T>` override it, so that the hint applies to each element.
+    #[doc(hidden)]
+    fn export_element_hint(hint: PropertyHintInfo) -> PropertyHintInfo {
+        hint
+    }
 }
 
 /// This function only exists as a place to add doc-tests for the `Export` trait.
@@ -152,6 +160,14 @@ where
     fn export_hint() -> PropertyHintInfo {
         T::export_hint()
     }
+
+    fn as_node_class() -> Option<ClassName> {
+        T::as_node_class()
+    }
+
+    fn export_element_hint(hint: PropertyHintInfo) -> PropertyHintInfo {
+        T::export_element_hint(hint)
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -383,6 +399,26 @@ pub mod export_info_functions {
         }
     }
 
+    /// Equivalent to `@export_tool_button` in Godot.
+    ///
+    /// `icon` is the name of an editor theme icon, e.g. `"Callable"`. Pass an empty string to have no icon.
+    #[cfg(since_api = "4.4")]
+    pub fn export_tool_button<S: AsRef<str>, I: AsRef<str>>(text: S, icon: I) -> PropertyHintInfo {
+        let text = text.as_ref();
+        let icon = icon.as_ref();
+
+        let hint_string = if icon.is_empty() {
+            text.to_string()
+        } else {
+            format!("{text},{icon}")
+        };
+
+        PropertyHintInfo {
+            hint: PropertyHint::TOOL_BUTTON,
+            hint_string: hint_string.into(),
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
@@ -480,22 +516,10 @@ mod export_impls {
 
     impl_property_by_godot_convert!(Color);
 
-    // Dictionary: will need to be done manually once they become typed.
-    impl_property_by_godot_convert!(Dictionary);
+    // Dictionary: implemented manually, as it is generic over key and value types.
     impl_property_by_godot_convert!(Variant);
 
-    // Packed arrays: we manually implement `Export`.
-    impl_property_by_godot_convert!(PackedByteArray, no_export);
-    impl_property_by_godot_convert!(PackedInt32Array, no_export);
-    impl_property_by_godot_convert!(PackedInt64Array, no_export);
-    impl_property_by_godot_convert!(PackedFloat32Array, no_export);
-    impl_property_by_godot_convert!(PackedFloat64Array, no_export);
-    impl_property_by_godot_convert!(PackedStringArray, no_export);
-    impl_property_by_godot_convert!(PackedVector2Array, no_export);
-    impl_property_by_godot_convert!(PackedVector3Array, no_export);
-    #[cfg(since_api = "4.3")]
-    impl_property_by_godot_convert!(PackedVector4Array, no_export);
-    impl_property_by_godot_convert!(PackedColorArray, no_export);
+    // Packed arrays: `Var` and `Export` are implemented in `impl_packed_array!`.
 
     // Primitives
     impl_property_by_godot_convert!(f64);
@@ -0,0 +1,16 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Type-safe signals, generated from `#[signal]` declarations.
+
+mod signal_object;
+mod typed_signal;
+mod variadic;
+
+pub use signal_object::{SignalObject, UserSignalObject};
+pub use typed_signal::TypedSignal;
+pub use variadic::{ParamTuple, SignalReceiver};
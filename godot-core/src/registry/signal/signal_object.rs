@@ -0,0 +1,73 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::Object;
+use crate::obj::{Gd, GodotClass, WithBaseField};
+
+/// Abstracts over the object that owns a signal.
+///
+/// Signals can be accessed either from inside a class (via `self.signals()`), or from outside (via `Gd::signals()`). In the former case,
+/// emitting must go through [`base_mut()`][WithBaseField::base_mut], so that connected Rust handlers can re-enter the same object.
+#[doc(hidden)]
+pub trait SignalObject<'c> {
+    /// Runs `f` with exclusive access to the underlying `Object`.
+    fn with_object_mut(&mut self, f: impl FnOnce(&mut Gd<Object>));
+
+    /// Returns a new strong reference to the underlying `Object`.
+    fn to_owned_object(&self) -> Gd<Object>;
+}
+
+/// Signal owner for user-defined classes.
+#[doc(hidden)]
+pub enum UserSignalObject<'c, C> {
+    /// Accessed from `self.signals()` inside the class; re-borrows through `base_mut()`.
+    Internal { self_mut: &'c mut C },
+
+    /// Accessed from `Gd::signals()`.
+    External { gd: Gd<C> },
+}
+
+impl<'c, C> SignalObject<'c> for UserSignalObject<'c, C>
+where
+    C: WithBaseField,
+{
+    fn with_object_mut(&mut self, f: impl FnOnce(&mut Gd<Object>)) {
+        match self {
+            UserSignalObject::Internal { self_mut } => {
+                // Keep guard alive for the duration of `f`, so that Godot can call back into this object.
+                let guard = self_mut.base_mut();
+                let mut object = (*guard).clone().upcast_object();
+                f(&mut object);
+            }
+            UserSignalObject::External { gd } => {
+                let mut object = gd.clone().upcast_object();
+                f(&mut object);
+            }
+        }
+    }
+
+    fn to_owned_object(&self) -> Gd<Object> {
+        match self {
+            UserSignalObject::Internal { self_mut } => {
+                self_mut.base_field().to_gd().upcast_object()
+            }
+            UserSignalObject::External { gd } => gd.clone().upcast_object(),
+        }
+    }
+}
+
+/// Signal owner for engine classes.
+impl<'c, C: GodotClass> SignalObject<'c> for Gd<C> {
+    fn with_object_mut(&mut self, f: impl FnOnce(&mut Gd<Object>)) {
+        let mut object = self.clone().upcast_object();
+        f(&mut object);
+    }
+
+    fn to_owned_object(&self) -> Gd<Object> {
+        self.clone().upcast_object()
+    }
+}
@@ -0,0 +1,285 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::Signal;
+use crate::meta::{FromGodot, ToGodot};
+use crate::obj::{WithBaseField, WithSignals};
+use crate::registry::signal::{ParamTuple, SignalObject};
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+#[cfg(since_api = "4.2")]
+use crate::builtin::{Callable, Variant};
+#[cfg(since_api = "4.2")]
+use crate::meta::error::CallError;
+#[cfg(since_api = "4.2")]
+use crate::meta::{CallContext, TypedFn};
+#[cfg(since_api = "4.2")]
+use crate::obj::{bounds, Bounds, Gd, GodotClass};
+#[cfg(since_api = "4.2")]
+use crate::registry::signal::SignalReceiver;
+#[cfg(since_api = "4.2")]
+use crate::task::SignalFuture;
+
+/// Type-safe version of a Godot signal.
+///
+/// Short-lived type, only valid in the scope of its surrounding object type `C`, for lifetime `'c`. The generic argument `Ps` represents
+/// the parameters of the signal, as a tuple. This ensures that emitting and connecting are checked by the compiler against the signature
+/// declared with `#[signal]`.
+///
+/// Typed signals are obtained through the generated signal collection of a class, e.g. `self.signals().damage_taken()` inside the class
+/// or `gd.signals().damage_taken()` from outside.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     hp: i32,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn damage_taken(amount: i32);
+///
+///     #[func]
+///     fn take_damage(&mut self, amount: i32) {
+///         self.hp -= amount;
+///         self.signals().damage_taken().emit(amount);
+///     }
+///
+///     fn setup(&mut self) {
+///         self.signals().damage_taken().connect(|amount: i32| {
+///             godot_print!("Ouch: {amount}");
+///         });
+///     }
+/// }
+/// ```
+pub struct TypedSignal<'c, C: WithSignals, Ps> {
+    object: C::__SignalObj<'c>,
+    name: Cow<'static, str>,
+    _signature: PhantomData<Ps>,
+}
+
+impl<'c, C, Ps> TypedSignal<'c, C, Ps>
+where
+    C: WithSignals,
+    C::__SignalObj<'c>: SignalObject<'c>,
+    Ps: ParamTuple,
+{
+    #[doc(hidden)]
+    pub fn new(object: C::__SignalObj<'c>, name: &'static str) -> Self {
+        Self {
+            object,
+            name: Cow::Borrowed(name),
+            _signature: PhantomData,
+        }
+    }
+
+    /// Returns the name of the signal.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Emit the signal with the given parameters, packed as a tuple.
+    ///
+    /// This is intended for generic code; usually you would call `emit()` with individual arguments.
+    pub fn emit_tuple(&mut self, args: Ps) {
+        let name = self.name.as_ref();
+
+        self.object.with_object_mut(|obj| {
+            obj.emit_signal(name, &args.to_variant_array());
+        });
+    }
+
+    /// Returns the untyped `Signal` for this typed signal, e.g. for passing it to GDScript.
+    pub fn to_untyped(&self) -> Signal {
+        Signal::from_object_signal(&self.object.to_owned_object(), self.name.as_ref())
+    }
+
+    /// Returns a future that resolves with the signal's arguments when it is next emitted.
+    ///
+    /// Typed signals also implement [`IntoFuture`], so they can be awaited directly. See [`task`][crate::task] for running futures.
+    #[cfg(since_api = "4.2")]
+    pub fn to_future(&self) -> SignalFuture<Ps> {
+        self.to_untyped().to_future()
+    }
+
+    /// Connect a non-member function (global function, associated function or closure).
+    ///
+    /// Example usages:
+    /// ```ignore
+    /// sig.connect(Self::static_func);
+    /// sig.connect(global_func);
+    /// sig.connect(|arg| { /* closure */ });
+    /// ```
+    ///
+    /// To connect to a method of the own object `self`, use [`connect_self()`][Self::connect_self].
+    #[cfg(since_api = "4.2")]
+    pub fn connect<F>(&mut self, mut function: F)
+    where
+        F: SignalReceiver<(), Ps>,
+    {
+        self.inner_connect_local(move |args: Ps| function.call((), args));
+    }
+
+    /// Connect a method (member function) with any `Gd<OtherC>` (not `self`) as first parameter.
+    ///
+    /// To connect to methods on the same object that declares the signal, use [`connect_self()`][Self::connect_self].
+    #[cfg(since_api = "4.2")]
+    pub fn connect_obj<F, OtherC>(&mut self, object: &Gd<OtherC>, mut method: F)
+    where
+        OtherC: GodotClass + Bounds<Declarer = bounds::DeclUser>,
+        for<'c_rcv> F: SignalReceiver<&'c_rcv mut OtherC, Ps>,
+    {
+        let mut gd = object.clone();
+        self.inner_connect_local(move |args: Ps| {
+            let mut guard = gd.bind_mut();
+            method.call(&mut *guard, args);
+        });
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn inner_connect_local<F>(&mut self, rust_fn: F)
+    where
+        F: FnMut(Ps) + 'static,
+    {
+        let signal_name = self.name.as_ref();
+        let handler = SignalHandler {
+            rust_fn,
+            _signature: PhantomData,
+        };
+        let callable = Callable::from_typed_fn(signal_name, handler);
+
+        self.object.with_object_mut(move |obj| {
+            obj.connect(signal_name, &callable);
+        });
+    }
+}
+
+impl<'c, C, Ps> TypedSignal<'c, C, Ps>
+where
+    C: WithSignals + WithBaseField,
+    C::__SignalObj<'c>: SignalObject<'c>,
+    Ps: ParamTuple,
+{
+    /// Connect a method (member function) with `&mut self` as the first parameter.
+    ///
+    /// Example usages:
+    /// ```ignore
+    /// sig.connect_self(Self::on_damage_taken);
+    /// sig.connect_self(|this: &mut Self, amount: i32| this.hp -= amount);
+    /// ```
+    ///
+    /// To connect to methods on other objects, use [`connect_obj()`][Self::connect_obj].
+    #[cfg(since_api = "4.2")]
+    pub fn connect_self<F>(&mut self, method: F)
+    where
+        for<'c_rcv> F: SignalReceiver<&'c_rcv mut C, Ps>,
+    {
+        let gd = self
+            .object
+            .to_owned_object()
+            .owned_cast::<C>()
+            .unwrap_or_else(|_| panic!("signal object is not of class {}", C::class_name()));
+
+        self.connect_obj(&gd, method);
+    }
+}
+
+impl<C: WithSignals, Ps> fmt::Debug for TypedSignal<'_, C, Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedSignal")
+            .field("class", &C::class_name())
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+#[cfg(since_api = "4.2")]
+impl<'c, C, Ps> std::future::IntoFuture for TypedSignal<'c, C, Ps>
+where
+    C: WithSignals,
+    C::__SignalObj<'c>: SignalObject<'c>,
+    Ps: ParamTuple,
+{
+    type Output = Ps;
+    type IntoFuture = SignalFuture<Ps>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        self.to_future()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Function connected to a typed signal.
+///
+/// Arguments which do not match the signal's parameters (e.g. when emitted from GDScript) are reported as a [`CallError`], not a panic.
+#[cfg(since_api = "4.2")]
+struct SignalHandler<F, Ps> {
+    rust_fn: F,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+#[cfg(since_api = "4.2")]
+impl<F, Ps> TypedFn<Ps> for SignalHandler<F, Ps>
+where
+    F: FnMut(Ps) + 'static,
+    Ps: ParamTuple,
+{
+    fn call_varargs(
+        &mut self,
+        call_ctx: &CallContext,
+        args: &[&Variant],
+    ) -> Result<Variant, CallError> {
+        let params = Ps::try_from_variant_array(args)
+            .map_err(|err| CallError::failed_signal_args(call_ctx, err))?;
+
+        (self.rust_fn)(params);
+        Ok(Variant::nil())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Emit methods with individual parameters
+
+macro_rules! impl_typed_signal_emit {
+    ($( $args:ident : $Ps:ident ),*) => {
+        impl<'c, C, $($Ps),*> TypedSignal<'c, C, ($($Ps,)*)>
+        where
+            C: WithSignals,
+            C::__SignalObj<'c>: SignalObject<'c>,
+            $($Ps: ToGodot + FromGodot + fmt::Debug + 'static),*
+        {
+            /// Emit the signal with the given parameters.
+            ///
+            /// Parameter types are checked against the `#[signal]` declaration at compile time.
+            #[allow(clippy::too_many_arguments)]
+            pub fn emit(&mut self, $($args: $Ps),*) {
+                self.emit_tuple(($($args,)*));
+            }
+        }
+    };
+}
+
+impl_typed_signal_emit!();
+impl_typed_signal_emit!(arg0: P0);
+impl_typed_signal_emit!(arg0: P0, arg1: P1);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6, arg7: P7);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6, arg7: P7, arg8: P8);
+impl_typed_signal_emit!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6, arg7: P7, arg8: P8, arg9: P9);
@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Emulates variadic argument lists (via tuples), related to functions and signals.
+
+// https://geo-ant.github.io/blog/2021/rust-traits-and-variadic-functions
+//
+// Could be generalized with R return type, and not special-casing `self`. But keep simple until actually needed.
+
+use crate::builtin::Variant;
+use crate::meta::error::ConvertError;
+use crate::meta::{FromGodot, ToGodot};
+use std::fmt;
+
+/// Represents a parameter list as Rust tuple, where each tuple element is one parameter.
+///
+/// This trait only contains metadata for the parameter list; the actual functions are defined in [`SignalReceiver`].
+pub trait ParamTuple: Sized + 'static {
+    /// Number of parameters in the tuple.
+    const LEN: usize;
+
+    /// Converts the tuple into a list of variants, in declaration order.
+    fn to_variant_array(&self) -> Vec<Variant>;
+
+    /// Converts a list of variants back into a tuple.
+    ///
+    /// Returns an error if the number of variants does not match [`LEN`][Self::LEN], or any of the elements cannot be converted.
+    fn try_from_variant_array(array: &[&Variant]) -> Result<Self, ConvertError>;
+
+    /// Converts a list of variants back into a tuple.
+    ///
+    /// # Panics
+    /// If the conversion fails, see [`try_from_variant_array()`][Self::try_from_variant_array].
+    fn from_variant_array(array: &[&Variant]) -> Self {
+        Self::try_from_variant_array(array)
+            .unwrap_or_else(|err| panic!("signal parameter conversion failed: {err}"))
+    }
+}
+
+/// A function or closure that can be connected to a signal with parameter list `Ps`.
+///
+/// `I` is the receiver instance: `()` for free functions and closures, `&mut C` for methods of a user class `C`.
+///
+/// This trait is implemented for all `FnMut` types with matching parameters (of any return type, which is discarded).
+/// You should not need to implement it yourself.
+pub trait SignalReceiver<I, Ps>: 'static {
+    /// Invokes the receiver with the given instance and parameters.
+    fn call(&mut self, maybe_instance: I, params: Ps);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Generated impls
+
+macro_rules! impl_signal_recipient {
+    ($( $args:ident : $Ps:ident ),*) => {
+        // --------------------------------------------------------------------------------------------------------------------------------------
+        // ParamTuple
+
+        impl<$($Ps),*> ParamTuple for ($($Ps,)*)
+        where
+            $($Ps: ToGodot + FromGodot + fmt::Debug + 'static),*
+        {
+            const LEN: usize = 0 $(+ impl_signal_recipient!(@one $Ps))*;
+
+            fn to_variant_array(&self) -> Vec<Variant> {
+                let ($($args,)*) = self;
+
+                vec![
+                    $( $args.to_variant(), )*
+                ]
+            }
+
+            #[allow(unused_variables, unused_mut)]
+            fn try_from_variant_array(array: &[&Variant]) -> Result<Self, ConvertError> {
+                if array.len() != Self::LEN {
+                    return Err(ConvertError::new(format!(
+                        "signal parameter count mismatch: expected {}, got {}",
+                        Self::LEN,
+                        array.len(),
+                    )));
+                }
+
+                let mut iter = array.iter().enumerate();
+                Ok(( $(
+                    {
+                        let (index, variant) = iter.next().expect("length checked above");
+                        <$Ps>::try_from_variant(variant)
+                            .map_err(|err| err.in_element(format!("at parameter #{index}")))?
+                    },
+                )* ))
+            }
+        }
+
+        // --------------------------------------------------------------------------------------------------------------------------------------
+        // SignalReceiver
+
+        // Global functions and closures.
+        impl<F, R, $($Ps: fmt::Debug + FromGodot + 'static),*> SignalReceiver<(), ( $($Ps,)* )> for F
+            where F: FnMut( $($Ps,)* ) -> R + 'static
+        {
+            fn call(&mut self, _no_instance: (), ($($args,)*): ( $($Ps,)* )) {
+                self($($args,)*);
+            }
+        }
+
+        // Methods with mutable receiver - &mut self.
+        impl<'c, C, F, R, $($Ps: fmt::Debug + FromGodot + 'static),*> SignalReceiver<&'c mut C, ( $($Ps,)* )> for F
+            where F: FnMut( &'c mut C, $($Ps,)* ) -> R + 'static
+        {
+            fn call(&mut self, instance: &'c mut C, ($($args,)*): ( $($Ps,)* )) {
+                self(instance, $($args,)*);
+            }
+        }
+    };
+
+    (@one $Ps:ident) => { 1 };
+}
+
+impl_signal_recipient!();
+impl_signal_recipient!(arg0: P0);
+impl_signal_recipient!(arg0: P0, arg1: P1);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6, arg7: P7);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6, arg7: P7, arg8: P8);
+impl_signal_recipient!(arg0: P0, arg1: P1, arg2: P2, arg3: P3, arg4: P4, arg5: P5, arg6: P6, arg7: P7, arg8: P8, arg9: P9);
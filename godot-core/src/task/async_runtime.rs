@@ -0,0 +1,347 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use crate::builtin::{Callable, Signal, Variant};
+use crate::classes::{Engine, SceneTree};
+use crate::sys;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Starts a new task, which runs the given future on the main thread.
+///
+/// The future is polled for the first time immediately, i.e. it runs synchronously until its first `.await` point that is not ready.
+/// Afterwards, it is resumed by the executor whenever it is woken up; see [module docs][crate::task] for details.
+///
+/// Returns a handle, which can be used to check the task's state or to cancel it. Dropping the handle does _not_ cancel the task.
+///
+/// # Panics
+/// If called from a thread other than the main thread.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    // The runtime is thread-local and only driven from the main loop; tasks spawned elsewhere would never be resumed.
+    assert!(
+        sys::is_main_thread(),
+        "godot::task::spawn() can only be called from the main thread"
+    );
+
+    ensure_frame_hook();
+
+    let id = ASYNC_RUNTIME.with_borrow_mut(|rt| rt.add_task(Box::pin(future)));
+
+    // Initial poll; if we are inside another task, this happens after that task has been polled.
+    schedule(id);
+    poll_woken_tasks();
+
+    TaskHandle { id }
+}
+
+/// Handle to a task started with [`spawn()`].
+///
+/// The handle is only valid on the main thread.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct TaskHandle {
+    id: TaskId,
+}
+
+impl TaskHandle {
+    /// Returns `true` if the task has neither completed nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        ASYNC_RUNTIME.with_borrow(|rt| rt.contains(self.id))
+    }
+
+    /// Cancels the task, dropping its future without polling it again.
+    ///
+    /// Has no effect if the task has already completed or been cancelled. If a task cancels itself, its future is dropped once the
+    /// current poll returns.
+    pub fn cancel(self) {
+        // Drop outside the borrow, as the future's destructor may access the runtime.
+        let _future = ASYNC_RUNTIME.with_borrow_mut(|rt| rt.remove_task(self.id));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal interface
+
+/// Polls all tasks that have been woken up since the last call, including tasks woken during this call.
+///
+/// Does nothing if called re-entrantly, i.e. from within a task. The outer invocation will then poll the woken tasks.
+pub(crate) fn poll_woken_tasks() {
+    if IS_POLLING.get() {
+        return;
+    }
+
+    IS_POLLING.set(true);
+    let _guard = ResetPolling;
+
+    loop {
+        let woken = std::mem::take(&mut *lock_wake_queue());
+        if woken.is_empty() {
+            break;
+        }
+
+        for id in woken {
+            poll_task(id);
+        }
+    }
+}
+
+/// Drops all tasks and disconnects from the main loop. Called when the library is unloaded.
+pub(crate) fn cleanup() {
+    let (tasks, frame_hook) = ASYNC_RUNTIME.with_borrow_mut(|rt| {
+        let tasks = std::mem::take(&mut rt.tasks);
+        rt.free_slots.clear();
+
+        (tasks, rt.frame_hook.take())
+    });
+
+    // Drop futures outside the borrow.
+    drop(tasks);
+    lock_wake_queue().clear();
+
+    if let Some((signal, callable)) = frame_hook {
+        if signal.object().is_some() && signal.is_connected(&callable) {
+            signal.disconnect(&callable);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+thread_local! {
+    static ASYNC_RUNTIME: RefCell<AsyncRuntime> = RefCell::new(AsyncRuntime::new());
+    static IS_POLLING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// IDs of tasks that have been woken up. Wakers may be sent to other threads, so this is global.
+static WAKE_QUEUE: Mutex<Vec<TaskId>> = Mutex::new(Vec::new());
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+struct TaskId {
+    index: usize,
+    generation: u32,
+}
+
+enum TaskState {
+    /// Slot is unused.
+    Vacant,
+
+    /// Task is waiting to be polled.
+    Pending(BoxFuture),
+
+    /// Task is currently being polled; its future has been moved out of the runtime.
+    Polling,
+}
+
+struct TaskSlot {
+    generation: u32,
+    state: TaskState,
+}
+
+struct AsyncRuntime {
+    tasks: Vec<TaskSlot>,
+    free_slots: Vec<usize>,
+
+    /// Connection to `SceneTree::process_frame`, once established.
+    frame_hook: Option<(Signal, Callable)>,
+}
+
+impl AsyncRuntime {
+    fn new() -> Self {
+        Self {
+            tasks: Vec::new(),
+            free_slots: Vec::new(),
+            frame_hook: None,
+        }
+    }
+
+    fn add_task(&mut self, future: BoxFuture) -> TaskId {
+        if let Some(index) = self.free_slots.pop() {
+            let slot = &mut self.tasks[index];
+            slot.generation = slot.generation.wrapping_add(1);
+            slot.state = TaskState::Pending(future);
+
+            TaskId {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            self.tasks.push(TaskSlot {
+                generation: 0,
+                state: TaskState::Pending(future),
+            });
+
+            TaskId {
+                index: self.tasks.len() - 1,
+                generation: 0,
+            }
+        }
+    }
+
+    fn slot_mut(&mut self, id: TaskId) -> Option<&mut TaskSlot> {
+        self.tasks
+            .get_mut(id.index)
+            .filter(|slot| slot.generation == id.generation)
+    }
+
+    fn contains(&self, id: TaskId) -> bool {
+        self.tasks.get(id.index).is_some_and(|slot| {
+            slot.generation == id.generation && !matches!(slot.state, TaskState::Vacant)
+        })
+    }
+
+    /// Moves the future out of the runtime for polling. Returns `None` if the task no longer exists or is already being polled.
+    fn take_for_poll(&mut self, id: TaskId) -> Option<BoxFuture> {
+        let slot = self.slot_mut(id)?;
+
+        match std::mem::replace(&mut slot.state, TaskState::Polling) {
+            TaskState::Pending(future) => Some(future),
+            other => {
+                slot.state = other;
+                None
+            }
+        }
+    }
+
+    /// Moves a polled future back. Returns it if the task has been cancelled in the meantime, so the caller can drop it.
+    fn put_back(&mut self, id: TaskId, future: BoxFuture) -> Option<BoxFuture> {
+        match self.slot_mut(id) {
+            Some(slot) if matches!(slot.state, TaskState::Polling) => {
+                slot.state = TaskState::Pending(future);
+                None
+            }
+            _ => Some(future),
+        }
+    }
+
+    fn remove_task(&mut self, id: TaskId) -> Option<BoxFuture> {
+        let slot = self.slot_mut(id)?;
+
+        let previous = std::mem::replace(&mut slot.state, TaskState::Vacant);
+        if matches!(previous, TaskState::Vacant) {
+            return None;
+        }
+
+        self.free_slots.push(id.index);
+        match previous {
+            TaskState::Pending(future) => Some(future),
+            _ => None,
+        }
+    }
+}
+
+fn poll_task(id: TaskId) {
+    let Some(mut future) = ASYNC_RUNTIME.with_borrow_mut(|rt| rt.take_for_poll(id)) else {
+        // Task completed or was cancelled after being woken.
+        return;
+    };
+
+    // Removes the task if polling panics, so the slot does not stay in `Polling` state forever.
+    let mut guard = RemoveOnDrop { id: Some(id) };
+
+    let waker = Waker::from(Arc::new(TaskWaker { id }));
+    let mut ctx = Context::from_waker(&waker);
+
+    match future.as_mut().poll(&mut ctx) {
+        Poll::Ready(()) => {
+            // Guard removes the task; future is dropped at end of scope.
+        }
+        Poll::Pending => {
+            guard.id = None;
+
+            let cancelled = ASYNC_RUNTIME.with_borrow_mut(|rt| rt.put_back(id, future));
+            drop(cancelled);
+        }
+    }
+}
+
+fn schedule(id: TaskId) {
+    lock_wake_queue().push(id);
+}
+
+fn lock_wake_queue() -> std::sync::MutexGuard<'static, Vec<TaskId>> {
+    // A panic while holding the lock cannot leave the queue in an inconsistent state.
+    WAKE_QUEUE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Connects to `SceneTree::process_frame`, so that tasks woken outside of signal emissions are resumed once per frame.
+fn ensure_frame_hook() {
+    let is_hooked = ASYNC_RUNTIME.with_borrow(|rt| rt.frame_hook.is_some());
+    if is_hooked {
+        return;
+    }
+
+    // If there is no scene tree (yet), try again on the next spawn.
+    let Some(main_loop) = Engine::singleton().get_main_loop() else {
+        return;
+    };
+    let Ok(tree) = main_loop.try_cast::<SceneTree>() else {
+        return;
+    };
+
+    let callable = Callable::from_local_fn("godot::task::poll_woken_tasks", |_args| {
+        poll_woken_tasks();
+        Ok(Variant::nil())
+    });
+
+    let signal = Signal::from_object_signal(&tree, "process_frame");
+    signal.connect(&callable, 0);
+
+    ASYNC_RUNTIME.with_borrow_mut(|rt| rt.frame_hook = Some((signal, callable)));
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helper types
+
+struct TaskWaker {
+    id: TaskId,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        schedule(self.id);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        schedule(self.id);
+    }
+}
+
+struct RemoveOnDrop {
+    id: Option<TaskId>,
+}
+
+impl Drop for RemoveOnDrop {
+    fn drop(&mut self) {
+        if let Some(id) = self.id {
+            // Runtime may already be destroyed (thread exit); then there is nothing to clean up.
+            let _ = ASYNC_RUNTIME.try_with(|rt| {
+                if let Ok(mut rt) = rt.try_borrow_mut() {
+                    rt.remove_task(id);
+                }
+            });
+        }
+    }
+}
+
+struct ResetPolling;
+
+impl Drop for ResetPolling {
+    fn drop(&mut self) {
+        IS_POLLING.set(false);
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, RustCallable, Signal, Variant};
+use crate::classes::object::ConnectFlags;
+use crate::obj::EngineBitfield;
+use crate::registry::signal::ParamTuple;
+use crate::task::async_runtime;
+
+/// Future that resolves when a signal is emitted, yielding the signal arguments as a tuple `R`.
+///
+/// Created with [`Signal::to_future()`] or [`TypedSignal::to_future()`][crate::registry::signal::TypedSignal::to_future]. The signal is
+/// connected for a single emission; dropping the future before that disconnects it again.
+///
+/// # Panics
+/// When polled after the signal's object has been freed without emitting the signal, or if the signal arguments cannot be converted to `R`.
+pub struct SignalFuture<R: ParamTuple> {
+    id: u64,
+    signal: Signal,
+    _signature: PhantomData<R>,
+}
+
+impl<R: ParamTuple> SignalFuture<R> {
+    pub(crate) fn new(signal: Signal) -> Self {
+        let id = NEXT_FUTURE_ID.get();
+        NEXT_FUTURE_ID.set(id + 1);
+
+        FUTURE_STATES.with_borrow_mut(|states| states.insert(id, SignalFutureState::Pending(None)));
+
+        let resolver = Callable::from_custom(SignalFutureResolver { id });
+        signal.connect(&resolver, ConnectFlags::ONE_SHOT.ord() as i64);
+
+        Self {
+            id,
+            signal,
+            _signature: PhantomData,
+        }
+    }
+}
+
+impl<R: ParamTuple> Future for SignalFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let id = self.id;
+
+        // Only convert arguments after the borrow is released, as conversions may run arbitrary code.
+        let args = FUTURE_STATES.with_borrow_mut(|states| {
+            let state = states
+                .get_mut(&id)
+                .expect("SignalFuture polled after completion");
+
+            let args = match state {
+                SignalFutureState::Pending(waker) => {
+                    *waker = Some(cx.waker().clone());
+                    return None;
+                }
+                SignalFutureState::Ready(args) => std::mem::take(args),
+                SignalFutureState::Dead => {
+                    panic!("SignalFuture: signal object was freed before the signal was emitted")
+                }
+            };
+
+            states.remove(&id);
+            Some(args)
+        });
+
+        match args {
+            Some(args) => {
+                let arg_refs: Vec<&Variant> = args.iter().collect();
+                Poll::Ready(R::from_variant_array(&arg_refs))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<R: ParamTuple> Drop for SignalFuture<R> {
+    fn drop(&mut self) {
+        let was_tracked = FUTURE_STATES
+            .try_with(|states| states.borrow_mut().remove(&self.id).is_some())
+            .unwrap_or(false);
+
+        // If the signal has not been emitted yet, the one-shot connection is still active.
+        if was_tracked && self.signal.object().is_some() {
+            let resolver = Callable::from_custom(SignalFutureResolver { id: self.id });
+
+            if self.signal.is_connected(&resolver) {
+                self.signal.disconnect(&resolver);
+            }
+        }
+    }
+}
+
+impl<R: ParamTuple> fmt::Debug for SignalFuture<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalFuture")
+            .field("signal", &self.signal)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+thread_local! {
+    static FUTURE_STATES: RefCell<HashMap<u64, SignalFutureState>> = RefCell::new(HashMap::new());
+    static NEXT_FUTURE_ID: Cell<u64> = const { Cell::new(0) };
+}
+
+enum SignalFutureState {
+    /// Signal not yet emitted; stores waker of the last poll.
+    Pending(Option<Waker>),
+
+    /// Signal emitted with the given arguments.
+    Ready(Vec<Variant>),
+
+    /// Signal's object was destroyed before emission.
+    Dead,
+}
+
+/// Callable connected to the signal, storing the arguments of the emission.
+///
+/// Equality is based on the ID, so a new instance can be used to disconnect the original one.
+#[derive(PartialEq, Hash)]
+struct SignalFutureResolver {
+    id: u64,
+}
+
+impl fmt::Display for SignalFutureResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SignalFuture::resolver")
+    }
+}
+
+impl RustCallable for SignalFutureResolver {
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        let waker = FUTURE_STATES.with_borrow_mut(|states| {
+            // Future may have been dropped in the meantime.
+            let state = states.get_mut(&self.id)?;
+
+            let args = args.iter().map(|&arg| arg.clone()).collect();
+            match std::mem::replace(state, SignalFutureState::Ready(args)) {
+                SignalFutureState::Pending(waker) => waker,
+                _ => None,
+            }
+        });
+
+        if let Some(waker) = waker {
+            waker.wake();
+
+            // Resume the awaiting task right away, like GDScript's `await` does.
+            async_runtime::poll_woken_tasks();
+        }
+
+        Ok(Variant::nil())
+    }
+}
+
+impl Drop for SignalFutureResolver {
+    fn drop(&mut self) {
+        // Destroyed either after the one-shot emission, on disconnect, or when the signal's object is freed. Only the latter is relevant.
+        // No polling here: this may run during object destruction in Godot. The task is resumed by the executor on the next frame.
+        let _ = FUTURE_STATES.try_with(|states| {
+            let Ok(mut states) = states.try_borrow_mut() else {
+                return;
+            };
+
+            let Some(state @ SignalFutureState::Pending(_)) = states.get_mut(&self.id) else {
+                return;
+            };
+
+            if let SignalFutureState::Pending(Some(waker)) =
+                std::mem::replace(state, SignalFutureState::Dead)
+            {
+                waker.wake();
+            }
+        });
+    }
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Integrates Rust `async`/`.await` with Godot's main loop and signals.
+//!
+//! Futures are run by a single-threaded executor on the main thread. Tasks are started with [`spawn()`], and resume whenever their wakers
+//! are invoked:
+//! - If a task awaits a [`SignalFuture`], it resumes immediately when the signal is emitted -- similar to `await` in GDScript.
+//! - Any other wake-up (e.g. from a different thread) is processed once per frame, during `SceneTree::process_frame`.
+//!
+//! # Example
+//! ```no_run
+//! use godot::prelude::*;
+//! use godot::classes::Timer;
+//! use godot::task;
+//!
+//! fn start_sequence(mut timer: Gd<Timer>) {
+//!     task::spawn(async move {
+//!         godot_print!("Starting...");
+//!
+//!         timer.start();
+//!         timer.signals().timeout().to_future().await;
+//!
+//!         godot_print!("Timer elapsed!");
+//!     });
+//! }
+//! ```
+//!
+//! Only available from Godot 4.2 on, since it relies on custom callables.
+
+#[cfg(since_api = "4.2")]
+mod async_runtime;
+#[cfg(since_api = "4.2")]
+mod futures;
+
+#[cfg(since_api = "4.2")]
+pub use async_runtime::{spawn, TaskHandle};
+#[cfg(since_api = "4.2")]
+pub use futures::SignalFuture;
+
+#[cfg(since_api = "4.2")]
+pub(crate) use async_runtime::cleanup;
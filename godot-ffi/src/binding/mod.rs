@@ -214,6 +214,13 @@ pub fn is_initialized() -> bool {
     BindingStorage::is_initialized()
 }
 
+/// Whether the current thread is the one on which the binding was initialized, i.e. Godot's main thread.
+///
+/// Returns `false` if the binding has not been initialized yet.
+pub fn is_main_thread() -> bool {
+    BindingStorage::is_main_thread()
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Crate-local implementation
 
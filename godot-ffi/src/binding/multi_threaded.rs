@@ -12,11 +12,14 @@
 //! The user of these structs and functions must still ensure that multithreaded usage of the various pointers is safe.
 
 use std::sync::OnceLock;
+use std::thread::ThreadId;
 
 use super::GodotBinding;
 use crate::ManualInitCell;
 
 pub(super) struct BindingStorage {
+    /// Thread on which the binding was first initialized. Hot reloading happens on the same thread, so it is never reset.
+    main_thread_id: OnceLock<ThreadId>,
     binding: ManualInitCell<GodotBinding>,
 }
 
@@ -25,6 +28,7 @@ impl BindingStorage {
     #[inline(always)]
     fn storage() -> &'static Self {
         static BINDING: BindingStorage = BindingStorage {
+            main_thread_id: OnceLock::new(),
             binding: ManualInitCell::new(),
         };
         &BINDING
@@ -43,6 +47,10 @@ impl BindingStorage {
             "initialize must only be called at startup or after deinitialize"
         );
 
+        storage
+            .main_thread_id
+            .get_or_init(|| std::thread::current().id());
+
         // SAFETY: per declared invariants.
         unsafe { storage.binding.set(binding) }
     }
@@ -85,6 +93,11 @@ impl BindingStorage {
         let storage = Self::storage();
         storage.binding.is_initialized()
     }
+
+    pub fn is_main_thread() -> bool {
+        let storage = Self::storage();
+        storage.main_thread_id.get() == Some(&std::thread::current().id())
+    }
 }
 
 pub struct GdextConfig {
@@ -185,6 +185,20 @@ impl BindingStorage {
 
         storage.initialized()
     }
+
+    pub fn is_main_thread() -> bool {
+        // Without threads, all code runs on the main thread.
+        #[cfg(wasm_nothreads)]
+        return true;
+
+        #[cfg(not(wasm_nothreads))]
+        {
+            // SAFETY: We don't access the binding.
+            let storage = unsafe { Self::storage() };
+
+            storage.main_thread_id.get() == Some(std::thread::current().id())
+        }
+    }
 }
 
 // SAFETY: We ensure that `binding` is only ever accessed from the same thread that initialized it.
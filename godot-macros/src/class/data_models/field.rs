@@ -16,6 +16,8 @@ pub struct Field {
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
     pub is_onready: bool,
+    /// Inspector categories/groups starting at this field, in registration order.
+    pub groups: Vec<FieldGroup>,
     #[cfg(feature = "register-docs")]
     pub attributes: Vec<venial::Attribute>,
     pub span: Span,
@@ -30,6 +32,7 @@ impl Field {
             var: None,
             export: None,
             is_onready: false,
+            groups: vec![],
             #[cfg(feature = "register-docs")]
             attributes: field.attributes.clone(),
             span: field.span(),
@@ -62,3 +65,31 @@ impl ToTokens for FieldDefault {
         self.default_val.to_tokens(tokens)
     }
 }
+
+/// `#[export_category]`, `#[export_group]` or `#[export_subgroup]` attribute on a field.
+pub struct FieldGroup {
+    pub kind: FieldGroupKind,
+    pub name: TokenStream,
+    pub prefix: Option<TokenStream>,
+    pub span: Span,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum FieldGroupKind {
+    Category,
+    Group,
+    Subgroup,
+}
+
+impl FieldGroupKind {
+    /// All kinds, in the order in which they are registered before a property.
+    pub const ALL: [Self; 3] = [Self::Category, Self::Group, Self::Subgroup];
+
+    pub fn attr_name(self) -> &'static str {
+        match self {
+            Self::Category => "export_category",
+            Self::Group => "export_group",
+            Self::Subgroup => "export_subgroup",
+        }
+    }
+}
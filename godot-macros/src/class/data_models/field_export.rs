@@ -9,7 +9,7 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::collections::{HashMap, HashSet};
 
-use crate::util::{KvParser, ListParser};
+use crate::util::{bail, require_api_version, KvParser, ListParser};
 use crate::ParseResult;
 
 pub struct FieldExport {
@@ -136,6 +136,28 @@ pub enum ExportType {
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export_storage`
+    ///
+    /// ### Property hints
+    /// - `NONE` (usually)
+    ///
+    /// Like [`Default`][Self::Default], but only stored, not shown in the editor.
+    Storage,
+
+    /// ### GDScript annotations
+    /// - `@export_tool_button`
+    ///
+    /// ### Property hints
+    /// - `TOOL_BUTTON`
+    ///
+    /// Read-only property, whose getter returns a callable to the `#[func]` named `method`.
+    ToolButton {
+        method: Ident,
+        text: TokenStream,
+        icon: Option<TokenStream>,
+    },
 }
 
 impl ExportType {
@@ -270,9 +292,59 @@ impl ExportType {
             return Ok(Self::ColorNoAlpha);
         }
 
+        if parser.handle_alone("storage")? {
+            return Ok(Self::Storage);
+        }
+
+        let span = parser.span();
+        if let Some(list_parser) = parser.handle_list("tool_button")? {
+            require_api_version!("4.4", span, "#[export(tool_button)]")?;
+            return Self::new_tool_button(list_parser, span);
+        }
+
         Ok(Self::Default)
     }
 
+    fn new_tool_button(mut parser: ListParser, span: Span) -> ParseResult<Self> {
+        let Some(method) = parser.next_ident()? else {
+            return bail!(
+                span,
+                "expected `tool_button = (method_name, \"text\")` or `tool_button = (method_name, \"text\", \"icon\")`"
+            );
+        };
+
+        let text = parser.next_expr()?;
+        let icon = if parser.peek().is_some() {
+            Some(parser.next_expr()?)
+        } else {
+            None
+        };
+
+        parser.finish()?;
+
+        Ok(Self::ToolButton { method, text, icon })
+    }
+
+    /// Name of the method invoked by a tool button, if this is one.
+    pub fn tool_button_method(&self) -> Option<&Ident> {
+        match self {
+            Self::ToolButton { method, .. } => Some(method),
+            _ => None,
+        }
+    }
+
+    /// Usage flags, unless overridden by `#[var(usage_flags = ...)]`.
+    pub fn usage_flags(&self) -> TokenStream {
+        let flags = quote! { ::godot::global::PropertyUsageFlags };
+
+        match self {
+            Self::Storage => quote! { #flags::STORAGE },
+            // Button in the inspector; there is no value to be saved.
+            Self::ToolButton { .. } => quote! { #flags::EDITOR },
+            _ => quote! { #flags::DEFAULT },
+        }
+    }
+
     fn new_range_list(mut parser: ListParser) -> ParseResult<Self> {
         const FLAG_OPTIONS: [&str; 7] = [
             "or_greater",
@@ -418,13 +490,13 @@ impl ExportType {
                     export_range(#min, #max, #step, #or_greater, #or_less, #exp, #radians_as_degrees || #radians, #degrees, #hide_slider, #suffix)
                 }?;
                 let deprecation_warning = if *radians {
-                    // For some reason, rustfmt formatting like this.  Probably a bug.
-                    // See https://github.com/godot-rust/gdext/pull/783#discussion_r1669105958 and
-                    // https://github.com/rust-lang/rustfmt/issues/6233
+                    // Block expression, since the hint is passed on as an argument.
                     quote! {
-                    #export_func;
-                    ::godot::__deprecated::emit_deprecated_warning!(export_range_radians);
-                            }
+                        {
+                            ::godot::__deprecated::emit_deprecated_warning!(export_range_radians);
+                            #export_func
+                        }
+                    }
                 } else {
                     quote! { #export_func }
                 };
@@ -511,6 +583,14 @@ impl ExportType {
                 export_placeholder(#placeholder)
             },
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            Self::Storage => None,
+
+            Self::ToolButton { text, icon, .. } => {
+                let icon = icon.clone().unwrap_or(quote! { "" });
+
+                quote_export_func! { export_tool_button(#text, #icon) }
+            }
         }
     }
 }
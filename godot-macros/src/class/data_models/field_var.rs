@@ -200,6 +200,31 @@ impl GetterSetterImpl {
             }
         }
 
+        Self::from_generated_signature(class_name, function_name, signature, function_body)
+    }
+
+    /// Getter of a `#[export(tool_button = ...)]` property, returning a callable for the method to invoke on click.
+    pub(super) fn from_tool_button(class_name: &Ident, field: &Field, method_name: &Ident) -> Self {
+        let function_name = format_ident!("{}{}", GetSet::Get.prefix(), field.name);
+        let method_name = method_name.to_string();
+
+        let signature = quote! {
+            fn #function_name(&self) -> ::godot::builtin::Callable
+        };
+        let function_body = quote! {
+            let this = <Self as ::godot::obj::WithBaseField>::to_gd(self);
+            ::godot::builtin::Callable::from_object_method(&this, #method_name)
+        };
+
+        Self::from_generated_signature(class_name, function_name, signature, function_body)
+    }
+
+    fn from_generated_signature(
+        class_name: &Ident,
+        function_name: Ident,
+        signature: TokenStream,
+        function_body: TokenStream,
+    ) -> Self {
         let function_impl = quote! {
             pub #signature {
                 #function_body
@@ -220,6 +245,8 @@ impl GetterSetterImpl {
                 registered_name: None,
                 is_script_virtual: false,
                 rpc_info: None,
+                default_parameters: Vec::new(),
+                is_vararg: false,
             },
         );
 
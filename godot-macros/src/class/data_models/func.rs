@@ -8,7 +8,7 @@
 use crate::class::RpcAttr;
 use crate::util::{bail_fn, ident, safe_ident};
 use crate::{util, ParseResult};
-use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use proc_macro2::{Delimiter, Group, Ident, TokenStream, TokenTree};
 use quote::{format_ident, quote};
 
 /// Information used for registering a Rust function with Godot.
@@ -29,6 +29,12 @@ pub struct FuncDefinition {
 
     /// Information about the RPC configuration, if provided.
     pub rpc_info: Option<RpcAttr>,
+
+    /// Default value expressions from `#[opt(default = ...)]`, for the last `default_parameters.len()` parameters.
+    pub default_parameters: Vec<TokenStream>,
+
+    /// True for `#[func(vararg)]` functions. The variadic parameter is not part of [`signature_info`].
+    pub is_vararg: bool,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -41,7 +47,7 @@ pub fn make_virtual_callback(
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
 
-    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_kind);
+    let wrapped_method = make_forwarding_closure(class_name, &signature_info, before_kind, false);
     let sig_tuple = signature_info.tuple_type();
 
     let call_ctx = make_call_context(
@@ -80,13 +86,18 @@ pub fn make_method_registration(
     let sig_tuple = signature_info.tuple_type();
 
     let is_script_virtual = func_definition.is_script_virtual;
-    let method_flags = match make_method_flags(signature_info.receiver_type, is_script_virtual) {
-        Ok(mf) => mf,
-        Err(msg) => return bail_fn(msg, &signature_info.method_name),
-    };
+    let is_vararg = func_definition.is_vararg;
+    let method_flags =
+        match make_method_flags(signature_info.receiver_type, is_script_virtual, is_vararg) {
+            Ok(mf) => mf,
+            Err(msg) => return bail_fn(msg, &signature_info.method_name),
+        };
 
     let forwarding_closure =
-        make_forwarding_closure(class_name, signature_info, BeforeKind::Without);
+        make_forwarding_closure(class_name, signature_info, BeforeKind::Without, is_vararg);
+
+    let has_defaults = !func_definition.default_parameters.is_empty();
+    let default_args_fn_decl = make_default_args_fn(class_name, &func_definition);
 
     // String literals
     let method_name = &signature_info.method_name;
@@ -98,8 +109,17 @@ pub fn make_method_registration(
     };
 
     let call_ctx = make_call_context(&class_name_str, &method_name_str);
-    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure);
-    let ptrcall_fn_decl = make_ptrcall_fn(&call_ctx, &forwarding_closure);
+    let varcall_fn_decl = make_varcall_fn(&call_ctx, &forwarding_closure, has_defaults, is_vararg);
+
+    // Godot never ptrcalls vararg methods.
+    let (ptrcall_fn_decl, ptrcall_fn) = if is_vararg {
+        (TokenStream::new(), quote! { None })
+    } else {
+        (
+            make_ptrcall_fn(&call_ctx, &forwarding_closure),
+            quote! { Some(ptrcall_fn) },
+        )
+    };
 
     // String literals II
     let param_ident_strs = signature_info
@@ -107,6 +127,12 @@ pub fn make_method_registration(
         .iter()
         .map(|ident| ident.to_string());
 
+    let default_args = if has_defaults {
+        quote! { make_default_args() }
+    } else {
+        quote! { Vec::new() }
+    };
+
     // Transport #[cfg] attrs to the FFI glue to ensure functions which were conditionally
     // removed from compilation don't cause errors.
     let cfg_attrs = util::extract_cfg_attrs(&func_definition.external_attributes)
@@ -125,6 +151,7 @@ pub fn make_method_registration(
 
             let method_name = StringName::from(#method_name_str);
 
+            #default_args_fn_decl
             #varcall_fn_decl;
             #ptrcall_fn_decl;
 
@@ -133,11 +160,12 @@ pub fn make_method_registration(
                 ClassMethodInfo::from_signature::<#class_name, Sig>(
                     method_name,
                     Some(varcall_fn),
-                    Some(ptrcall_fn),
+                    #ptrcall_fn,
                     #method_flags,
                     &[
                         #( #param_ident_strs ),*
                     ],
+                    #default_args,
                 )
             };
 
@@ -171,7 +199,10 @@ pub struct SignatureInfo {
     pub method_name: Ident,
     pub receiver_type: ReceiverType,
     pub param_idents: Vec<Ident>,
+    /// Parameter types, with `&` removed for borrowed parameters.
     pub param_types: Vec<venial::TypeExpr>,
+    /// For each parameter, whether it is declared as shared reference `&T`.
+    pub borrowed_params: Vec<bool>,
     pub ret_type: TokenStream,
 }
 
@@ -182,13 +213,32 @@ impl SignatureInfo {
             receiver_type: ReceiverType::Mut,
             param_idents: vec![],
             param_types: vec![],
+            borrowed_params: vec![],
             ret_type: quote! { () },
         }
     }
 
     pub fn tuple_type(&self) -> TokenStream {
         // Note: for GdSelf receivers, first parameter is not even part of SignatureInfo anymore.
-        util::make_signature_tuple_type(&self.ret_type, &self.param_types)
+        // Borrowed parameters are received through ParamRef<T>, which avoids ref-count increments and copies in ptrcalls.
+        let param_types: Vec<venial::TypeExpr> = self
+            .param_types
+            .iter()
+            .zip(&self.borrowed_params)
+            .map(|(ty, &is_borrowed)| {
+                if is_borrowed {
+                    venial::TypeExpr {
+                        tokens: quote! { ::godot::meta::ParamRef<#ty> }
+                            .into_iter()
+                            .collect(),
+                    }
+                } else {
+                    ty.clone()
+                }
+            })
+            .collect();
+
+        util::make_signature_tuple_type(&self.ret_type, &param_types)
     }
 }
 
@@ -208,10 +258,31 @@ fn make_forwarding_closure(
     class_name: &Ident,
     signature_info: &SignatureInfo,
     before_kind: BeforeKind,
+    is_vararg: bool,
 ) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
+    // Borrowed parameters are stored as ParamRef<T> in the tuple; `&param` deref-coerces to `&T`.
+    let args: Vec<TokenStream> = params
+        .iter()
+        .zip(&signature_info.borrowed_params)
+        .map(|(param, &is_borrowed)| {
+            if is_borrowed {
+                quote! { &#param }
+            } else {
+                quote! { #param }
+            }
+        })
+        .collect();
+
+    // Variadic arguments are passed to the closure separately, and forwarded as last argument.
+    let (varargs_param, varargs_arg) = if is_vararg {
+        (quote! { , __varargs }, quote! { , __varargs })
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
             let instance = ::godot::private::Storage::get(storage);
@@ -237,11 +308,11 @@ fn make_forwarding_closure(
             let method_call = if matches!(before_kind, BeforeKind::OnlyBefore) {
                 TokenStream::new()
             } else {
-                quote! { instance.#method_name( #(#params),* ) }
+                quote! { instance.#method_name( #(#args),* #varargs_arg ) }
             };
 
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
@@ -257,23 +328,23 @@ fn make_forwarding_closure(
             // Method call is always present, since GdSelf implies that the user declares the method.
             // (Absent method is only used in the case of a generated default virtual method, e.g. for ready()).
             quote! {
-                |instance_ptr, params| {
+                |instance_ptr, params #varargs_param| {
                     let ( #(#params,)* ) = params;
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
 
                     #before_method_call
-                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#params),*)
+                    #class_name::#method_name(::godot::private::Storage::get_gd(storage), #(#args),* #varargs_arg)
                 }
             }
         }
         ReceiverType::Static => {
             // No before-call needed, since static methods are not virtual.
             quote! {
-                |_, params| {
+                |_, params #varargs_param| {
                     let ( #(#params,)* ) = params;
-                    #class_name::#method_name(#(#params),*)
+                    #class_name::#method_name(#(#args),* #varargs_arg)
                 }
             }
         }
@@ -318,6 +389,7 @@ pub(crate) fn into_signature_info(
     let num_params = signature.params.inner.len();
     let mut param_idents = Vec::with_capacity(num_params);
     let mut param_types = Vec::with_capacity(num_params);
+    let mut borrowed_params = Vec::with_capacity(num_params);
     let ret_type = match signature.return_ty {
         None => quote! { () },
         Some(ty) => map_self_to_class_name(ty.tokens, class_name),
@@ -342,11 +414,16 @@ pub(crate) fn into_signature_info(
             }
             venial::FnParam::Typed(arg) => {
                 let ident = maybe_rename_parameter(arg.name, &mut next_unnamed_index);
+                let (tokens, is_borrowed) = match strip_shared_ref(&arg.ty.tokens) {
+                    Some(inner) => (inner.to_vec(), true),
+                    None => (arg.ty.tokens, false),
+                };
                 let ty = venial::TypeExpr {
-                    tokens: map_self_to_class_name(arg.ty.tokens, class_name),
+                    tokens: map_self_to_class_name(tokens, class_name),
                 };
 
                 param_types.push(ty);
+                borrowed_params.push(is_borrowed);
                 param_idents.push(ident);
             }
         }
@@ -357,10 +434,47 @@ pub(crate) fn into_signature_info(
         receiver_type,
         param_idents,
         param_types,
+        borrowed_params,
         ret_type,
     }
 }
 
+/// If the type is a shared reference `&T` or `&'a T`, returns the tokens of `T`.
+fn strip_shared_ref(tokens: &[TokenTree]) -> Option<&[TokenTree]> {
+    let rest = match tokens {
+        [TokenTree::Punct(amp), rest @ ..] if amp.as_char() == '&' => rest,
+        _ => return None,
+    };
+
+    // Skip lifetime, if present.
+    let rest = match rest {
+        [TokenTree::Punct(tick), TokenTree::Ident(_), rest @ ..] if tick.as_char() == '\'' => rest,
+        _ => rest,
+    };
+
+    match rest {
+        [] => None,
+        [TokenTree::Ident(kw), ..] if kw == "mut" => None,
+        _ => Some(rest),
+    }
+}
+
+/// Whether the type is `&[&Variant]`, as required for the variadic parameter of `#[func(vararg)]`. Allows lifetimes and paths to `Variant`.
+pub fn is_variant_ref_slice(tokens: &[TokenTree]) -> bool {
+    let Some([TokenTree::Group(slice)]) = strip_shared_ref(tokens) else {
+        return false;
+    };
+    if slice.delimiter() != Delimiter::Bracket {
+        return false;
+    }
+
+    let elem_tokens: Vec<TokenTree> = slice.stream().into_iter().collect();
+    matches!(
+        strip_shared_ref(&elem_tokens),
+        Some([.., TokenTree::Ident(ty)]) if ty == "Variant"
+    )
+}
+
 pub(crate) fn maybe_rename_parameter(param_ident: Ident, next_unnamed_index: &mut i32) -> Ident {
     // Parameter will be forwarded as an argument to the instance, so we need to give `_` a name.
     let param_str = param_ident.to_string(); // a pity that Ident has no string operations.
@@ -383,6 +497,7 @@ pub(crate) fn maybe_rename_parameter(param_ident: Ident, next_unnamed_index: &mu
 fn make_method_flags(
     method_type: ReceiverType,
     is_script_virtual: bool,
+    is_vararg: bool,
 ) -> Result<TokenStream, String> {
     let flags = quote! { ::godot::global::MethodFlags };
 
@@ -404,18 +519,77 @@ fn make_method_flags(
         }
     };
 
-    let flags = if is_script_virtual {
+    let base_flags = if is_script_virtual {
         quote! { #base_flags | #flags::VIRTUAL }
     } else {
         base_flags
     };
 
+    let flags = if is_vararg {
+        quote! { #base_flags | #flags::VARARG }
+    } else {
+        base_flags
+    };
+
     Ok(flags)
 }
 
+/// Generate code for a function returning the values of `#[opt(default = ...)]` parameters, or nothing if there are none.
+fn make_default_args_fn(class_name: &Ident, func_definition: &FuncDefinition) -> TokenStream {
+    let default_exprs = &func_definition.default_parameters;
+    if default_exprs.is_empty() {
+        return TokenStream::new();
+    }
+
+    let param_types = &func_definition.signature_info.param_types;
+    let default_types = &param_types[param_types.len() - default_exprs.len()..];
+
+    // Expressions are evaluated outside the impl block, where `Self` is not available.
+    let default_exprs = default_exprs
+        .iter()
+        .map(|expr| map_self_to_class_name::<_, TokenStream>(expr.clone(), class_name));
+
+    quote! {
+        fn make_default_args() -> Vec<Variant> {
+            vec![
+                #({
+                    let value: #default_types = #default_exprs;
+                    ::godot::meta::ToGodot::to_variant(&value)
+                }),*
+            ]
+        }
+    }
+}
+
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> TokenStream {
-    let invocation = make_varcall_invocation(wrapped_method);
+fn make_varcall_fn(
+    call_ctx: &TokenStream,
+    wrapped_method: &TokenStream,
+    has_defaults: bool,
+    is_vararg: bool,
+) -> TokenStream {
+    let invocation = if is_vararg {
+        make_varcall_vararg_invocation(wrapped_method)
+    } else {
+        make_varcall_invocation(wrapped_method)
+    };
+
+    // Godot does not fill in default arguments for varcalls; evaluate them only if needed (inside panic handler).
+    let default_args_decl = if is_vararg {
+        TokenStream::new()
+    } else if has_defaults {
+        quote! {
+            let default_args = if (arg_count as usize) < <Sig as ::godot::meta::VarcallSignatureTuple>::PARAM_COUNT {
+                make_default_args()
+            } else {
+                Vec::new()
+            };
+        }
+    } else {
+        quote! {
+            let default_args: Vec<Variant> = Vec::new();
+        }
+    };
 
     // TODO reduce amount of code generated, by delegating work to a library function. Could even be one that produces this function pointer.
     quote! {
@@ -431,7 +605,10 @@ fn make_varcall_fn(call_ctx: &TokenStream, wrapped_method: &TokenStream) -> Toke
             ::godot::private::handle_varcall_panic(
                 &call_ctx,
                 &mut *err,
-                || #invocation
+                || {
+                    #default_args_decl
+                    #invocation
+                }
             );
         }
     }
@@ -485,6 +662,22 @@ fn make_ptrcall_invocation(wrapped_method: &TokenStream, is_virtual: bool) -> To
 fn make_varcall_invocation(wrapped_method: &TokenStream) -> TokenStream {
     quote! {
         <Sig as ::godot::meta::VarcallSignatureTuple>::in_varcall(
+            instance_ptr,
+            &call_ctx,
+            args_ptr,
+            arg_count,
+            &default_args,
+            ret,
+            err,
+            #wrapped_method,
+        )
+    }
+}
+
+/// Generate code for a `varcall()` call expression of a `#[func(vararg)]` function.
+fn make_varcall_vararg_invocation(wrapped_method: &TokenStream) -> TokenStream {
+    quote! {
+        <Sig as ::godot::meta::VarcallSignatureTuple>::in_varcall_vararg(
             instance_ptr,
             &call_ctx,
             args_ptr,
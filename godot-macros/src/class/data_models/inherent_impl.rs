@@ -6,9 +6,9 @@
  */
 
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    into_signature_info, is_variant_ref_slice, make_constant_registration,
+    make_method_registration, make_signal_collection, make_signal_registrations, ConstDefinition,
+    FuncDefinition, ReceiverType, RpcAttr, RpcMode, SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{bail, c_str, ident, require_api_version, KvParser};
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
@@ -22,6 +22,7 @@ enum ItemAttrType {
     Func(FuncAttr, Option<RpcAttr>),
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
+    Property(PropertyAttr),
 }
 
 struct ItemAttr {
@@ -41,6 +42,7 @@ enum AttrParseResult {
     FuncRpc(FuncAttr, RpcAttr),
     Signal(venial::AttributeValue),
     Const(#[allow(dead_code)] venial::AttributeValue),
+    Property(PropertyAttr),
 }
 
 impl AttrParseResult {
@@ -52,6 +54,7 @@ impl AttrParseResult {
             AttrParseResult::FuncRpc(func, rpc) => ItemAttrType::Func(func, Some(rpc)),
             AttrParseResult::Signal(signal) => ItemAttrType::Signal(signal),
             AttrParseResult::Const(constant) => ItemAttrType::Const(constant),
+            AttrParseResult::Property(property) => ItemAttrType::Property(property),
         }
     }
 }
@@ -61,6 +64,21 @@ struct FuncAttr {
     pub rename: Option<String>,
     pub is_virtual: bool,
     pub has_gd_self: bool,
+    pub is_vararg: bool,
+}
+
+/// Attribute `#[property(get, ...)]` on a method, declaring a computed property.
+struct PropertyAttr {
+    pub rename: Option<String>,
+    pub setter: Option<Ident>,
+}
+
+/// Property without backing field, whose getter is a method.
+struct ComputedPropertyDefinition {
+    property_name: String,
+    getter_name: String,
+    setter: Option<Ident>,
+    ty: TokenStream,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -81,14 +99,26 @@ pub fn transform_inherent_impl(
     let prv = quote! { ::godot::private };
 
     // Can add extra functions to the end of the impl block.
-    let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
+    let (funcs, signals, computed_properties) =
+        process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
     let consts = process_godot_constants(&mut impl_block)?;
 
+    // Setters are looked up among the funcs, so this must happen before they are consumed.
+    let property_registrations =
+        make_computed_property_registrations(&class_name, computed_properties, &funcs)?;
+
     #[cfg(all(feature = "register-docs", since_api = "4.3"))]
     let docs = crate::docs::make_inherent_impl_docs(&funcs, &consts, &signals);
     #[cfg(not(all(feature = "register-docs", since_api = "4.3")))]
     let docs = quote! {};
 
+    // The signal collection and its `WithSignals`/`WithUserSignals` impls exist once per class, so they are only generated for the primary
+    // block. Secondary blocks reject #[signal] in process_godot_fns(), so no signals get lost here.
+    let signal_collection = if meta.secondary {
+        None
+    } else {
+        make_signal_collection(&class_name, &signals)
+    };
     let signal_registrations = make_signal_registrations(signals, &class_name_obj);
 
     #[cfg(feature = "codegen-full")]
@@ -110,6 +140,7 @@ pub fn transform_inherent_impl(
         ::godot::sys::plugin_execute_pre_main!({
             #method_storage_name.lock().unwrap().push(|| {
                 #( #method_registrations )*
+                #( #property_registrations )*
                 #( #signal_registrations )*
             });
 
@@ -174,6 +205,7 @@ pub fn transform_inherent_impl(
             #trait_impl
             #fill_storage
             #class_registration
+            #signal_collection
         };
 
         Ok(result)
@@ -194,9 +226,14 @@ fn process_godot_fns(
     class_name: &Ident,
     impl_block: &mut venial::Impl,
     is_secondary_impl: bool,
-) -> ParseResult<(Vec<FuncDefinition>, Vec<SignalDefinition>)> {
+) -> ParseResult<(
+    Vec<FuncDefinition>,
+    Vec<SignalDefinition>,
+    Vec<ComputedPropertyDefinition>,
+)> {
     let mut func_definitions = vec![];
     let mut signal_definitions = vec![];
+    let mut computed_properties = vec![];
     let mut virtual_functions = vec![];
 
     let mut removed_indexes = vec![];
@@ -227,6 +264,9 @@ fn process_godot_fns(
             ItemAttrType::Func(func, rpc_info) => {
                 let external_attributes = function.attributes.clone();
 
+                // Must happen before the signature is extracted, so #[opt] attributes don't end up in the generated code.
+                let default_parameters = extract_default_parameters(function, func.has_gd_self)?;
+
                 // Signatures are the same thing without body.
                 let mut signature = util::reduce_to_signature(function);
                 let gd_self_parameter = if func.has_gd_self {
@@ -254,6 +294,43 @@ fn process_godot_fns(
                     None
                 };
 
+                if func.is_vararg {
+                    if func.is_virtual {
+                        return bail_attr(
+                            attr.attr_name,
+                            "keys `vararg` and `virtual` cannot be combined",
+                            function,
+                        );
+                    }
+                    if !default_parameters.is_empty() {
+                        return bail_attr(
+                            attr.attr_name,
+                            "with attribute key `vararg`, parameters cannot have #[opt] default values",
+                            function,
+                        );
+                    }
+
+                    // Variadic parameter (e.g. `args: &[&Variant]`) is passed separately and not registered with Godot.
+                    match signature.params.inner.last() {
+                        Some((venial::FnParam::Typed(param), _)) => {
+                            if !is_variant_ref_slice(&param.ty.tokens) {
+                                return bail!(
+                                    &param.ty,
+                                    "with attribute key `vararg`, the last parameter must have type `&[&Variant]`"
+                                );
+                            }
+                        }
+                        _ => {
+                            return bail_attr(
+                                attr.attr_name,
+                                "with attribute key `vararg`, the method must have a last parameter of type `&[&Variant]`",
+                                function,
+                            );
+                        }
+                    }
+                    signature.params.inner.pop();
+                }
+
                 // Clone might not strictly be necessary, but the 2 other callers of into_signature_info() are better off with pass-by-value.
                 let signature_info =
                     into_signature_info(signature.clone(), class_name, gd_self_parameter.is_some());
@@ -281,13 +358,16 @@ fn process_godot_fns(
                     registered_name,
                     is_script_virtual: func.is_virtual,
                     rpc_info,
+                    default_parameters,
+                    is_vararg: func.is_vararg,
                 });
             }
 
             ItemAttrType::Signal(ref _attr_val) => {
                 if is_secondary_impl {
                     return attr.bail(
-                        "#[signal] is not currently supported in secondary impl blocks",
+                        "#[signal] is not supported in secondary impl blocks; declare all signals in the primary #[godot_api] block, \
+                        which generates the class's typed signal collection",
                         function,
                     );
                 }
@@ -296,11 +376,13 @@ fn process_godot_fns(
                 }
 
                 let external_attributes = function.attributes.clone();
+                let vis_marker = function.vis_marker.clone();
                 let sig = util::reduce_to_signature(function);
 
                 signal_definitions.push(SignalDefinition {
                     signature: sig,
                     external_attributes,
+                    vis_marker,
                 });
 
                 removed_indexes.push(index);
@@ -312,6 +394,50 @@ fn process_godot_fns(
                     function,
                 )
             }
+
+            ItemAttrType::Property(ref property) => {
+                if function.return_ty.is_none() {
+                    return attr.bail("getter must return the property's value", function);
+                }
+
+                let external_attributes = function.attributes.clone();
+                let signature = util::reduce_to_signature(function);
+                let signature_info = into_signature_info(signature, class_name, false);
+
+                if !matches!(signature_info.receiver_type, ReceiverType::Ref)
+                    || !signature_info.param_idents.is_empty()
+                {
+                    return attr.bail(
+                        "getter must have signature `fn(&self) -> T`, without further parameters",
+                        function,
+                    );
+                }
+
+                // Registered under Godot's naming convention, so the method does not clash with the property in GDScript.
+                let property_name = property
+                    .rename
+                    .clone()
+                    .unwrap_or_else(|| function.name.to_string());
+                let getter_name = format!("get_{property_name}");
+
+                computed_properties.push(ComputedPropertyDefinition {
+                    property_name,
+                    getter_name: getter_name.clone(),
+                    setter: property.setter.clone(),
+                    // `Self` already replaced with class name, as registration happens outside the impl block.
+                    ty: signature_info.ret_type.clone(),
+                });
+
+                func_definitions.push(FuncDefinition {
+                    signature_info,
+                    external_attributes,
+                    registered_name: Some(getter_name),
+                    is_script_virtual: false,
+                    rpc_info: None,
+                    default_parameters: vec![],
+                    is_vararg: false,
+                });
+            }
         }
     }
 
@@ -327,7 +453,7 @@ fn process_godot_fns(
         impl_block.body_items.push(member);
     }
 
-    Ok((func_definitions, signal_definitions))
+    Ok((func_definitions, signal_definitions, computed_properties))
 }
 
 fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefinition>> {
@@ -346,6 +472,9 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
                 ItemAttrType::Signal(_) => {
                     return bail!(constant, "#[signal] can only be used on functions")
                 }
+                ItemAttrType::Property(_) => {
+                    return bail!(constant, "#[property] can only be used on functions")
+                }
                 ItemAttrType::Const(_) => {
                     if constant.initializer.is_none() {
                         return bail!(constant, "exported constant must have initializer");
@@ -364,6 +493,55 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
     Ok(constant_signatures)
 }
 
+/// Removes `#[opt(default = expr)]` attributes from the function's parameters and returns the default expressions.
+///
+/// Only trailing parameters may have defaults; the returned list corresponds to the last `len()` parameters.
+fn extract_default_parameters(
+    function: &mut venial::Function,
+    has_gd_self: bool,
+) -> ParseResult<Vec<TokenStream>> {
+    let typed_params = function
+        .params
+        .iter_mut()
+        .filter_map(|(param, _)| match param {
+            venial::FnParam::Typed(param) => Some(param),
+            venial::FnParam::Receiver(_) => None,
+        });
+
+    // Gd<Self> parameter is not registered with Godot.
+    let skipped = if has_gd_self { 1 } else { 0 };
+
+    let mut default_parameters = vec![];
+    for param in typed_params.skip(skipped) {
+        let default = match KvParser::parse(&param.attributes, "opt")? {
+            Some(mut parser) => {
+                let default = parser.handle_expr_required("default")?;
+                parser.finish()?;
+                Some(default)
+            }
+            None => None,
+        };
+
+        param.attributes.retain(|attr| {
+            attr.get_single_path_segment()
+                .map_or(true, |name| name != "opt")
+        });
+
+        match default {
+            Some(default) => default_parameters.push(default),
+            None if !default_parameters.is_empty() => {
+                return bail!(
+                    &param.name,
+                    "parameters without #[opt] cannot follow parameters with default values"
+                );
+            }
+            None => {}
+        }
+    }
+
+    Ok(default_parameters)
+}
+
 fn add_virtual_script_call(
     virtual_functions: &mut Vec<venial::Function>,
     function: &mut venial::Function,
@@ -394,8 +572,21 @@ fn add_virtual_script_call(
     };
     let method_name_cstr = c_str(&method_name_str);
 
-    let sig_tuple = signature_info.tuple_type();
+    // Script overrides receive their arguments as variants, so borrowed parameters are passed on as owned values.
+    let sig_tuple =
+        util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
     let arg_names = &signature_info.param_idents;
+    let script_args: Vec<TokenStream> = arg_names
+        .iter()
+        .zip(&signature_info.borrowed_params)
+        .map(|(name, &is_borrowed)| {
+            if is_borrowed {
+                quote! { ::std::clone::Clone::clone(#name) }
+            } else {
+                quote! { #name }
+            }
+        })
+        .collect();
 
     let (object_ptr, receiver);
     if let Some(gd_self_parameter) = gd_self_parameter {
@@ -415,7 +606,7 @@ fn add_virtual_script_call(
         if has_virtual_override {
             // Dynamic dispatch.
             type CallSig = #sig_tuple;
-            let args = (#( #arg_names, )*);
+            let args = (#( #script_args, )*);
             unsafe {
                 <CallSig as ::godot::meta::VarcallSignatureTuple>::out_script_virtual_call(
                     #class_name_str,
@@ -482,12 +673,16 @@ where
                 // #[func(gd_self)]
                 let has_gd_self = parser.handle_alone("gd_self")?;
 
+                // #[func(vararg)]
+                let is_vararg = parser.handle_alone("vararg")?;
+
                 parser.finish()?;
 
                 AttrParseResult::Func(FuncAttr {
                     rename,
                     is_virtual,
                     has_gd_self,
+                    is_vararg,
                 })
             }
 
@@ -551,6 +746,30 @@ where
             // #[constant]
             name if name == "constant" => AttrParseResult::Const(attr.value.clone()),
 
+            // #[property]
+            name if name == "property" => {
+                // Safe unwrap, since #[property] must be present if we got to this point.
+                let mut parser = KvParser::parse(attributes, "property")?.unwrap();
+
+                // #[property(get)] -- required, as the method itself is the getter.
+                if !parser.handle_alone("get")? {
+                    return bail!(
+                        &*item,
+                        "#[property] requires key `get`, e.g. #[property(get)]"
+                    );
+                }
+
+                // #[property(get, set = my_setter)]
+                let setter = parser.handle_ident("set")?;
+
+                // #[property(get, rename = my_name)]
+                let rename = parser.handle_ident_or_string("rename")?;
+
+                parser.finish()?;
+
+                AttrParseResult::Property(PropertyAttr { rename, setter })
+            }
+
             // Ignore unknown attributes.
             _ => continue,
         };
@@ -587,6 +806,67 @@ where
     }))
 }
 
+/// Registers properties declared with `#[property(get)]` on methods.
+///
+/// Must run after the getter and setter methods have been registered, which is the case inside the same registration closure.
+fn make_computed_property_registrations(
+    class_name: &Ident,
+    computed_properties: Vec<ComputedPropertyDefinition>,
+    funcs: &[FuncDefinition],
+) -> ParseResult<Vec<TokenStream>> {
+    let mut registrations = vec![];
+
+    for property in computed_properties {
+        let ComputedPropertyDefinition {
+            property_name,
+            getter_name,
+            setter,
+            ty,
+        } = property;
+
+        // Setter must be a #[func] from the same block; use its registered name, which may differ due to `rename`.
+        let setter_name = match &setter {
+            Some(setter) => {
+                let Some(func) = funcs
+                    .iter()
+                    .find(|func| func.signature_info.method_name == *setter)
+                else {
+                    return bail!(
+                        setter,
+                        "#[property(set = {setter})]: setter must be a #[func] in the same impl block"
+                    );
+                };
+
+                func.registered_name
+                    .clone()
+                    .unwrap_or_else(|| setter.to_string())
+            }
+            None => String::new(),
+        };
+
+        // Computed values are shown in the editor, but not stored in scenes or resources.
+        let usage_flags = if setter.is_some() {
+            quote! { ::godot::global::PropertyUsageFlags::EDITOR }
+        } else {
+            quote! {
+                ::godot::global::PropertyUsageFlags::EDITOR | ::godot::global::PropertyUsageFlags::READ_ONLY
+            }
+        };
+
+        registrations.push(quote! {
+            ::godot::register::private::register_var::<#class_name, #ty>(
+                #property_name,
+                #getter_name,
+                #setter_name,
+                <#ty as ::godot::register::property::Var>::var_hint(),
+                #usage_flags,
+            );
+        });
+    }
+
+    Ok(registrations)
+}
+
 fn bail_attr<R>(attr_name: Ident, msg: &str, method: &venial::Function) -> ParseResult<R> {
     bail!(&method.name, "#[{}]: {}", attr_name, msg)
 }
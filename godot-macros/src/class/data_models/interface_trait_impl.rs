@@ -74,7 +74,7 @@ pub fn transform_trait_impl(original_impl: venial::Impl) -> ParseResult<TokenStr
 
                     #(#cfg_attrs)*
                     impl ::godot::obj::cap::GodotRegisterClass for #class_name {
-                        fn __godot_register_class(builder: &mut ::godot::builder::GodotBuilder<Self>) {
+                        fn __godot_register_class(builder: &mut ::godot::register::ClassBuilder<Self>) {
                             <Self as #trait_path>::register_class(builder)
                         }
                     }
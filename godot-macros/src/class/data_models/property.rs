@@ -7,7 +7,9 @@
 
 //! Parsing the `var` and `export` attributes on fields.
 
-use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{
+    Field, FieldGroup, FieldGroupKind, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags,
+};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -63,6 +65,26 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             continue;
         };
 
+        // Categories and groups apply to all properties registered after them.
+        for group in field.groups.iter() {
+            let FieldGroup {
+                kind, name, prefix, ..
+            } = group;
+
+            let prefix = prefix.clone().unwrap_or_else(|| quote! { "" });
+            export_tokens.push(match kind {
+                FieldGroupKind::Category => quote! {
+                    ::godot::register::private::register_category::<#class_name>(#name);
+                },
+                FieldGroupKind::Group => quote! {
+                    ::godot::register::private::register_group::<#class_name>(#name, #prefix);
+                },
+                FieldGroupKind::Subgroup => quote! {
+                    ::godot::register::private::register_subgroup::<#class_name>(#name, #prefix);
+                },
+            });
+        }
+
         let field_name = field_ident.to_string();
 
         let FieldVar {
@@ -75,6 +97,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         let export_hint;
         let registration_fn;
+        let property_type;
+        let tool_button_method = export
+            .as_ref()
+            .and_then(|export| export.export_type.tool_button_method());
 
         if let Some(export) = export {
             if usage_flags.is_inferred() {
@@ -82,10 +108,21 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             }
 
             export_hint = export.to_export_hint();
-            registration_fn = quote! { register_export };
         } else {
             export_hint = None;
+        }
+
+        if tool_button_method.is_some() {
+            // Not backed by the field's value, but by a callable to the method. `Callable` does not implement `Export`.
+            property_type = quote! { ::godot::builtin::Callable };
             registration_fn = quote! { register_var };
+        } else {
+            property_type = quote! { #field_type };
+            registration_fn = if export.is_some() {
+                quote! { register_export }
+            } else {
+                quote! { register_var }
+            };
         }
 
         let usage_flags = match usage_flags {
@@ -93,7 +130,11 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                 quote! { ::godot::global::PropertyUsageFlags::NONE }
             }
             UsageFlags::InferredExport => {
-                quote! { ::godot::global::PropertyUsageFlags::DEFAULT }
+                // Only set together with `export`.
+                export
+                    .as_ref()
+                    .map(|export| export.export_type.usage_flags())
+                    .unwrap_or_else(|| quote! { ::godot::global::PropertyUsageFlags::DEFAULT })
             }
             UsageFlags::Custom(flags) => quote! {
                 #(
@@ -105,7 +146,14 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         let hint = match hint {
             FieldHint::Inferred => {
                 if let Some(export_hint) = export_hint {
-                    quote! { #export_hint }
+                    if tool_button_method.is_some() {
+                        quote! { #export_hint }
+                    } else {
+                        // Containers apply the hint to their elements, e.g. #[export(range = ...)] on Array<i32>.
+                        quote! {
+                            <#field_type as ::godot::register::property::Export>::export_element_hint(#export_hint)
+                        }
+                    }
                 } else if export.is_some() {
                     quote! { <#field_type as ::godot::register::property::Export>::export_hint() }
                 } else {
@@ -134,19 +182,26 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             },
         };
 
-        let getter_name = make_getter_setter(
-            getter.to_impl(class_name, GetSet::Get, field),
-            &mut getter_setter_impls,
-            &mut export_tokens,
-        );
-        let setter_name = make_getter_setter(
-            setter.to_impl(class_name, GetSet::Set, field),
-            &mut getter_setter_impls,
-            &mut export_tokens,
-        );
+        let (getter_impl, setter_impl) = match tool_button_method {
+            Some(method) => (
+                Some(GetterSetterImpl::from_tool_button(
+                    class_name, field, method,
+                )),
+                None,
+            ),
+            None => (
+                getter.to_impl(class_name, GetSet::Get, field),
+                setter.to_impl(class_name, GetSet::Set, field),
+            ),
+        };
+
+        let getter_name =
+            make_getter_setter(getter_impl, &mut getter_setter_impls, &mut export_tokens);
+        let setter_name =
+            make_getter_setter(setter_impl, &mut getter_setter_impls, &mut export_tokens);
 
         export_tokens.push(quote! {
-            ::godot::register::private::#registration_fn::<#class_name, #field_type>(
+            ::godot::register::private::#registration_fn::<#class_name, #property_type>(
                 #field_name,
                 #getter_name,
                 #setter_name,
@@ -6,8 +6,8 @@
  */
 
 use crate::util;
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 
 /// Holds information known from a signal's definition
 pub struct SignalDefinition {
@@ -16,6 +16,9 @@ pub struct SignalDefinition {
 
     /// The signal's non-gdext attributes (all except #[signal]).
     pub external_attributes: Vec<venial::Attribute>,
+
+    /// Visibility of the signal declaration, applied to the generated type-safe accessor.
+    pub vis_marker: Option<venial::VisMarker>,
 }
 
 pub fn make_signal_registrations(
@@ -28,6 +31,7 @@ pub fn make_signal_registrations(
         let SignalDefinition {
             signature,
             external_attributes,
+            ..
         } = signal;
         let mut param_types: Vec<venial::TypeExpr> = Vec::new();
         let mut param_names: Vec<String> = Vec::new();
@@ -66,21 +70,13 @@ pub fn make_signal_registrations(
 
         let signal_registration = quote! {
             #(#signal_cfg_attrs)*
-            unsafe {
-                use ::godot::sys;
+            {
                 let parameters_info: [::godot::meta::PropertyInfo; #signal_parameters_count] = #signal_parameters;
 
-                let mut parameters_info_sys: [sys::GDExtensionPropertyInfo; #signal_parameters_count] =
-                    std::array::from_fn(|i| parameters_info[i].property_sys());
-
-                let signal_name = ::godot::builtin::StringName::from(#signal_name_str);
-
-                sys::interface_fn!(classdb_register_extension_class_signal)(
-                    sys::get_library(),
-                    #class_name_obj.string_sys(),
-                    signal_name.string_sys(),
-                    parameters_info_sys.as_ptr(),
-                    sys::GDExtensionInt::from(#signal_parameters_count as i64),
+                ::godot::register::private::register_signal(
+                    #class_name_obj,
+                    #signal_name_str,
+                    &parameters_info,
                 );
             }
         };
@@ -89,3 +85,92 @@ pub fn make_signal_registrations(
     }
     signal_registrations
 }
+
+/// Generates the signal collection struct `SignalsOf{Class}` and the `WithSignals`/`WithUserSignals` impls.
+///
+/// Returns `None` if there are no signals.
+pub fn make_signal_collection(
+    class_name: &Ident,
+    signals: &[SignalDefinition],
+) -> Option<TokenStream> {
+    if signals.is_empty() {
+        return None;
+    }
+
+    let collection_struct_name = format_ident!("SignalsOf{}", class_name);
+    let collection_docs = format!(
+        "Type-safe signals of class `{class_name}`, accessible via `self.signals()` or `Gd::signals()`."
+    );
+
+    let mut accessors = Vec::with_capacity(signals.len());
+    for signal in signals {
+        let SignalDefinition {
+            signature,
+            external_attributes,
+            vis_marker,
+        } = signal;
+
+        let param_types = signature
+            .params
+            .inner
+            .iter()
+            .filter_map(|(param, _)| match param {
+                venial::FnParam::Typed(param) => Some(&param.ty),
+                venial::FnParam::Receiver(_) => None,
+            });
+
+        let signal_name = &signature.name;
+        let signal_name_str = signal_name.to_string();
+        let cfg_attrs: Vec<&venial::Attribute> = util::extract_cfg_attrs(external_attributes)
+            .into_iter()
+            .collect();
+        let accessor_docs = format!("Signal `{signal_name_str}`.");
+
+        accessors.push(quote! {
+            #(#cfg_attrs)*
+            #[doc = #accessor_docs]
+            #vis_marker fn #signal_name(self) -> ::godot::register::TypedSignal<'c, #class_name, (#(#param_types,)*)> {
+                ::godot::register::TypedSignal::new(self.__internal_obj, #signal_name_str)
+            }
+        });
+    }
+
+    let code = quote! {
+        #[doc = #collection_docs]
+        pub struct #collection_struct_name<'c> {
+            #[doc(hidden)]
+            __internal_obj: ::godot::register::private::UserSignalObject<'c, #class_name>,
+        }
+
+        impl<'c> #collection_struct_name<'c> {
+            #( #accessors )*
+        }
+
+        impl ::godot::obj::WithSignals for #class_name {
+            type SignalCollection<'c> = #collection_struct_name<'c>;
+            #[doc(hidden)]
+            type __SignalObj<'c> = ::godot::register::private::UserSignalObject<'c, Self>;
+
+            #[doc(hidden)]
+            fn __signals_from_external(external: &::godot::obj::Gd<Self>) -> Self::SignalCollection<'_> {
+                #collection_struct_name {
+                    __internal_obj: ::godot::register::private::UserSignalObject::External {
+                        gd: external.clone(),
+                    },
+                }
+            }
+        }
+
+        impl ::godot::obj::WithUserSignals for #class_name {
+            fn signals(&mut self) -> Self::SignalCollection<'_> {
+                #collection_struct_name {
+                    __internal_obj: ::godot::register::private::UserSignalObject::Internal {
+                        self_mut: self,
+                    },
+                }
+            }
+        }
+    };
+
+    Some(code)
+}
@@ -10,7 +10,7 @@ use quote::{format_ident, quote, quote_spanned};
 
 use crate::class::{
     make_property_impl, make_virtual_callback, BeforeKind, Field, FieldDefault, FieldExport,
-    FieldVar, Fields, SignatureInfo,
+    FieldGroup, FieldGroupKind, FieldVar, Fields, SignatureInfo,
 };
 use crate::util::{bail, error, ident, path_ends_with_complex, require_api_version, KvParser};
 use crate::{handle_mutually_exclusive_keys, util, ParseResult};
@@ -35,6 +35,19 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let mut fields = parse_fields(named_fields, struct_cfg.init_strategy)?;
     let is_editor_plugin = struct_cfg.is_editor_plugin();
 
+    if !struct_cfg.is_tool {
+        for field in fields.all_fields.iter() {
+            if let Some(export) = field.export.as_ref() {
+                if export.export_type.tool_button_method().is_some() {
+                    fields.errors.push(error!(
+                        export.span,
+                        "#[export(tool_button)] requires #[class(tool)]"
+                    ));
+                }
+            }
+        }
+    }
+
     let mut deprecations = std::mem::take(&mut struct_cfg.deprecations);
     deprecations.append(&mut fields.deprecations);
 
@@ -549,6 +562,45 @@ fn parse_fields(
             parser.finish()?;
         }
 
+        // #[export(tool_button = ...)] is read-only and backed by a method, not the field.
+        if let Some(export) = field.export.as_ref() {
+            if export.export_type.tool_button_method().is_some() {
+                if let Some(var) = field.var.as_ref() {
+                    errors.push(error!(
+                        var.span,
+                        "#[export(tool_button)] cannot be combined with #[var]"
+                    ));
+                }
+
+                if field.default_val.is_none() {
+                    field.default_val = Some(FieldDefault {
+                        default_val: quote! { ::godot::builtin::Callable::invalid() },
+                        span: export.span,
+                    });
+                }
+            }
+        }
+
+        // #[export_category(name = "...")], #[export_group(name = "...", prefix = "...")], #[export_subgroup(...)]
+        for kind in FieldGroupKind::ALL {
+            if let Some(mut parser) = KvParser::parse(&named_field.attributes, kind.attr_name())? {
+                let name = parser.handle_expr_required("name")?;
+                let prefix = if kind == FieldGroupKind::Category {
+                    None
+                } else {
+                    parser.handle_expr("prefix")?
+                };
+
+                field.groups.push(FieldGroup {
+                    kind,
+                    name,
+                    prefix,
+                    span: parser.span(),
+                });
+                parser.finish()?;
+            }
+        }
+
         // #[hint] to override type inference (must be at the end).
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "hint")? {
             if let Some(override_base) = handle_opposite_keys(&mut parser, "base", "hint")? {
@@ -591,6 +643,14 @@ fn parse_fields(
                 ));
             }
 
+            for group in field.groups.iter() {
+                errors.push(error!(
+                    group.span,
+                    "base field cannot have the attribute #[{}]",
+                    group.kind.attr_name()
+                ));
+            }
+
             if let Some(prev_base) = base_field.replace(field) {
                 // Ensure at most one Base<T>.
                 errors.push(error!(
@@ -600,6 +660,17 @@ fn parse_fields(
                 ));
             }
         } else {
+            // Groups are registered right before the field's property; without one, there is no position in the inspector.
+            if field.var.is_none() && field.export.is_none() {
+                for group in field.groups.iter() {
+                    errors.push(error!(
+                        group.span,
+                        "#[{}] requires the field to have #[export] or #[var]",
+                        group.kind.attr_name()
+                    ));
+                }
+            }
+
             all_fields.push(field);
         }
     }
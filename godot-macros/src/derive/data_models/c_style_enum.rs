@@ -5,7 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::util::bail;
+use crate::util::{bail, KvParser};
 use crate::ParseResult;
 use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
@@ -16,6 +16,12 @@ pub struct CStyleEnum {
     /// The names of each enumerator.
     enumerator_names: Vec<Ident>,
 
+    /// The names of each enumerator as seen by Godot, i.e. after `#[godot(rename = ...)]`.
+    enumerator_godot_names: Vec<String>,
+
+    /// Whether string representations are matched regardless of case, when converting from Godot.
+    case_insensitive: bool,
+
     /// The discriminants of each variant, both explicit and implicit.
     ///
     /// Can be simple or complex expressions, the latter with parentheses:
@@ -29,21 +35,59 @@ impl CStyleEnum {
     /// Parses the enum.
     ///
     /// Ensures all the variants are unit variants, and that any explicit discriminants are integer literals.
-    pub fn parse_enum(enum_: &venial::Enum) -> ParseResult<Self> {
+    pub fn parse_enum(enum_: &venial::Enum, case_insensitive: bool) -> ParseResult<Self> {
         let variants = enum_
             .variants
             .items()
             .map(CStyleEnumerator::parse_enum_variant)
             .collect::<ParseResult<Vec<_>>>()?;
 
+        let godot_names = Self::collect_godot_names(&variants, case_insensitive)?;
         let (names, ord_exprs) = Self::create_discriminant_mapping(variants)?;
 
         Ok(Self {
             enumerator_names: names,
+            enumerator_godot_names: godot_names,
+            case_insensitive,
             enumerator_ords: ord_exprs,
         })
     }
 
+    /// Returns the Godot name of each enumerator, ensuring they are unique (ignoring case, if `case_insensitive`).
+    fn collect_godot_names(
+        enumerators: &[CStyleEnumerator],
+        case_insensitive: bool,
+    ) -> ParseResult<Vec<String>> {
+        let mut godot_names: Vec<String> = Vec::with_capacity(enumerators.len());
+
+        for enumerator in enumerators {
+            let godot_name = &enumerator.godot_name;
+            let is_duplicate = godot_names.iter().any(|existing| {
+                if case_insensitive {
+                    existing.to_lowercase() == godot_name.to_lowercase()
+                } else {
+                    existing == godot_name
+                }
+            });
+
+            if is_duplicate {
+                return bail!(
+                    &enumerator.name,
+                    "enumerator name \"{godot_name}\" is used more than once{}",
+                    if case_insensitive {
+                        " (ignoring case)"
+                    } else {
+                        ""
+                    }
+                );
+            }
+
+            godot_names.push(godot_name.clone());
+        }
+
+        Ok(godot_names)
+    }
+
     fn create_discriminant_mapping(
         enumerators: Vec<CStyleEnumerator>,
     ) -> ParseResult<(Vec<Ident>, Vec<TokenStream>)> {
@@ -81,6 +125,16 @@ impl CStyleEnum {
         &self.enumerator_names
     }
 
+    /// Returns the names of the enumerators as seen by Godot, in order of declaration.
+    pub fn enumerator_godot_names(&self) -> &[String] {
+        &self.enumerator_godot_names
+    }
+
+    /// Whether string representations are matched regardless of case, when converting from Godot.
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
     /// Returns the ordinal expression (discriminant) of each enumerator, in order of declaration.
     pub fn enumerator_ord_exprs(&self) -> &[TokenStream] {
         &self.enumerator_ords
@@ -88,7 +142,7 @@ impl CStyleEnum {
 
     /// Return a hint string for use with `PropertyHint::ENUM` where each variant has an explicit integer hint.
     pub fn to_int_hint(&self) -> String {
-        self.enumerator_names
+        self.enumerator_godot_names
             .iter()
             .zip(self.enumerator_ords.iter())
             .map(|(name, discrim)| format!("{name}:{discrim}"))
@@ -98,11 +152,7 @@ impl CStyleEnum {
 
     /// Return a hint string for use with `PropertyHint::ENUM` where the variants are just kept as strings.
     pub fn to_string_hint(&self) -> String {
-        self.enumerator_names
-            .iter()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>()
-            .join(",")
+        self.enumerator_godot_names.join(",")
     }
 }
 
@@ -111,6 +161,8 @@ impl CStyleEnum {
 pub struct CStyleEnumerator {
     /// The name of the variant.
     name: Ident,
+    /// The name of the variant in Godot; differs from `name` if `#[godot(rename = ...)]` is present.
+    godot_name: String,
     /// The explicit discriminant of the variant, `None` means there was no explicit discriminant.
     discriminant: Option<TokenTree>,
 }
@@ -123,13 +175,22 @@ impl CStyleEnumerator {
             _ => {
                 return bail!(
                     &enum_variant.fields,
-                    "GodotConvert only supports C-style enums"
+                    "GodotConvert only supports C-style enums with this via type; use #[godot(via = Dictionary)] for enums with data"
                 )
             }
         }
 
+        let mut godot_name = enum_variant.name.to_string();
+        if let Some(mut parser) = KvParser::parse(&enum_variant.attributes, "godot")? {
+            if let Some(rename) = parser.handle_ident_or_string("rename")? {
+                godot_name = rename;
+            }
+            parser.finish()?;
+        }
+
         Ok(Self {
             name: enum_variant.name.clone(),
+            godot_name,
             discriminant: enum_variant.value.as_ref().map(|val| &val.value).cloned(),
         })
     }
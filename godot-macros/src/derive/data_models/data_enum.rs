@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::Ident;
+
+use crate::util::KvParser;
+use crate::ParseResult;
+
+use super::fields::ConvertFields;
+
+/// Stores info from enums whose variants may carry data, for use in deriving `GodotConvert` and other related traits.
+///
+/// Such enums are represented as a dictionary with a single entry: the variant's tag as key, and its payload as value.
+pub struct DataEnum {
+    pub variants: Vec<DataVariant>,
+}
+
+impl DataEnum {
+    pub fn parse_enum(enum_: &venial::Enum) -> ParseResult<Self> {
+        let variants = enum_
+            .variants
+            .items()
+            .map(DataVariant::parse_enum_variant)
+            .collect::<ParseResult<Vec<_>>>()?;
+
+        Ok(Self { variants })
+    }
+}
+
+/// Each variant in an enum with data.
+pub struct DataVariant {
+    /// The name of the variant.
+    pub name: Ident,
+
+    /// Tag identifying the variant in Godot; defaults to the variant name.
+    pub tag: String,
+
+    /// The payload of the variant.
+    pub fields: ConvertFields,
+}
+
+impl DataVariant {
+    fn parse_enum_variant(enum_variant: &venial::EnumVariant) -> ParseResult<Self> {
+        let mut tag = enum_variant.name.to_string();
+
+        if let Some(mut parser) = KvParser::parse(&enum_variant.attributes, "godot")? {
+            if let Some(rename) = parser.handle_ident_or_string("rename")? {
+                tag = rename;
+            }
+            parser.finish()?;
+        }
+
+        Ok(Self {
+            name: enum_variant.name.clone(),
+            tag,
+            fields: ConvertFields::parse(&enum_variant.fields)?,
+        })
+    }
+}
@@ -0,0 +1,169 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::util::{bail, KvParser};
+use crate::ParseResult;
+
+/// Stores the fields of a struct or enum variant, for use in deriving `GodotConvert` on compound types.
+pub struct ConvertFields {
+    pub kind: FieldsKind,
+    pub fields: Vec<ConvertField>,
+}
+
+/// How the fields of a struct or enum variant are declared.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum FieldsKind {
+    /// `struct S;` or `Variant`.
+    Unit,
+    /// `struct S(A, B);` or `Variant(A, B)`.
+    Tuple,
+    /// `struct S { a: A, b: B }` or `Variant { a: A, b: B }`.
+    Named,
+}
+
+impl ConvertFields {
+    pub fn parse(fields: &venial::Fields) -> ParseResult<Self> {
+        let result = match fields {
+            venial::Fields::Unit => Self {
+                kind: FieldsKind::Unit,
+                fields: vec![],
+            },
+            venial::Fields::Tuple(tuple) => Self {
+                kind: FieldsKind::Tuple,
+                fields: tuple
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (field, _))| ConvertField::parse_tuple(index, field))
+                    .collect::<ParseResult<_>>()?,
+            },
+            venial::Fields::Named(named) => Self {
+                kind: FieldsKind::Named,
+                fields: named
+                    .fields
+                    .iter()
+                    .map(|(field, _)| ConvertField::parse_named(field))
+                    .collect::<ParseResult<_>>()?,
+            },
+        };
+
+        Ok(result)
+    }
+
+    /// Whether this is a tuple with exactly one field and no attributes, which is represented by the field's value alone.
+    pub fn is_newtype(&self) -> bool {
+        self.kind == FieldsKind::Tuple
+            && self.fields.len() == 1
+            && !self.fields[0].skip
+            && !self.fields[0].default
+    }
+
+    /// The fields which take part in the conversion, i.e. are not `#[godot(skip)]`.
+    pub fn converted(&self) -> impl Iterator<Item = &ConvertField> {
+        self.fields.iter().filter(|field| !field.skip)
+    }
+
+    /// Pattern destructuring all fields into their local bindings, to be appended to a path.
+    ///
+    /// Skipped fields are bound to `_`.
+    pub fn binding_pattern(&self) -> TokenStream {
+        let bindings = self.fields.iter().map(|field| {
+            if field.skip {
+                quote! { _ }
+            } else {
+                let binding = &field.binding;
+                quote! { #binding }
+            }
+        });
+
+        match self.kind {
+            FieldsKind::Unit => TokenStream::new(),
+            FieldsKind::Tuple => quote! { ( #(#bindings),* ) },
+            FieldsKind::Named => {
+                let members = self.fields.iter().map(|field| &field.member);
+                quote! { { #( #members: #bindings ),* } }
+            }
+        }
+    }
+}
+
+/// A single field of a struct or enum variant.
+pub struct ConvertField {
+    /// How the field is accessed: its name for named fields, its index for tuple fields.
+    pub member: TokenStream,
+
+    /// Name of a local variable bound to the field, when destructuring.
+    pub binding: Ident,
+
+    /// Key of the field in a dictionary representation; defaults to the field name.
+    pub key: String,
+
+    /// `#[godot(default)]`: use `Default::default()` if the field is absent.
+    pub default: bool,
+
+    /// `#[godot(skip)]`: don't convert the field at all, always use `Default::default()`.
+    pub skip: bool,
+}
+
+impl ConvertField {
+    fn parse_named(field: &venial::NamedField) -> ParseResult<Self> {
+        let name = &field.name;
+        let mut result = Self {
+            member: quote! { #name },
+            binding: format_ident!("field_{}", name),
+            key: name.to_string(),
+            default: false,
+            skip: false,
+        };
+
+        if let Some(mut parser) = KvParser::parse(&field.attributes, "godot")? {
+            if let Some(rename) = parser.handle_ident_or_string("rename")? {
+                result.key = rename;
+            }
+
+            result.parse_flags(&mut parser)?;
+            parser.finish()?;
+        }
+
+        Ok(result)
+    }
+
+    fn parse_tuple(index: usize, field: &venial::TupleField) -> ParseResult<Self> {
+        let member = Literal::usize_unsuffixed(index);
+        let mut result = Self {
+            member: quote! { #member },
+            binding: format_ident!("field_{}", index),
+            key: index.to_string(),
+            default: false,
+            skip: false,
+        };
+
+        if let Some(mut parser) = KvParser::parse(&field.attributes, "godot")? {
+            if parser.handle_ident_or_string("rename")?.is_some() {
+                return bail!(
+                    parser.span(),
+                    "#[godot(rename)] is only supported on named fields"
+                );
+            }
+
+            result.parse_flags(&mut parser)?;
+            parser.finish()?;
+        }
+
+        Ok(result)
+    }
+
+    fn parse_flags(&mut self, parser: &mut KvParser) -> ParseResult<()> {
+        self.default = parser.handle_alone("default")?;
+        self.skip = parser.handle_alone("skip")?;
+
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@
  */
 
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::ToTokens;
+use quote::{quote_spanned, ToTokens};
 
 use crate::util::{bail, KvParser};
 use crate::ParseResult;
@@ -15,8 +15,12 @@ use crate::ParseResult;
 pub enum GodotAttribute {
     /// `#[godot(transparent)]`
     Transparent { span: Span },
-    /// `#[godot(via = via_type)]`
-    Via { span: Span, via_type: ViaType },
+    /// `#[godot(via = via_type)]`, optionally with `case_insensitive`
+    Via {
+        span: Span,
+        via_type: ViaType,
+        case_insensitive: bool,
+    },
 }
 
 impl GodotAttribute {
@@ -39,6 +43,7 @@ impl GodotAttribute {
             return Ok(Self::Via {
                 span,
                 via_type: ViaType::parse_ident(via_type)?,
+                case_insensitive: parser.handle_alone("case_insensitive")?,
             });
         }
 
@@ -63,27 +68,51 @@ impl GodotAttribute {
 pub enum ViaType {
     /// The via type is `GString`
     GString { gstring_ident: Ident },
+    /// The via type is `StringName`
+    StringName { stringname_ident: Ident },
     /// The via type is an integer
     Int { int_ident: Ident },
+    /// The via type is `Dictionary`
+    Dictionary { dictionary_ident: Ident },
+    /// The via type is `Array`, i.e. an untyped `VariantArray`
+    Array { array_ident: Ident },
 }
 
 impl ViaType {
     fn parse_ident(ident: Ident) -> ParseResult<Self> {
         let via_type = match ident.to_string().as_str() {
             "GString" => ViaType::GString { gstring_ident: ident },
+            "StringName" => ViaType::StringName { stringname_ident: ident },
             "i8" |"i16" | "i32" | "i64" | "u8" | "u16" | "u32" => ViaType::Int { int_ident: ident },
-            other => return bail!(ident, "Via type `{other}` is not supported, expected one of: GString, i8, i16, i32, i64, u8, u16, u32")
+            "Dictionary" => ViaType::Dictionary { dictionary_ident: ident },
+            "Array" => ViaType::Array { array_ident: ident },
+            other => return bail!(ident, "Via type `{other}` is not supported, expected one of: GString, StringName, i8, i16, i32, i64, u8, u16, u32, Dictionary, Array")
         };
 
         Ok(via_type)
     }
+
+    /// Whether enums are represented by the names of their enumerators, rather than their discriminants.
+    pub fn is_string(&self) -> bool {
+        matches!(self, ViaType::GString { .. } | ViaType::StringName { .. })
+    }
 }
 
 impl ToTokens for ViaType {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             ViaType::GString { gstring_ident } => gstring_ident.to_tokens(tokens),
+            ViaType::StringName { stringname_ident } => stringname_ident.to_tokens(tokens),
             ViaType::Int { int_ident } => int_ident.to_tokens(tokens),
+            // Fully qualified, so that users need not import the types (`Array` would otherwise also need a generic argument).
+            ViaType::Dictionary { dictionary_ident } => {
+                quote_spanned! { dictionary_ident.span()=> ::godot::builtin::Dictionary }
+                    .to_tokens(tokens)
+            }
+            ViaType::Array { array_ident } => {
+                quote_spanned! { array_ident.span()=> ::godot::builtin::VariantArray }
+                    .to_tokens(tokens)
+            }
         }
     }
 }
@@ -12,6 +12,8 @@ use crate::util::bail;
 use crate::ParseResult;
 
 use super::c_style_enum::CStyleEnum;
+use super::data_enum::DataEnum;
+use super::fields::{ConvertFields, FieldsKind};
 use super::godot_attribute::{GodotAttribute, ViaType};
 use super::newtype::NewtypeStruct;
 
@@ -73,36 +75,93 @@ impl GodotConvert {
 pub enum ConvertType {
     /// Deriving for a newtype struct.
     NewType { field: NewtypeStruct },
-    /// Deriving for an enum.
+    /// Deriving for a struct with named fields (via `Dictionary`) or a tuple struct (via `Array`).
+    Struct { fields: ConvertFields, via: ViaType },
+    /// Deriving for a C-style enum.
     Enum { variants: CStyleEnum, via: ViaType },
+    /// Deriving for an enum whose variants may carry data (via `Dictionary`).
+    DataEnum { variants: DataEnum, via: ViaType },
 }
 
 impl ConvertType {
     pub fn parse_declaration(item: venial::Item) -> ParseResult<Self> {
         let attribute = GodotAttribute::parse_attribute(&item)?;
 
-        match &item {
-            venial::Item::Struct(struct_) => {
-                let GodotAttribute::Transparent { .. } = attribute else {
-                    return bail!(attribute.span(), "#[derive(GodotConvert)] on structs currently only works with #[godot(transparent)]");
-                };
+        if let GodotAttribute::Via {
+            span,
+            via_type,
+            case_insensitive: true,
+        } = &attribute
+        {
+            if !matches!(item, venial::Item::Enum(_)) || !via_type.is_string() {
+                return bail!(
+                    *span,
+                    "#[godot(case_insensitive)] is only supported on enums with #[godot(via = GString)] or #[godot(via = StringName)]"
+                );
+            }
+        }
 
-                Ok(Self::NewType {
+        match &item {
+            venial::Item::Struct(struct_) => match attribute {
+                GodotAttribute::Transparent { .. } => Ok(Self::NewType {
                     field: NewtypeStruct::parse_struct(struct_)?,
-                })
-            }
+                }),
+                GodotAttribute::Via { span, via_type, .. } => {
+                    let fields = ConvertFields::parse(&struct_.fields)?;
+
+                    match (&via_type, fields.kind) {
+                        (ViaType::Dictionary { .. }, FieldsKind::Named)
+                        | (ViaType::Array { .. }, FieldsKind::Tuple) => {}
+                        (ViaType::Dictionary { .. } | ViaType::Array { .. }, _) => {
+                            return bail!(
+                                span,
+                                "#[derive(GodotConvert)] requires #[godot(via = Dictionary)] for structs with named fields, \
+                                and #[godot(via = Array)] for tuple structs"
+                            );
+                        }
+                        _ => {
+                            return bail!(
+                                span,
+                                "#[derive(GodotConvert)] on structs works with #[godot(transparent)], #[godot(via = Dictionary)] \
+                                or #[godot(via = Array)]"
+                            );
+                        }
+                    }
+
+                    Ok(Self::Struct {
+                        fields,
+                        via: via_type,
+                    })
+                }
+            },
             venial::Item::Enum(enum_) => {
-                let GodotAttribute::Via { via_type, .. } = attribute else {
+                let GodotAttribute::Via {
+                    span,
+                    via_type,
+                    case_insensitive,
+                } = attribute
+                else {
                     return bail!(
                         attribute.span(),
                         "#[derive(GodotConvert)] on enums requires #[godot(via = ...)]"
                     );
                 };
 
-                Ok(Self::Enum {
-                    variants: CStyleEnum::parse_enum(enum_)?,
-                    via: via_type,
-                })
+                match via_type {
+                    ViaType::Dictionary { .. } => Ok(Self::DataEnum {
+                        variants: DataEnum::parse_enum(enum_)?,
+                        via: via_type,
+                    }),
+                    ViaType::Array { .. } => bail!(
+                        span,
+                        "#[derive(GodotConvert)] on enums does not support #[godot(via = Array)]; \
+                        use #[godot(via = Dictionary)] for enums with data"
+                    ),
+                    _ => Ok(Self::Enum {
+                        variants: CStyleEnum::parse_enum(enum_, case_insensitive)?,
+                        via: via_type,
+                    }),
+                }
             }
             _ => unreachable!(), // already checked outside.
         }
@@ -112,7 +171,9 @@ impl ConvertType {
     pub fn via_type(&self) -> TokenStream {
         match self {
             ConvertType::NewType { field } => field.ty.to_token_stream(),
-            ConvertType::Enum { via, .. } => via.to_token_stream(),
+            ConvertType::Struct { via, .. }
+            | ConvertType::Enum { via, .. }
+            | ConvertType::DataEnum { via, .. } => via.to_token_stream(),
         }
     }
 }
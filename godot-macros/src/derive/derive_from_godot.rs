@@ -5,8 +5,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertFields, ConvertType, DataEnum, FieldsKind, GodotConvert, NewtypeStruct,
+    ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
+use crate::derive::derive_to_godot::{make_compound_via_type, make_string_via_type};
 use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -23,15 +27,22 @@ pub fn make_fromgodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) -
     match data {
         ConvertType::NewType { field } => make_fromgodot_for_newtype_struct(name, field),
 
-        ConvertType::Enum {
-            variants,
-            via: ViaType::GString { .. },
-        } => make_fromgodot_for_gstring_enum(name, variants),
+        ConvertType::Struct { fields, .. } => make_fromgodot_for_struct(name, fields),
+
+        ConvertType::DataEnum { variants, .. } => make_fromgodot_for_data_enum(name, variants),
+
+        ConvertType::Enum { variants, via } if via.is_string() => {
+            make_fromgodot_for_string_enum(name, variants, via)
+        }
 
         ConvertType::Enum {
             variants,
             via: ViaType::Int { int_ident },
         } => make_fromgodot_for_int_enum(name, variants, int_ident, cache),
+
+        ConvertType::Enum { .. } => {
+            unreachable!("C-style enums are not converted via Dictionary or Array")
+        }
     }
 }
 
@@ -90,16 +101,30 @@ fn make_fromgodot_for_int_enum(
     }
 }
 
-/// Derives `FromGodot` for enums with a via type of `GString`.
-fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream {
+/// Derives `FromGodot` for enums with a via type of `GString` or `StringName`.
+///
+/// With `#[godot(case_insensitive)]`, the string is compared to the enumerator names regardless of case.
+fn make_fromgodot_for_string_enum(name: &Ident, enum_: &CStyleEnum, via: &ViaType) -> TokenStream {
     let names = enum_.enumerator_names();
-    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let via_type = make_string_via_type(via);
     let bad_variant_error = format!("invalid {name} variant");
 
+    let (names_str, normalize) = if enum_.is_case_insensitive() {
+        let names_str = enum_
+            .enumerator_godot_names()
+            .iter()
+            .map(|name| name.to_lowercase())
+            .collect::<Vec<_>>();
+
+        (names_str, quote! { .to_lowercase() })
+    } else {
+        (enum_.enumerator_godot_names().to_vec(), TokenStream::new())
+    };
+
     quote! {
         impl ::godot::meta::FromGodot for #name {
-            fn try_from_godot(via: ::godot::builtin::GString) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
-                match via.to_string().as_str() {
+            fn try_from_godot(via: #via_type) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                match via.to_string() #normalize .as_str() {
                     #(
                         #names_str => Ok(#name::#names),
                     )*
@@ -110,3 +135,135 @@ fn make_fromgodot_for_gstring_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStr
         }
     }
 }
+
+/// Derives `FromGodot` for structs with named fields (via `Dictionary`) or tuple structs (via `Array`).
+fn make_fromgodot_for_struct(name: &Ident, fields: &ConvertFields) -> TokenStream {
+    let via_type = make_compound_via_type(fields);
+    let construct = make_compound_from_via(fields, &quote! { #name }, &util::ident("via"));
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(via: #via_type) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                #construct
+            }
+        }
+    }
+}
+
+/// Derives `FromGodot` for enums with data, from a dictionary `{ tag: payload }` with a single entry.
+fn make_fromgodot_for_data_enum(name: &Ident, enum_: &DataEnum) -> TokenStream {
+    let bad_variant_error = format!("invalid {name} variant");
+    let bad_entries_error =
+        format!("{name} must be represented by a dictionary with a single entry");
+    let payload = util::ident("payload");
+
+    let arms = enum_.variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let tag = &variant.tag;
+        let position = format!("with key {tag}");
+
+        let body = if variant.fields.kind == FieldsKind::Unit {
+            quote! { Ok(#name::#variant_name) }
+        } else if variant.fields.is_newtype() {
+            quote! {
+                Ok(#name::#variant_name(::godot::private::convert_field(&#payload, #position)?))
+            }
+        } else {
+            let via_type = make_compound_via_type(&variant.fields);
+            let construct =
+                make_compound_from_via(&variant.fields, &quote! { #name::#variant_name }, &payload);
+
+            quote! {
+                let #payload: #via_type = ::godot::private::convert_field(&#payload, #position)?;
+                #construct
+            }
+        };
+
+        quote! {
+            #tag => { #body }
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::FromGodot for #name {
+            #[allow(unused_variables)] // Payload is not used if all variants are unit-like.
+            fn try_from_godot(via: ::godot::builtin::Dictionary) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                if via.len() != 1 {
+                    return Err(::godot::meta::error::ConvertError::with_error_value(#bad_entries_error, via));
+                }
+
+                let (tag, #payload) = via.iter_shared().next().expect("dictionary has a single entry");
+
+                match tag.to_string().as_str() {
+                    #( #arms )*
+                    // Pass `via` and not `tag`, to retain debug info of original value.
+                    _ => Err(::godot::meta::error::ConvertError::with_error_value(#bad_variant_error, via)),
+                }
+            }
+        }
+    }
+}
+
+/// Creates statements that construct `constructor { .. }` from `source`, a `Dictionary` (named fields) or `VariantArray` (tuple fields).
+///
+/// Evaluates to `Result<Self, ConvertError>`. Skipped fields and absent `#[godot(default)]` fields are initialized with `Default::default()`.
+fn make_compound_from_via(
+    fields: &ConvertFields,
+    constructor: &TokenStream,
+    source: &Ident,
+) -> TokenStream {
+    let mut index = 0usize;
+    let mut inits = Vec::new();
+
+    for field in fields.fields.iter() {
+        let member = &field.member;
+
+        if field.skip {
+            inits.push(quote! { #member: ::std::default::Default::default() });
+            continue;
+        }
+
+        let (lookup, position) = match fields.kind {
+            FieldsKind::Named => {
+                let key = &field.key;
+                (quote! { #source.get(#key) }, format!("with key {key}"))
+            }
+            FieldsKind::Tuple | FieldsKind::Unit => {
+                let lookup = quote! { #source.get(#index) };
+                index += 1;
+                (lookup, format!("at index {}", index - 1))
+            }
+        };
+
+        let missing = if field.default {
+            quote! { ::std::default::Default::default() }
+        } else {
+            quote! { return Err(::godot::private::missing_field_error(#source, #position)) }
+        };
+
+        inits.push(quote! {
+            #member: match #lookup {
+                Some(value) => ::godot::private::convert_field(&value, #position)?,
+                None => #missing,
+            }
+        });
+    }
+
+    // Arrays with superfluous elements are rejected; dictionaries may contain additional keys.
+    let length_check = match fields.kind {
+        FieldsKind::Named => TokenStream::new(),
+        FieldsKind::Tuple | FieldsKind::Unit => {
+            let too_long_error = format!("expected array with at most {index} elements");
+            quote! {
+                if #source.len() > #index {
+                    return Err(::godot::meta::error::ConvertError::with_error_value(#too_long_error, #source));
+                }
+            }
+        }
+    };
+
+    quote! {
+        #length_check
+        Ok(#constructor { #( #inits, )* })
+    }
+}
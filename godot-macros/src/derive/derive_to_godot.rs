@@ -8,7 +8,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::derive::data_models::{CStyleEnum, ConvertType, GodotConvert, NewtypeStruct, ViaType};
+use crate::derive::data_models::{
+    CStyleEnum, ConvertField, ConvertFields, ConvertType, DataEnum, FieldsKind, GodotConvert,
+    NewtypeStruct, ViaType,
+};
 use crate::derive::derive_godot_convert::EnumeratorExprCache;
 
 /// Creates a `ToGodot` impl for the given `GodotConvert`.
@@ -23,15 +26,22 @@ pub fn make_togodot(convert: &GodotConvert, cache: &mut EnumeratorExprCache) ->
     match data {
         ConvertType::NewType { field } => make_togodot_for_newtype_struct(name, field),
 
-        ConvertType::Enum {
-            variants,
-            via: ViaType::GString { .. },
-        } => make_togodot_for_string_enum(name, variants),
+        ConvertType::Struct { fields, .. } => make_togodot_for_struct(name, fields),
+
+        ConvertType::DataEnum { variants, .. } => make_togodot_for_data_enum(name, variants),
+
+        ConvertType::Enum { variants, via } if via.is_string() => {
+            make_togodot_for_string_enum(name, variants, via)
+        }
 
         ConvertType::Enum {
             variants,
             via: ViaType::Int { int_ident },
         } => make_togodot_for_int_enum(name, variants, int_ident, cache),
+
+        ConvertType::Enum { .. } => {
+            unreachable!("C-style enums are not converted via Dictionary or Array")
+        }
     }
 }
 
@@ -80,16 +90,17 @@ fn make_togodot_for_int_enum(
     }
 }
 
-/// Derives `ToGodot` for enums with a via type of `GString`.
-fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream {
+/// Derives `ToGodot` for enums with a via type of `GString` or `StringName`.
+fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum, via: &ViaType) -> TokenStream {
     let names = enum_.enumerator_names();
-    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let names_str = enum_.enumerator_godot_names();
+    let via_type = make_string_via_type(via);
 
     quote! {
         impl ::godot::meta::ToGodot for #name {
-            type ToVia<'v> = ::godot::builtin::GString;
+            type ToVia<'v> = #via_type;
 
-            fn to_godot(&self) -> ::godot::builtin::GString {
+            fn to_godot(&self) -> #via_type {
                 match self {
                     #(
                         #name::#names => #names_str.into(),
@@ -99,3 +110,111 @@ fn make_togodot_for_string_enum(name: &Ident, enum_: &CStyleEnum) -> TokenStream
         }
     }
 }
+
+/// Returns the fully qualified via type of enums represented by strings.
+pub(super) fn make_string_via_type(via: &ViaType) -> TokenStream {
+    match via {
+        ViaType::StringName { .. } => quote! { ::godot::builtin::StringName },
+        _ => quote! { ::godot::builtin::GString },
+    }
+}
+
+/// Derives `ToGodot` for structs with named fields (via `Dictionary`) or tuple structs (via `Array`).
+fn make_togodot_for_struct(name: &Ident, fields: &ConvertFields) -> TokenStream {
+    let via_type = make_compound_via_type(fields);
+    let to_via = make_compound_to_via(fields, |field| {
+        let member = &field.member;
+        quote! { &self.#member }
+    });
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type ToVia<'v> = #via_type;
+
+            fn to_godot(&self) -> #via_type {
+                #to_via
+            }
+        }
+    }
+}
+
+/// Derives `ToGodot` for enums with data, as a dictionary `{ tag: payload }` with a single entry.
+///
+/// The payload is `null` for unit variants, the value itself for single-field tuple variants, an array for other tuple variants
+/// and a dictionary for variants with named fields.
+fn make_togodot_for_data_enum(name: &Ident, enum_: &DataEnum) -> TokenStream {
+    let arms = enum_.variants.iter().map(|variant| {
+        let variant_name = &variant.name;
+        let tag = &variant.tag;
+        let pattern = variant.fields.binding_pattern();
+
+        let payload = if variant.fields.kind == FieldsKind::Unit {
+            quote! { ::godot::builtin::Variant::nil() }
+        } else if variant.fields.is_newtype() {
+            let binding = &variant.fields.fields[0].binding;
+            quote! { ::godot::meta::ToGodot::to_variant(#binding) }
+        } else {
+            make_compound_to_via(&variant.fields, |field| {
+                let binding = &field.binding;
+                quote! { #binding }
+            })
+        };
+
+        quote! {
+            #name::#variant_name #pattern => dict.set(#tag, #payload),
+        }
+    });
+
+    quote! {
+        impl ::godot::meta::ToGodot for #name {
+            type ToVia<'v> = ::godot::builtin::Dictionary;
+
+            fn to_godot(&self) -> ::godot::builtin::Dictionary {
+                let mut dict = ::godot::builtin::Dictionary::new();
+                match self {
+                    #( #arms )*
+                }
+                dict
+            }
+        }
+    }
+}
+
+/// Returns the via type of a struct or enum payload with the given fields.
+pub(super) fn make_compound_via_type(fields: &ConvertFields) -> TokenStream {
+    match fields.kind {
+        FieldsKind::Named => quote! { ::godot::builtin::Dictionary },
+        FieldsKind::Tuple | FieldsKind::Unit => quote! { ::godot::builtin::VariantArray },
+    }
+}
+
+/// Creates an expression which converts all non-skipped fields into a `Dictionary` (named fields) or `VariantArray` (tuple fields).
+///
+/// `access` returns an expression of type `&FieldType` for each field.
+fn make_compound_to_via(
+    fields: &ConvertFields,
+    access: impl Fn(&ConvertField) -> TokenStream,
+) -> TokenStream {
+    let values = fields.converted().map(access);
+
+    match fields.kind {
+        FieldsKind::Named => {
+            let keys = fields.converted().map(|field| &field.key);
+
+            quote! {{
+                let mut dict = ::godot::builtin::Dictionary::new();
+                #(
+                    dict.set(#keys, ::godot::meta::ToGodot::to_variant(#values));
+                )*
+                dict
+            }}
+        }
+        FieldsKind::Tuple | FieldsKind::Unit => quote! {{
+            let mut array = ::godot::builtin::VariantArray::new();
+            #(
+                array.push(&::godot::meta::ToGodot::to_variant(#values));
+            )*
+            array
+        }},
+    }
+}
@@ -40,7 +40,8 @@ pub fn derive_var(item: venial::Item) -> ParseResult<TokenStream> {
 
 /// Make an appropriate property hint implementation.
 ///
-/// For newtype structs we just defer to the wrapped type. For enums we use `PropertyHint::ENUM` with an appropriate hint string.
+/// For newtype structs we just defer to the wrapped type, for compound types to the via type. For C-style enums we use `PropertyHint::ENUM`
+/// with an appropriate hint string.
 fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
     use super::data_models::ConvertType as Data;
     use super::data_models::ViaType;
@@ -52,10 +53,19 @@ fn create_property_hint_impl(convert: &GodotConvert) -> TokenStream {
                 <#ty as ::godot::register::property::Var>::var_hint()
             }
         }
+        Data::Struct { .. } | Data::DataEnum { .. } => {
+            let via_type = convert.convert_type.via_type();
+            quote! {
+                <#via_type as ::godot::register::property::Var>::var_hint()
+            }
+        }
         Data::Enum { variants, via } => {
             let hint_string = match via {
-                ViaType::GString { .. } => variants.to_string_hint(),
+                ViaType::GString { .. } | ViaType::StringName { .. } => variants.to_string_hint(),
                 ViaType::Int { .. } => variants.to_int_hint(),
+                ViaType::Dictionary { .. } | ViaType::Array { .. } => {
+                    unreachable!("C-style enums are not converted via Dictionary or Array")
+                }
             };
 
             quote! {
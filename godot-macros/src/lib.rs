@@ -327,16 +327,94 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// When exporting an `Array<T>` or packed array, hints such as `#[export(range = ...)]` or `#[export(file)]` apply to each element,
+/// like in GDScript. Arrays of resources and nodes, e.g. `Array<Gd<Texture2D>>`, show the corresponding class picker for their elements.
+///
+/// `#[export(storage)]` corresponds to `@export_storage`: the property is saved with the scene or resource, but not shown in the editor.
+///
+/// ## Tool buttons
+///
+/// Since Godot 4.4, a `Callable` field annotated with `#[export(tool_button = (method, "Text", "Icon"))]` is displayed as a button
+/// in the inspector, which invokes `method` when pressed. This corresponds to `@export_tool_button` and requires the class to be a
+/// `#[class(tool)]` with a `Base<T>` field. The icon is optional. The field itself is never read; it is initialized to an invalid
+/// `Callable` by the generated `init`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, tool, base=Node)]
+/// struct Spawner {
+///     #[export(tool_button = (spawn, "Spawn", "Add"))]
+///     spawn_button: Callable,
+///
+///     // @export_storage
+///     #[export(storage)]
+///     spawn_count: i32,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Spawner {
+///     #[func]
+///     fn spawn(&mut self) {
+///         self.spawn_count += 1;
+///     }
+/// }
+/// ```
+///
+/// ## Inspector groups
+///
+/// Exported properties can be organized in the editor inspector with `#[export_category]`, `#[export_group]` and `#[export_subgroup]`,
+/// the equivalents of GDScript's annotations of the same name. Each attribute starts a new category/group/subgroup right before the
+/// annotated field, which then contains all following properties (in declaration order):
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// # #[class(init)]
+/// struct Player {
+///     // @export_category("Player")
+///     #[export_category(name = "Player")]
+///     // @export_group("Movement", "move_")
+///     #[export_group(name = "Movement", prefix = "move_")]
+///     #[export]
+///     move_speed: f32,
+///     #[export]
+///     move_acceleration: f32,
+///
+///     // @export_group("Combat")
+///     // @export_subgroup("Melee")
+///     #[export_group(name = "Combat")]
+///     #[export_subgroup(name = "Melee")]
+///     #[export]
+///     damage: i32,
+///
+///     // @export_group("") ends the current group.
+///     #[export_group(name = "")]
+///     #[export]
+///     name: GString,
+/// }
+/// ```
+///
+/// If a `prefix` is given, only properties whose names start with it are shown in the group, with the prefix stripped.
+///
 /// # Signals
 ///
-/// The `#[signal]` attribute is quite limited at the moment. The functions it decorates (the signals) can accept parameters.
-/// It will be fundamentally reworked.
+/// Functions decorated with `#[signal]` declare signals. They have no body and can accept parameters.
+///
+/// For every class with at least one signal, `#[godot_api]` generates a signal collection named `SignalsOf{Class}`, accessible through
+/// `self.signals()` inside the class (requires a `Base<T>` field), or through `Gd::signals()` from outside. Each signal in this collection
+/// is a [`TypedSignal`](../register/struct.TypedSignal.html), whose `emit()` and `connect*()` methods are checked against the declared
+/// parameter types at compile time. The accessor methods have the same visibility as the `#[signal]` declarations.
 ///
 /// ```no_run
 /// # use godot::prelude::*;
 /// #[derive(GodotClass)]
 /// # #[class(init)]
-/// struct MyClass {}
+/// struct MyClass {
+///     base: Base<RefCounted>,
+/// }
 ///
 /// #[godot_api]
 /// impl MyClass {
@@ -345,6 +423,13 @@ use crate::util::{bail, ident, KvParser};
 ///
 ///     #[signal]
 ///     fn some_signal_with_parameters(my_parameter: Gd<Node>);
+///
+///     #[signal]
+///     fn damage_taken(amount: i32);
+///
+///     fn take_damage(&mut self, amount: i32) {
+///         self.signals().damage_taken().emit(amount);
+///     }
 /// }
 /// ```
 ///
@@ -485,7 +570,20 @@ use crate::util::{bail, ident, KvParser};
     alias = "tool",
     alias = "rename"
 )]
-#[proc_macro_derive(GodotClass, attributes(class, base, hint, var, export, init))]
+#[proc_macro_derive(
+    GodotClass,
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_category,
+        export_group,
+        export_subgroup,
+        init
+    )
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -651,6 +749,85 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Borrowed parameters
+///
+/// Parameters can be declared as shared references `&T`, if `T` implements [`ParamBorrow`](../meta/trait.ParamBorrow.html): this includes
+/// `Gd<T>`, `Array<T>`, `Dictionary`, `Variant`, string and packed array types. When Godot calls such a function through ptrcall (e.g. from
+/// statically typed GDScript), the argument is borrowed from the caller, avoiding ref-count increments and string copies. Dynamic calls
+/// (varcall) still convert the argument into an owned value. The function is registered with the same parameter types as if `T` were
+/// taken by value.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn count_children(&self, node: &Gd<Node>, prefix: &GString) -> i64 {
+///         node.get_children()
+///             .iter_shared()
+///             .filter(|child| child.get_name().to_string().starts_with(&prefix.to_string()))
+///             .count() as i64
+///     }
+/// }
+/// ```
+///
+/// `#[func(virtual)]` functions accept borrowed parameters as well. When a script overrides such a function, the arguments are converted to
+/// variants for the script call.
+///
+/// The engine's `I*` virtual traits take objects, strings, arrays, dictionaries and variants by reference too, for example
+/// `fn input(&mut self, event: &Gd<InputEvent>)`. Objects which may be null are passed as `Option<Gd<T>>`.
+///
+/// ## Default parameters
+///
+/// Trailing parameters can be given default values with `#[opt(default = expr)]`. The expression must evaluate to the parameter's type.
+/// Godot callers (e.g. GDScript) may then omit these arguments, whereas Rust code still calls the function with all parameters.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func]
+///     fn spawn(&mut self, pos: Vector2, #[opt(default = 1)] count: i32) {
+///         // ...
+///     }
+/// }
+/// ```
+///
+/// In GDScript, this can be called as `obj.spawn(pos)` or `obj.spawn(pos, 3)`. Default values are evaluated during class registration
+/// and again for each call that omits arguments.
+///
+/// ## Variadic functions
+///
+/// With `#[func(vararg)]`, a function accepts any number of arguments after its regular parameters, like GDScript's `print()`.
+/// The last parameter must be of type `&[&Variant]` and receives all excess arguments. Such functions cannot have default parameters.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # #[class(init)]
+/// # struct MyStruct {
+/// #     base: Base<RefCounted>,
+/// # }
+/// #[godot_api]
+/// impl MyStruct {
+///     #[func(vararg)]
+///     fn log(&self, level: i32, args: &[&Variant]) {
+///         let message: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+///         godot_print!("[{level}] {}", message.join(" "));
+///     }
+/// }
+/// ```
+///
 /// ## Virtual methods
 ///
 /// Functions with the `#[func(virtual)]` attribute are virtual functions, meaning attached scripts can override them.
@@ -770,6 +947,46 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// [`TransferMode`]: ../classes/multiplayer_peer/struct.TransferMode.html
 /// [`RpcConfig`]: ../register/struct.RpcConfig.html
 ///
+/// # Computed properties
+///
+/// Properties usually need a backing field with `#[var]` or `#[export]`. For values that are derived from other state, you can instead
+/// declare a property on a getter method with `#[property(get)]`:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct MyStruct {
+///     health: i32,
+///     max_health: i32,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {
+///     #[property(get)]
+///     fn health_percent(&self) -> f32 {
+///         self.health as f32 / self.max_health as f32 * 100.0
+///     }
+///
+///     #[property(get, set = set_health_ratio, rename = ratio)]
+///     fn health_ratio(&self) -> f32 {
+///         self.health as f32 / self.max_health as f32
+///     }
+///
+///     #[func]
+///     fn set_health_ratio(&mut self, ratio: f32) {
+///         self.health = (ratio * self.max_health as f32) as i32;
+///     }
+/// }
+/// ```
+///
+/// The getter must have the signature `fn(&self) -> T`, where `T` implements [`Var`](../register/property/trait.Var.html). It is registered
+/// as method `get_<property>`, following Godot conventions. Available keys:
+/// - `set = method`: a `#[func]` in the same `impl` block, accepting the new value. Without it, the property is read-only.
+/// - `rename = name`: registers the property under a different name than the method.
+///
+/// Computed properties are visible in the editor inspector (read-only without a setter), but not stored in scenes or resources.
+///
 /// # Constants and signals
 ///
 /// Please refer to [the book](https://godot-rust.github.io/book/register/constants.html).
@@ -800,6 +1017,8 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 #[doc(
     alias = "func",
     alias = "rpc",
+    alias = "opt",
+    alias = "vararg",
     alias = "virtual",
     alias = "signal",
     alias = "constant",
@@ -860,7 +1079,7 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_dyn)
 }
 
-/// Derive macro for [`GodotConvert`](../meta/trait.GodotConvert.html) on structs.
+/// Derive macro for [`GodotConvert`](../meta/trait.GodotConvert.html) on structs and enums.
 ///
 /// This derive macro also derives [`ToGodot`](../meta/trait.ToGodot.html) and [`FromGodot`](../meta/trait.FromGodot.html).
 ///
@@ -932,11 +1151,12 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// type.
 ///
 /// The types you can use this with currently are:
-/// - `GString`
+/// - `GString`, `StringName`
 /// - `i8`, `i16`, `i32`, `i64`
 /// - `u8`, `u16`, `u32`
 ///
-/// When using one of the integer types, each variant of the enum will be converted into its discriminant.
+/// When using one of the string types, each variant of the enum will be converted into its name. When using one of the integer types,
+/// each variant of the enum will be converted into its discriminant.
 ///
 /// ### Examples
 ///
@@ -986,6 +1206,97 @@ pub fn godot_dyn(_meta: TokenStream, input: TokenStream) -> TokenStream {
 /// assert_eq!(MyEnum::B.to_godot(), 10);
 /// assert_eq!(MyEnum::C.to_godot(), 11);
 /// ```
+///
+/// ### Names of enumerators
+///
+/// The name of an individual enumerator can be changed with `#[godot(rename = "...")]`, which accepts a string literal or identifier.
+/// For string via types, this is the value stored in Godot; for integer ones, it is the name displayed in the editor.
+///
+/// With the additional `case_insensitive` key, string via types accept any capitalization of the names when converting from Godot.
+/// Converting to Godot always produces the name as declared.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// #[derive(GodotConvert)]
+/// #[godot(via = StringName, case_insensitive)]
+/// enum Faction {
+///     #[godot(rename = "the_empire")]
+///     Empire,
+///     Rebels,
+/// }
+///
+/// assert_eq!(Faction::Empire.to_godot(), StringName::from("the_empire"));
+/// assert!(matches!(Faction::from_godot(StringName::from("REBELS")), Faction::Rebels));
+/// ```
+///
+/// ## `via = Dictionary` and `via = Array`
+///
+/// Structs with named fields can be converted to a `Dictionary`, with one entry per field. The key is the field name.
+/// Tuple structs can be converted to an untyped `Array` (`VariantArray`), with one element per field. Each field type must implement
+/// `ToGodot` and `FromGodot`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary)]
+/// struct Stats {
+///     health: i64,
+///     name: GString,
+/// }
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Array)]
+/// struct Range(i32, i32);
+///
+/// let stats = Stats { health: 100, name: "Orc".into() };
+/// assert_eq!(stats.to_godot(), dict! { "health": 100, "name": "Orc" });
+/// assert_eq!(Range(1, 5).to_godot(), varray![1, 5]);
+/// ```
+///
+/// Enums whose variants carry data can be converted to a `Dictionary` with a single entry. Its key is the variant name, its value
+/// the payload: `null` for unit variants, the value itself for variants with a single unnamed field, an array for other tuple variants,
+/// and a dictionary for variants with named fields.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary)]
+/// enum Shape {
+///     Point,
+///     Circle(f32),
+///     Rect { width: f32, height: f32 },
+/// }
+///
+/// assert_eq!(Shape::Point.to_godot(), dict! { "Point": Variant::nil() });
+/// assert_eq!(Shape::Circle(2.0).to_godot(), dict! { "Circle": 2.0 });
+/// ```
+///
+/// ### Field options
+///
+/// Fields and enum variants accept further options in a `#[godot(...)]` attribute:
+/// - `rename = "name"`: use `name` as the dictionary key instead of the field name, or as the tag instead of the variant name.
+///   Not available for tuple fields.
+/// - `default`: if the key or element is absent, use `Default::default()` instead of failing the conversion.
+/// - `skip`: do not convert the field at all; it is always initialized with `Default::default()`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert)]
+/// #[godot(via = Dictionary)]
+/// struct Settings {
+///     #[godot(rename = volume_db)]
+///     volume: f32,
+///     #[godot(default)]
+///     muted: bool,
+///     #[godot(skip)]
+///     dirty: bool,
+/// }
+/// ```
+///
+/// When converting from Godot, errors report the key or index of the field which could not be converted.
 #[proc_macro_derive(GodotConvert, attributes(godot))]
 pub fn derive_godot_convert(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_godot_convert)
@@ -186,6 +186,47 @@ impl KvParser {
         Ok(Some(b))
     }
 
+    /// Handles an optional key whose value is either an identifier or a plain string literal, e.g. `key = name` or `key = "Some Name"`.
+    ///
+    /// Returns the identifier's name or the literal's content.
+    pub fn handle_ident_or_string(&mut self, key: &str) -> ParseResult<Option<String>> {
+        let Some(expr) = self.handle_expr(key)? else {
+            return Ok(None);
+        };
+
+        let mut tokens = expr.into_iter();
+        let value = match tokens.next() {
+            Some(TokenTree::Ident(id)) => id.to_string(),
+            Some(TokenTree::Literal(lit)) => {
+                let lit_str = lit.to_string();
+                match lit_str.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+                    Some(content) if !content.contains('\\') => content.to_string(),
+                    _ => {
+                        return bail!(
+                            lit,
+                            "value for '{key}' must be an identifier or a string literal without escape sequences"
+                        )
+                    }
+                }
+            }
+            _ => {
+                return bail!(
+                    key,
+                    "missing value for '{key}' (must be identifier or string literal)"
+                )
+            }
+        };
+
+        if let Some(surplus) = tokens.next() {
+            return bail!(
+                surplus,
+                "value for '{key}' must be identifier or string literal; found extra tokens"
+            );
+        }
+
+        Ok(Some(value))
+    }
+
     /// Handles a key that must be provided and must have an identifier as the value.
     pub fn handle_ident_required(&mut self, key: &str) -> ParseResult<Ident> {
         self.handle_ident(key)?
@@ -35,6 +35,7 @@
 //! * [`tools`], higher-level utilities that extend the generated code, e.g. `load<T>()`.
 //! * [`meta`], fundamental information about types, properties and conversions.
 //! * [`init`], entry point and global library configuration.
+//! * [`task`], integration of Rust `async`/`.await` with signals and the main loop.
 //!
 //! The [`prelude`] contains often-imported symbols; feel free to `use godot::prelude::*` in your code.
 //! <br><br>
@@ -123,7 +124,13 @@
 //!   Generates documentation for your structs from your Rust documentation.
 //!   Documentation is visible in Godot via `F1` -> searching for that class.
 //!   This feature requires at least Godot 4.3.
-//!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)
+//!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)<br><br>
+//!
+//! * **`leak-diagnostics`**
+//!
+//!   Records objects created from Rust (`new_alloc()`, `new_gd()`, `Gd::from_init_fn()`) with their creation site, and reports those still
+//!   alive as leaks when the extension is unloaded. Live objects can be queried at runtime through `godot::obj::leak_diagnostics`.
+//!   Intended for debugging; adds overhead to each object creation.
 //!
 //! _Integrations:_
 //!
@@ -165,7 +172,7 @@ compile_error!("The feature `double-precision` currently requires `api-custom` d
 // Modules
 
 #[doc(inline)]
-pub use godot_core::{builtin, classes, global, meta, obj, tools};
+pub use godot_core::{builtin, classes, global, meta, obj, task, tools};
 
 #[doc(hidden)]
 pub use godot_core::possibly_docs as docs;
@@ -183,7 +190,11 @@ pub mod init {
 
 /// Register/export Rust symbols to Godot: classes, methods, enums...
 pub mod register {
+    pub use godot_core::builder::{
+        ClassBuilder, MethodBuilder, MethodFn, PropertyBuilder, SignalBuilder,
+    };
     pub use godot_core::registry::property;
+    pub use godot_core::registry::signal::{ParamTuple, SignalReceiver, TypedSignal};
     pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
 
     #[cfg(feature = "__codegen-full")]
@@ -196,6 +207,7 @@ pub mod register {
         pub use godot_core::registry::class::auto_register_rpcs;
 
         pub use godot_core::registry::godot_register_wrappers::*;
+        pub use godot_core::registry::signal::{SignalObject, UserSignalObject};
         pub use godot_core::registry::{constant, method};
     }
 }
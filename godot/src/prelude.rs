@@ -28,7 +28,7 @@ pub use super::tools::{load, save, try_load, try_save, GFile};
 pub use super::init::{gdextension, ExtensionLibrary, InitLevel};
 pub use super::obj::{
     AsDyn, Base, DynGd, DynGdMut, DynGdRef, Gd, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnReady,
+    OnReady, OwnedGd, WeakGd,
 };
 
 // Make trait methods available.
@@ -37,3 +37,4 @@ pub use super::obj::EngineEnum as _;
 pub use super::obj::NewAlloc as _;
 pub use super::obj::NewGd as _;
 pub use super::obj::WithBaseField as _; // base(), base_mut(), to_gd()
+pub use super::obj::WithUserSignals as _; // signals()
@@ -142,6 +142,31 @@ func test_refcounted_as_object_return_from_user_func_ptrcall():
 	var obj: MockRefCountedRust = obj_test.return_refcounted_as_object()
 	assert_eq(obj.i, 42)
 
+func test_borrowed_params_varcall():
+	var obj = FuncBorrowObj.new()
+	var array: Array[int] = [1, 2, 3]
+	assert_eq(obj.string_len("hello"), 5)
+	assert_eq(obj.sum(array), 6)
+	assert_eq(obj.greet(), "hello world")
+
+func test_borrowed_params_ptrcall():
+	var obj: FuncBorrowObj = FuncBorrowObj.new()
+	var array: Array[int] = [1, 2, 3]
+	assert_eq(obj.string_len("hello"), 5)
+	assert_eq(obj.sum(array), 6)
+	assert_eq(obj.greet("Godot"), "hello Godot")
+
+	var node := Node.new()
+	node.name = "borrowed"
+	assert_eq(obj.node_name(node), &"borrowed")
+	node.free()
+
+	# Borrowed objects are not retained by the callee.
+	var refc := RefCounted.new()
+	var count_before := refc.get_reference_count()
+	assert_eq(obj.ref_count(refc), count_before)
+	assert_eq(refc.get_reference_count(), count_before)
+
 func test_custom_constructor():
 	var obj = CustomConstructor.construct_object(42)
 	assert_eq(obj.val, 42)
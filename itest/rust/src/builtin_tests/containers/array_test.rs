@@ -492,6 +492,278 @@ fn array_binary_search_custom() {
     assert_eq!(a.bsearch_custom(3, &func), 2);
 }
 
+#[itest]
+fn array_map_filter_reduce() {
+    let a = array![1, 2, 3, 4, 5];
+
+    let doubled: Array<i64> = a.map(|x| x as i64 * 2);
+    assert_eq!(doubled, array![2, 4, 6, 8, 10]);
+
+    let strings: Array<GString> = a.map(|x| x.to_string().into());
+    assert_eq!(strings, array!["1", "2", "3", "4", "5"]);
+
+    let even = a.filter(|x| x % 2 == 0);
+    assert_eq!(even, array![2, 4]);
+    assert_eq!(a.len(), 5, "filter() does not modify source");
+
+    let sum = a.reduce(0, |acc, x| acc + x);
+    assert_eq!(sum, 15);
+
+    let empty = Array::<i32>::new();
+    assert_eq!(empty.map(|x| x + 1), Array::<i32>::new());
+    assert_eq!(empty.reduce(7, |acc, x| acc + x), 7);
+}
+
+#[itest]
+fn array_any_all() {
+    let a = array![2, 4, 6];
+
+    assert!(a.all(|x| x % 2 == 0));
+    assert!(!a.all(|x| x > 2));
+    assert!(a.any(|x| x == 4));
+    assert!(!a.any(|x| x > 10));
+
+    let empty = Array::<i32>::new();
+    assert!(empty.all(|_| false));
+    assert!(!empty.any(|_| true));
+}
+
+#[itest]
+fn array_retain() {
+    let mut a = array![1, 2, 3, 4, 5, 6];
+    let shared = a.clone();
+
+    a.retain(|x| x % 3 != 0);
+    assert_eq!(a, array![1, 2, 4, 5]);
+    assert_eq!(shared, array![1, 2, 4, 5], "retain() modifies in place");
+
+    a.retain(|_| true);
+    assert_eq!(a, array![1, 2, 4, 5]);
+
+    a.retain(|_| false);
+    assert!(a.is_empty());
+
+    let mut a = array![1, 2, 3];
+    let mut shared = a.clone();
+    expect_panic("retain() with predicate changing the length", || {
+        a.retain(|_| {
+            shared.push(0);
+            true
+        });
+    });
+}
+
+#[itest]
+fn array_modify() {
+    let mut a = array!["a", "b"];
+
+    let len = a.modify(1, |s: &mut GString| {
+        *s = format!("{s}{s}").into();
+        s.len()
+    });
+    assert_eq!(len, 2);
+    assert_eq!(a, array!["a", "bb"]);
+
+    expect_panic("modify() out of bounds", || {
+        a.modify(2, |_| {});
+    });
+}
+
+#[itest]
+fn array_swap() {
+    let mut a = array![1, 2, 3];
+    a.swap(0, 2);
+    assert_eq!(a, array![3, 2, 1]);
+
+    a.swap(1, 1);
+    assert_eq!(a, array![3, 2, 1]);
+
+    expect_panic("swap() out of bounds", || {
+        a.swap(0, 3);
+    });
+}
+
+#[itest]
+fn array_split_off() {
+    let mut a = array![1, 2, 3, 4];
+
+    let tail = a.split_off(1);
+    assert_eq!(a, array![1]);
+    assert_eq!(tail, array![2, 3, 4]);
+
+    let tail = a.split_off(1);
+    assert_eq!(a, array![1]);
+    assert!(tail.is_empty());
+
+    expect_panic("split_off() out of bounds", || {
+        let _ = a.split_off(2);
+    });
+}
+
+#[itest]
+fn array_drain() {
+    let mut a = array![0, 1, 2, 3, 4, 5];
+
+    let drained = a.drain(1..3);
+    assert_eq!(drained, array![1, 2]);
+    assert_eq!(a, array![0, 3, 4, 5]);
+
+    let drained = a.drain(2..);
+    assert_eq!(drained, array![4, 5]);
+    assert_eq!(a, array![0, 3]);
+
+    let drained = a.drain(..);
+    assert_eq!(drained, array![0, 3]);
+    assert!(a.is_empty());
+
+    expect_panic("drain() out of bounds", || {
+        a.drain(0..1);
+    });
+    expect_panic("drain() with overflowing inclusive end", || {
+        a.drain(0..=usize::MAX);
+    });
+}
+
+#[itest]
+fn array_dedup() {
+    let mut a = array![1, 1, 2, 3, 3, 3, 1, 4, 4];
+    a.dedup();
+    assert_eq!(a, array![1, 2, 3, 1, 4]);
+
+    let mut empty = Array::<i32>::new();
+    empty.dedup();
+    assert!(empty.is_empty());
+}
+
+#[itest]
+fn array_chunks() {
+    let a = array![1, 2, 3, 4, 5];
+
+    let chunks = a.chunks(2);
+    assert_eq!(chunks.size_hint(), (3, Some(3)));
+
+    let chunks: Vec<Array<i32>> = chunks.collect();
+    assert_eq!(chunks, vec![array![1, 2], array![3, 4], array![5]]);
+
+    assert_eq!(Array::<i32>::new().chunks(3).count(), 0);
+
+    expect_panic("chunks(0)", || {
+        a.chunks(0);
+    });
+}
+
+#[itest]
+fn array_read_only() {
+    let mut a = array![3, 1, 2];
+    assert!(!a.is_read_only());
+
+    a.make_read_only();
+    assert!(a.is_read_only());
+
+    expect_panic("set() on read-only array", || {
+        a.set(0, 10);
+    });
+    expect_panic("modify() on read-only array", || {
+        a.modify(0, |x| *x += 1);
+    });
+    expect_panic("swap() on read-only array", || {
+        a.swap(0, 1);
+    });
+    expect_panic("retain() on read-only array", || {
+        a.retain(|_| false);
+    });
+    expect_panic("drain() on read-only array", || {
+        a.drain(..);
+    });
+    expect_panic("dedup() on read-only array", || {
+        a.dedup();
+    });
+    assert_eq!(a, array![3, 1, 2], "read-only array unchanged");
+
+    // Duplicates are writable again.
+    let mut copy = a.duplicate_shallow();
+    assert!(!copy.is_read_only());
+    copy.set(0, 10);
+    assert_eq!(copy, array![10, 1, 2]);
+}
+
+#[itest]
+fn array_into_read_only() {
+    let a = array![1, 2, 3];
+    let shared = a.clone();
+
+    let read_only = a.into_read_only();
+    assert!(read_only.is_read_only());
+    assert!(
+        shared.is_read_only(),
+        "other references observe read-only state"
+    );
+
+    // Shared accessors are available through Deref.
+    assert_eq!(read_only.len(), 3);
+    assert_eq!(read_only.get(1), Some(2));
+    assert_eq!(read_only.iter_shared().sum::<i64>(), 6);
+    assert_eq!(read_only.to_godot(), array![1, 2, 3]);
+}
+
+#[itest]
+fn array_read_only_from_godot() {
+    let mut writable = array![1, 2];
+    let read_only = ReadOnlyArray::<i64>::from_godot(writable.clone());
+    assert!(read_only.is_read_only());
+
+    // Non-read-only arrays are copied, leaving the original writable.
+    assert!(!writable.is_read_only());
+    writable.push(3);
+    assert_eq!(read_only.len(), 2);
+
+    // Read-only arrays are referenced directly.
+    let frozen = array![4, 5].into_read_only();
+    let converted = ReadOnlyArray::<i64>::from_godot(frozen.to_godot());
+    assert_eq!(converted, frozen);
+    assert_eq!(converted.to_variant(), frozen.to_variant());
+}
+
+#[itest]
+fn array_read_only_func() {
+    let mut obj = ArrayTest::new_gd();
+
+    let result = obj.call("sum_read_only_array", &[array![1, 2, 3].to_variant()]);
+    assert_eq!(result, 6.to_variant());
+
+    let result = obj.call("return_read_only_array", &[]);
+    let array = result.to::<Array<i64>>();
+    assert!(array.is_read_only());
+    assert_eq!(array, array![1, 2, 3]);
+}
+
+#[itest]
+#[cfg(since_api = "4.2")]
+fn array_functional_ops_callable() {
+    let a = array![1, 2, 3, 4];
+    let ops = a.functional_ops();
+
+    let is_even = Callable::from_local_fn("is_even", |args: &[&Variant]| {
+        Ok((args[0].to::<i32>() % 2 == 0).to_variant())
+    });
+    let square = Callable::from_local_fn("square", |args: &[&Variant]| {
+        let x = args[0].to::<i32>();
+        Ok((x * x).to_variant())
+    });
+    let sum = Callable::from_local_fn("sum", |args: &[&Variant]| {
+        Ok((args[0].to::<i32>() + args[1].to::<i32>()).to_variant())
+    });
+
+    let filtered: Array<i32> = ops.filter(&is_even);
+    assert_eq!(filtered, array![2, 4]);
+
+    assert_eq!(ops.map(&square), varray![1, 4, 9, 16]);
+    assert_eq!(ops.reduce(&sum, &10.to_variant()), 20.to_variant());
+
+    assert!(ops.any(&is_even));
+    assert!(!ops.all(&is_even));
+}
+
 #[cfg(since_api = "4.2")]
 fn backwards_sort_callable() -> Callable {
     Callable::from_local_fn("sort backwards", |args: &[&Variant]| {
@@ -542,4 +814,14 @@ impl ArrayTest {
     fn return_typed_array(&self, n: i64) -> Array<i64> {
         (1..(n + 1)).collect()
     }
+
+    #[func]
+    fn return_read_only_array(&self) -> ReadOnlyArray<i64> {
+        array![1, 2, 3].into_read_only()
+    }
+
+    #[func]
+    fn sum_read_only_array(&self, array: ReadOnlyArray<i64>) -> i64 {
+        array.iter_shared().sum()
+    }
 }
@@ -416,6 +416,43 @@ pub mod custom_callable {
         assert_eq!(sum3, 0.to_variant());
     }
 
+    #[itest]
+    fn callable_from_typed_fn() {
+        let callable = Callable::from_typed_fn("concat", |a: i64, b: GString| format!("{a}-{b}"));
+
+        assert!(callable.is_valid());
+        assert!(callable.is_custom());
+        assert!(callable.object().is_none());
+
+        let result = callable.callv(&varray![12, "ab"]);
+        assert_eq!(result, "12-ab".to_variant());
+
+        // Unit return type maps to Nil.
+        let unit = Callable::from_typed_fn("unit", || {});
+        assert_eq!(unit.callv(&varray![]), Variant::nil());
+    }
+
+    #[itest]
+    fn callable_from_typed_fn_mismatch() {
+        let calls = std::rc::Rc::new(std::cell::Cell::new(0));
+        let callable = {
+            let calls = calls.clone();
+            Callable::from_typed_fn("typed", move |a: i64| {
+                calls.set(calls.get() + 1);
+                a * 2
+            })
+        };
+
+        // Causes errors in Godot, but should neither crash nor invoke the function.
+        assert_eq!(callable.callv(&varray![]), Variant::nil());
+        assert_eq!(callable.callv(&varray![1, 2]), Variant::nil());
+        assert_eq!(callable.callv(&varray!["not an int"]), Variant::nil());
+        assert_eq!(calls.get(), 0);
+
+        assert_eq!(callable.callv(&varray![21]), 42.to_variant());
+        assert_eq!(calls.get(), 1);
+    }
+
     #[itest]
     fn callable_custom_with_err() {
         let callable_with_err =
@@ -7,8 +7,14 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, Variant};
+#[cfg(since_api = "4.4")]
+use godot::builtin::VariantType;
+use godot::builtin::{
+    dict, varray, Dictionary, GString, ReadOnlyDictionary, TypedDictionary, Variant,
+};
 use godot::meta::{FromGodot, ToGodot};
+#[cfg(since_api = "4.4")]
+use godot::obj::EngineEnum;
 use godot::sys::GdextBuild;
 
 use crate::framework::{expect_panic, itest};
@@ -324,6 +330,71 @@ fn dictionary_clear() {
     assert!(dictionary.is_empty());
 }
 
+#[itest]
+fn dictionary_read_only() {
+    let mut dictionary = dict! { "foo": 0 };
+    assert!(!dictionary.is_read_only());
+
+    dictionary.make_read_only();
+    assert!(dictionary.is_read_only());
+
+    expect_panic("set() on read-only dictionary", || {
+        dictionary.set("foo", 1);
+    });
+    expect_panic("insert() on read-only dictionary", || {
+        let _ = dictionary.insert("bar", 2);
+    });
+    expect_panic("remove() on read-only dictionary", || {
+        dictionary.remove("foo");
+    });
+    expect_panic("clear() on read-only dictionary", || {
+        dictionary.clear();
+    });
+    expect_panic("extend_dictionary() on read-only dictionary", || {
+        dictionary.extend_dictionary(&dict! { "bar": 2 }, true);
+    });
+    expect_panic("extend() on read-only dictionary", || {
+        dictionary.extend([("bar", 2)]);
+    });
+    assert_eq!(
+        dictionary,
+        dict! { "foo": 0 },
+        "read-only dictionary unchanged"
+    );
+
+    // Duplicates are writable again.
+    let mut copy = dictionary.duplicate_shallow();
+    assert!(!copy.is_read_only());
+    copy.set("foo", 1);
+    assert_eq!(copy, dict! { "foo": 1 });
+}
+
+#[itest]
+fn dictionary_into_read_only() {
+    let dictionary = dict! { "foo": 0, "bar": true };
+    let shared = dictionary.clone();
+
+    let read_only = dictionary.into_read_only();
+    assert!(read_only.is_read_only());
+    assert!(
+        shared.is_read_only(),
+        "other references observe read-only state"
+    );
+
+    // Shared accessors are available through Deref.
+    assert_eq!(read_only.len(), 2);
+    assert_eq!(read_only.get("bar"), Some(true.to_variant()));
+    assert_eq!(read_only.to_godot(), dict! { "foo": 0, "bar": true });
+
+    // Non-read-only dictionaries are copied on conversion, leaving the original writable.
+    let mut writable = dict! { "foo": 0 };
+    let converted = ReadOnlyDictionary::from_godot(writable.clone());
+    assert!(converted.is_read_only());
+    assert!(!writable.is_read_only());
+    writable.set("bar", 1);
+    assert_eq!(converted.len(), 1);
+}
+
 #[itest]
 fn dictionary_find_key() {
     let dictionary = dict! {
@@ -685,3 +756,83 @@ fn dictionary_should_format_with_display() {
     };
     assert_eq!(format!("{d}"), "{ one: 1, two: true, three: <null> }")
 }
+
+#[itest]
+fn typed_dictionary_set_get() {
+    let mut dict = TypedDictionary::<GString, i64>::new();
+    assert!(dict.is_empty());
+
+    dict.set("one", 1);
+    assert_eq!(dict.insert("two", 2), None);
+    assert_eq!(dict.insert("two", 22), Some(2));
+
+    assert_eq!(dict.len(), 2);
+    assert_eq!(dict.at("one"), 1);
+    assert_eq!(dict.get("two"), Some(22));
+    assert_eq!(dict.get("three"), None);
+    assert!(dict.contains_key("one"));
+
+    assert_eq!(dict.remove("one"), Some(1));
+    assert!(!dict.contains_key("one"));
+
+    expect_panic("missing key", || {
+        dict.at("one");
+    });
+}
+
+#[itest]
+fn typed_dictionary_iter() {
+    let dict: TypedDictionary<i64, GString> =
+        [(1, GString::from("one")), (2, GString::from("two"))]
+            .into_iter()
+            .collect();
+
+    let entries: HashMap<i64, GString> = dict.iter_shared().collect();
+    assert_eq!(
+        entries,
+        HashMap::from([(1, GString::from("one")), (2, GString::from("two"))])
+    );
+
+    let keys: HashSet<i64> = dict.keys_array().iter_shared().collect();
+    assert_eq!(keys, HashSet::from([1, 2]));
+    assert_eq!(dict.values_array().len(), 2);
+}
+
+#[itest]
+fn typed_dictionary_convert() {
+    let dict: TypedDictionary<GString, i64> = [(GString::from("a"), 1)].into_iter().collect();
+
+    let variant = dict.to_variant();
+    let back = variant.to::<TypedDictionary<GString, i64>>();
+    assert_eq!(back, dict);
+
+    // Wrong value type.
+    let mismatched = dict! { "a": "not an int" }.to_variant();
+    let result = mismatched.try_to::<TypedDictionary<GString, i64>>();
+    assert!(result.is_err(), "conversion should fail: {result:?}");
+
+    // Partially typed dictionaries are allowed.
+    let untyped_values = TypedDictionary::<GString, Variant>::new();
+    assert!(untyped_values.is_empty());
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn typed_dictionary_runtime_type() {
+    let dict = TypedDictionary::<GString, i64>::new();
+    let inner = dict.as_untyped().as_inner();
+
+    assert!(inner.is_typed());
+    assert_eq!(
+        inner.get_typed_key_builtin(),
+        VariantType::STRING.ord() as i64
+    );
+    assert_eq!(
+        inner.get_typed_value_builtin(),
+        VariantType::INT.ord() as i64
+    );
+
+    // Untyped dictionaries cannot be converted to typed ones, even if the elements would match.
+    let untyped = dict! { "a": 1 }.to_variant();
+    assert!(untyped.try_to::<TypedDictionary<GString, i64>>().is_err());
+}
@@ -5,11 +5,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+
 use crate::framework::{expect_panic, itest};
 use godot::builtin::{
     Color, GString, PackedByteArray, PackedColorArray, PackedFloat32Array, PackedInt32Array,
     PackedStringArray,
 };
+use godot::classes::file_access::CompressionMode;
 
 #[itest]
 fn packed_array_default() {
@@ -48,6 +51,14 @@ fn packed_array_from_vec_i32() {
     assert_eq!(int32_array[1], 2);
 }
 
+#[itest]
+fn packed_array_from_vec_u8() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    let byte_array = PackedByteArray::from(bytes.clone());
+
+    assert_eq!(byte_array.as_slice(), bytes.as_slice());
+}
+
 #[itest]
 fn packed_array_from_vec_color() {
     const SRC: [Color; 3] = [
@@ -63,6 +74,27 @@ fn packed_array_from_vec_color() {
     }
 }
 
+#[itest]
+#[cfg(since_api = "4.3")]
+fn packed_array_vector4() {
+    use godot::builtin::{varray, PackedVector4Array, Variant, VariantArray, Vector4};
+    use godot::meta::{FromGodot, ToGodot};
+
+    let vectors = [Vector4::new(1.0, 2.0, 3.0, 4.0), Vector4::splat(-1.0)];
+    let mut array = PackedVector4Array::from(vectors.to_vec());
+
+    assert_eq!(array.as_slice(), &vectors);
+    array[1] = Vector4::ZERO;
+    assert_eq!(array.get(1), Some(Vector4::ZERO));
+
+    let variant_array = VariantArray::from(&array);
+    assert_eq!(variant_array, varray![vectors[0], Vector4::ZERO]);
+    assert_eq!(PackedVector4Array::from(&variant_array), array);
+
+    let variant: Variant = array.to_variant();
+    assert_eq!(PackedVector4Array::from_variant(&variant), array);
+}
+
 #[itest]
 fn packed_array_from_array_str() {
     let string_array = PackedStringArray::from(["hello".into(), "world".into()]);
@@ -305,3 +337,144 @@ fn packed_array_format() {
     let a = PackedByteArray::new();
     assert_eq!(format!("{a}"), "[]");
 }
+
+#[itest]
+fn packed_byte_array_encode_decode() {
+    let mut a = PackedByteArray::new();
+    a.resize(16);
+
+    assert_eq!(a.encode_u16(0, 0xBEEF), Ok(()));
+    assert_eq!(a.decode_u16(0), Ok(0xBEEF));
+
+    assert_eq!(a.encode_s32(2, -12345), Ok(()));
+    assert_eq!(a.decode_s32(2), Ok(-12345));
+
+    assert_eq!(a.encode_u64(8, u64::MAX - 1), Ok(()));
+    assert_eq!(a.decode_u64(8), Ok(u64::MAX - 1));
+
+    assert_eq!(a.encode_float(4, 1.5), Ok(()));
+    assert_eq!(a.decode_float(4), Ok(1.5));
+    assert_eq!(a.decode_u8(4), Ok(a[4]));
+
+    assert_eq!(a.encode_double(8, -0.25), Ok(()));
+    assert_eq!(a.decode_double(8), Ok(-0.25));
+
+    assert_eq!(a.encode_half(0, 2.0), Ok(()));
+    assert_eq!(a.decode_half(0), Ok(2.0));
+
+    // Out of bounds: nothing is written, and the array is not resized.
+    assert!(a.encode_u32(14, 1).unwrap_err().is_out_of_bounds());
+    assert!(a.encode_u8(16, 1).is_err());
+    assert!(a.decode_s64(9).is_err());
+    assert!(a.decode_u8(usize::MAX).is_err());
+    assert_eq!(a.len(), 16);
+
+    let err = a.decode_u32(14).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot access 4 byte(s) at offset 14 in array of length 16"
+    );
+}
+
+#[itest]
+fn packed_byte_array_compress() {
+    let original: PackedByteArray = (0..200u8).map(|i| i % 7).collect();
+
+    for mode in [
+        CompressionMode::FASTLZ,
+        CompressionMode::DEFLATE,
+        CompressionMode::ZSTD,
+        CompressionMode::GZIP,
+    ] {
+        let compressed = original.compress(mode).expect("compress");
+        assert_ne!(compressed, original);
+
+        let decompressed = compressed
+            .decompress(original.len(), mode)
+            .expect("decompress");
+        assert_eq!(decompressed, original, "roundtrip with {mode:?}");
+    }
+
+    let compressed = original.compress(CompressionMode::GZIP).unwrap();
+    let decompressed = compressed
+        .decompress_dynamic(None, CompressionMode::GZIP)
+        .expect("decompress_dynamic");
+    assert_eq!(decompressed, original);
+
+    // Corrupt data.
+    let garbage = PackedByteArray::from(&[1, 2, 3, 4]);
+    let err = garbage.decompress(100, CompressionMode::GZIP).unwrap_err();
+    assert!(!err.is_out_of_bounds());
+    assert!(garbage
+        .decompress_dynamic(None, CompressionMode::GZIP)
+        .is_err());
+}
+
+#[itest]
+fn packed_byte_array_compress_empty() {
+    let empty = PackedByteArray::new();
+
+    let compressed = empty.compress(CompressionMode::DEFLATE).expect("compress");
+    assert!(compressed.is_empty());
+
+    let decompressed = compressed
+        .decompress(0, CompressionMode::DEFLATE)
+        .expect("decompress");
+    assert!(decompressed.is_empty());
+
+    let decompressed = compressed
+        .decompress_dynamic(None, CompressionMode::DEFLATE)
+        .expect("decompress_dynamic");
+    assert!(decompressed.is_empty());
+
+    // Non-empty data with zero-sized output.
+    let original = PackedByteArray::from(&[1, 2, 3]);
+    let compressed = original.compress(CompressionMode::DEFLATE).unwrap();
+    let decompressed = compressed
+        .decompress(0, CompressionMode::DEFLATE)
+        .expect("decompress to 0 bytes");
+    assert!(decompressed.is_empty());
+}
+
+#[itest]
+fn packed_byte_array_reader() {
+    let a = PackedByteArray::from(&[1, 2, 3, 4, 5, b'\n', 6]);
+    let mut reader = a.reader();
+
+    let mut buf = [0; 3];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(reader.position(), 3);
+
+    let mut line = Vec::new();
+    reader.read_until(b'\n', &mut line).unwrap();
+    assert_eq!(line, [4, 5, b'\n']);
+
+    assert_eq!(reader.seek(SeekFrom::End(-2)).unwrap(), 5);
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, [b'\n', 6]);
+
+    // Past the end: reads return no data; before the start: error.
+    reader.seek(SeekFrom::Current(10)).unwrap();
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    assert!(reader.seek(SeekFrom::Current(-100)).is_err());
+}
+
+#[itest]
+fn packed_byte_array_writer() {
+    let mut a = PackedByteArray::from(&[9, 9, 9]);
+
+    let mut writer = a.writer();
+    writer.write_all(&[1, 2]).unwrap();
+    assert_eq!(writer.position(), 2);
+
+    // Overwrites and extends.
+    writer.write_all(&[3, 4, 5]).unwrap();
+
+    // Gaps are filled with zeroes.
+    writer.seek(SeekFrom::End(2)).unwrap();
+    writer.write_all(&[7]).unwrap();
+
+    assert_eq!(a.to_vec(), vec![1, 2, 3, 4, 5, 0, 0, 7]);
+}
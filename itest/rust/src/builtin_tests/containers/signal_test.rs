@@ -8,11 +8,16 @@
 use godot::builtin::{GString, Signal, StringName};
 use godot::classes::{Object, RefCounted};
 use godot::meta::ToGodot;
-use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField};
+use godot::obj::{Base, Gd, NewAlloc, NewGd, WithBaseField, WithUserSignals};
 use godot::register::{godot_api, GodotClass};
 use godot::sys;
 use std::cell::Cell;
 
+#[cfg(since_api = "4.2")]
+use godot::classes::Node;
+#[cfg(since_api = "4.2")]
+use std::rc::Rc;
+
 use crate::framework::itest;
 
 #[itest]
@@ -60,12 +65,146 @@ fn signal_construction_and_id() {
     assert_eq!(signal.object(), None);
 }
 
+#[itest]
+fn signal_symbols_emit_untyped_connect() {
+    let mut emitter = Emitter::new_alloc();
+    let receiver = Receiver::new_alloc();
+
+    emitter.connect("emitter_1", &receiver.callable("receiver_1"));
+    emitter.bind_mut().emit_signals_internal();
+    assert!(receiver.bind().used[1].get());
+
+    // No typed handler connected, so the emitter itself has not been notified.
+    assert_eq!(emitter.bind().last_received, 0);
+
+    let signal = emitter.signals().emitter_1().to_untyped();
+    assert_eq!(signal.name(), StringName::from("emitter_1"));
+    assert_eq!(signal.object(), Some(emitter.clone().upcast()));
+
+    receiver.free();
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_symbols_external() {
+    let emitter = Emitter::new_alloc();
+    let mut sig = emitter.signals().emitter_1();
+
+    // Local function; deliberately use a !Send type.
+    let tracker = Rc::new(Cell::new(0));
+    {
+        let tracker = tracker.clone();
+        sig.connect(move |i: i64| {
+            tracker.set(i);
+        });
+    }
+
+    // Self-modifying method.
+    sig.connect_self(Emitter::self_receive);
+
+    // Connect to other object.
+    let receiver = Receiver::new_alloc();
+    sig.connect_obj(&receiver, |r: &mut Receiver, arg1: i64| {
+        r.receiver_1(arg1);
+    });
+
+    sig.emit(987);
+
+    assert_eq!(tracker.get(), 987);
+    assert_eq!(emitter.bind().last_received, 987);
+    assert!(receiver.bind().used[1].get());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_symbols_internal() {
+    let mut emitter = Emitter::new_alloc();
+
+    // Connect/emit within the class, through &mut self.
+    emitter.bind_mut().connect_signals_internal();
+    emitter.bind_mut().emit_signals_internal();
+
+    // Handler borrowed the same object mutably, while emitting from &mut self.
+    assert_eq!(emitter.bind().last_received, 987);
+    LAST_STATIC_FUNCTION_ARG.with(|cell| assert_eq!(cell.get(), 987));
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_symbols_mismatched_args() {
+    let mut emitter = Emitter::new_alloc();
+
+    let tracker = Rc::new(Cell::new(0));
+    {
+        let tracker = tracker.clone();
+        emitter
+            .signals()
+            .emitter_1()
+            .connect(move |i: i64| tracker.set(i));
+    }
+
+    // Untyped emissions not matching the declaration are reported as call errors, without invoking the closure.
+    emitter.emit_signal("emitter_1", &["not a number".to_variant()]);
+    emitter.emit_signal("emitter_1", &[]);
+    assert_eq!(tracker.get(), 0);
+
+    emitter.signals().emitter_1().emit(987);
+    assert_eq!(tracker.get(), 987);
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_symbols_engine() {
+    let mut node = Node::new_alloc();
+
+    // Own signal of Node, with an object parameter.
+    let entered = Rc::new(Cell::new(None));
+    {
+        let entered = entered.clone();
+        node.signals()
+            .child_entered_tree()
+            .connect(move |n: Option<Gd<Node>>| {
+                entered.set(n.map(|n| n.instance_id()));
+            });
+    }
+
+    // Inherited signal of Object, reached through Deref of the signal collection.
+    let changed = Rc::new(Cell::new(false));
+    {
+        let changed = changed.clone();
+        node.signals()
+            .property_list_changed()
+            .connect(move || changed.set(true));
+    }
+
+    // Emitted by Godot.
+    node.notify_property_list_changed();
+    assert!(changed.get());
+
+    // Emitted through typed API.
+    node.signals().child_entered_tree().emit(Some(node.clone()));
+    assert_eq!(entered.get(), Some(node.instance_id()));
+
+    node.free();
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helper types
 
 #[derive(GodotClass)]
 #[class(init, base=Object)]
-struct Emitter {}
+struct Emitter {
+    last_received: i64,
+    base: Base<Object>,
+}
 
 #[godot_api]
 impl Emitter {
@@ -77,6 +216,32 @@ impl Emitter {
 
     #[signal]
     fn emitter_2(arg1: Gd<Object>, arg2: GString);
+
+    #[cfg(since_api = "4.2")]
+    fn self_receive(&mut self, arg1: i64) {
+        self.last_received = arg1;
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn self_receive_static(arg1: i64) {
+        LAST_STATIC_FUNCTION_ARG.set(arg1);
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn connect_signals_internal(&mut self) {
+        let mut sig = self.signals().emitter_1();
+        sig.connect_self(Self::self_receive);
+        sig.connect(Self::self_receive_static);
+    }
+
+    fn emit_signals_internal(&mut self) {
+        self.signals().emitter_1().emit(987);
+    }
+}
+
+#[cfg(since_api = "4.2")]
+thread_local! {
+    static LAST_STATIC_FUNCTION_ARG: Cell<i64> = const { Cell::new(0) };
 }
 
 #[derive(GodotClass)]
@@ -125,6 +290,24 @@ mod custom_callable {
     use crate::builtin_tests::containers::callable_test::custom_callable::PanicCallable;
     use crate::framework::{itest, TestContext};
 
+    #[itest]
+    fn signal_connect_fn() {
+        let mut node = Node::new_alloc();
+        node.add_user_signal("test_signal");
+
+        let received = std::rc::Rc::new(std::cell::Cell::new(0));
+        {
+            let received = received.clone();
+            let signal = Signal::from_object_signal(&node, "test_signal");
+            signal.connect_fn(move |value: i64| received.set(value), 0);
+        }
+
+        node.emit_signal("test_signal", &[987i64.to_variant()]);
+        assert_eq!(received.get(), 987);
+
+        node.free();
+    }
+
     #[itest]
     fn signal_panic_user_from_fn() {
         connect_signal_panic_shared(
@@ -5,9 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use godot::builtin::{
-    array, dict, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray, Vector2,
-    Vector2Axis,
+    array, dict, varray, Array, Dictionary, GString, NodePath, StringName, Variant, VariantArray,
+    Vector2, Vector2Axis,
 };
 use godot::classes::{Node, Resource};
 use godot::meta::error::ConvertError;
@@ -278,6 +280,70 @@ fn array_to_vec() {
     assert!(to.is_err());
 }
 
+#[itest]
+fn array_to_vec_element_error() {
+    let from = varray![1, 2, "three"];
+    let err = from.to_variant().try_to::<Vec<i32>>().unwrap_err();
+
+    assert!(err.to_string().contains("at index 2"), "{err}");
+}
+
+#[itest]
+fn hash_set_to_array() {
+    let from: HashSet<i32> = [1, 2, 3].into_iter().collect();
+    let array = from.to_variant().to::<Array<i32>>();
+    assert_eq!(array.len(), 3);
+
+    let back = array.to_variant().to::<HashSet<i32>>();
+    assert_eq!(back, from);
+}
+
+#[itest]
+fn map_to_dictionary() {
+    let from: HashMap<String, f32> = [("a".to_string(), 1.5), ("b".to_string(), -2.0)].into();
+    let dict = from.to_variant().to::<Dictionary>();
+    assert_eq!(dict, dict! { "a": 1.5, "b": -2.0 });
+
+    let back = dict.to_variant().to::<HashMap<String, f32>>();
+    assert_eq!(back, from);
+
+    let from: BTreeMap<i64, GString> = [(1, "one".into()), (2, "two".into())].into();
+    let back = from.to_variant().to::<BTreeMap<i64, GString>>();
+    assert_eq!(back, from);
+}
+
+#[itest]
+fn dictionary_to_map_element_error() {
+    let dict = dict! { "a": 1, "b": "two" };
+    let err = dict
+        .to_variant()
+        .try_to::<HashMap<String, i32>>()
+        .unwrap_err();
+
+    assert!(err.to_string().contains("with key b"), "{err}");
+}
+
+#[itest]
+fn tuple_to_array() {
+    let from = (1, GString::from("two"), Vector2::new(3.0, 4.0));
+    let array = from.to_variant().to::<VariantArray>();
+    assert_eq!(array, varray![1, "two", Vector2::new(3.0, 4.0)]);
+
+    let back = array.to_variant().to::<(i32, GString, Vector2)>();
+    assert_eq!(back, from);
+
+    // Wrong length.
+    let err = varray![1, 2].to_variant().try_to::<(i32,)>().unwrap_err();
+    assert!(err.to_string().contains("length 2"), "{err}");
+
+    // Wrong element type.
+    let err = varray![1, 2]
+        .to_variant()
+        .try_to::<(i32, GString)>()
+        .unwrap_err();
+    assert!(err.to_string().contains("at index 1"), "{err}");
+}
+
 #[itest]
 fn rust_array_to_array() {
     let from = [1, 2, 3];
@@ -29,37 +29,37 @@ impl IScriptExtension for TestScript {
         true
     }
 
-    unsafe fn instance_create(&self, for_object: Gd<Object>) -> *mut c_void {
-        create_script_instance(TestScriptInstance::new(self.to_gd().upcast()), for_object)
+    unsafe fn instance_create(&self, for_object: &Gd<Object>) -> *mut c_void {
+        create_script_instance(TestScriptInstance::new(self.to_gd().upcast()), for_object.clone())
     }
 
     fn editor_can_reload_from_file(&mut self) -> bool { unreachable!() }
     fn get_base_script(&self) -> Option<Gd<Script>> { unreachable!() }
     fn get_global_name(&self) -> StringName { unreachable!() }
-    fn inherits_script(&self, _script: Gd<Script>) -> bool { unreachable!() }
+    fn inherits_script(&self, _script: &Gd<Script>) -> bool { unreachable!() }
     fn get_instance_base_type(&self) -> StringName { unreachable!() }
-    unsafe fn placeholder_instance_create(&self, _for_object: Gd<Object>) -> *mut c_void { unreachable!() }
-    fn instance_has(&self, _object: Gd<Object>) -> bool { unreachable!() }
+    unsafe fn placeholder_instance_create(&self, _for_object: &Gd<Object>) -> *mut c_void { unreachable!() }
+    fn instance_has(&self, _object: &Gd<Object>) -> bool { unreachable!() }
     fn has_source_code(&self) -> bool { unreachable!() }
     fn get_source_code(&self) -> GString { unreachable!() }
-    fn set_source_code(&mut self, _code: GString) { unreachable!() }
+    fn set_source_code(&mut self, _code: &GString) { unreachable!() }
     fn reload(&mut self, _keep_state: bool) -> Error { unreachable!() }
     fn get_documentation(&self) -> Array<Dictionary> { unreachable!() }
-    fn has_method(&self, _method: StringName) -> bool { unreachable!() }
+    fn has_method(&self, _method: &StringName) -> bool { unreachable!() }
     #[cfg(since_api = "4.2")]
-    fn has_static_method(&self, _method: StringName) -> bool { unreachable!() }
-    fn get_method_info(&self, _method: StringName) -> Dictionary { unreachable!() }
+    fn has_static_method(&self, _method: &StringName) -> bool { unreachable!() }
+    fn get_method_info(&self, _method: &StringName) -> Dictionary { unreachable!() }
     fn is_tool(&self) -> bool { unreachable!() }
     fn is_valid(&self) -> bool { unreachable!() }
     fn get_language(&self) -> Option<Gd<ScriptLanguage>> { unreachable!() }
-    fn has_script_signal(&self, _signall: StringName) -> bool { unreachable!() }
+    fn has_script_signal(&self, _signall: &StringName) -> bool { unreachable!() }
     fn get_script_signal_list(&self) -> Array<Dictionary> { unreachable!() }
-    fn has_property_default_value(&self, _property: StringName) -> bool { unreachable!() }
-    fn get_property_default_value(&self, _property: StringName) -> Variant { unreachable!() }
+    fn has_property_default_value(&self, _property: &StringName) -> bool { unreachable!() }
+    fn get_property_default_value(&self, _property: &StringName) -> Variant { unreachable!() }
     fn update_exports(&mut self) { unreachable!() }
     fn get_script_method_list(&self) -> Array<Dictionary> { unreachable!() }
     fn get_script_property_list(&self) -> Array<Dictionary> { unreachable!() }
-    fn get_member_line(&self, _member: StringName) -> i32 { unreachable!() }
+    fn get_member_line(&self, _member: &StringName) -> i32 { unreachable!() }
     fn get_constants(&self) -> godot::prelude::Dictionary { unreachable!() }
     fn get_members(&self) -> godot::prelude::Array<StringName> { unreachable!() }
     fn is_placeholder_fallback_enabled(&self) -> bool { unreachable!() }
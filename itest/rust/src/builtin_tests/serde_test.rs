@@ -6,7 +6,13 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::{array, Array, Color, ColorHsv, GString, NodePath, StringName, Vector2i};
+use godot::builtin::{
+    array, dict, varray, Array, Callable, Color, ColorHsv, Dictionary, GString, NodePath,
+    PackedByteArray, PackedStringArray, StringName, Variant, Vector2i,
+};
+use godot::classes::RefCounted;
+use godot::meta::ToGodot;
+use godot::obj::NewGd;
 use serde::{Deserialize, Serialize};
 
 fn serde_roundtrip<T>(value: &T, expected_json: &str)
@@ -79,6 +85,105 @@ fn serde_array_godot_type() {
     serde_roundtrip(&value, expected_json)
 }
 
+#[itest]
+fn serde_packed_byte_array() {
+    let value = PackedByteArray::from(&[1, 2, 255]);
+
+    let expected_json = r#"[1,2,255]"#;
+
+    serde_roundtrip(&value, expected_json)
+}
+
+#[itest]
+fn serde_packed_string_array() {
+    let value = PackedStringArray::from(&["Godot".into(), "Rust".into()]);
+
+    let expected_json = r#"["Godot","Rust"]"#;
+
+    serde_roundtrip(&value, expected_json)
+}
+
+#[itest]
+#[cfg(since_api = "4.3")]
+fn serde_packed_vector4_array() {
+    use godot::builtin::{PackedVector4Array, Vector4};
+
+    let value = PackedVector4Array::from(&[Vector4::new(1.0, 2.0, 3.0, 4.0)]);
+
+    let expected_json = r#"[{"x":1.0,"y":2.0,"z":3.0,"w":4.0}]"#;
+
+    serde_roundtrip(&value, expected_json)
+}
+
+#[itest]
+fn serde_variant() {
+    serde_roundtrip(&Variant::nil(), r#""Nil""#);
+    serde_roundtrip(&5.to_variant(), r#"{"Int":5}"#);
+    serde_roundtrip(&2.5.to_variant(), r#"{"Float":2.5}"#);
+    serde_roundtrip(&"text".to_variant(), r#"{"String":"text"}"#);
+    serde_roundtrip(
+        &StringName::from("name").to_variant(),
+        r#"{"StringName":"name"}"#,
+    );
+    serde_roundtrip(
+        &Vector2i::new(1, 2).to_variant(),
+        r#"{"Vector2i":{"x":1,"y":2}}"#,
+    );
+    serde_roundtrip(
+        &PackedByteArray::from(&[1, 2]).to_variant(),
+        r#"{"PackedByteArray":[1,2]}"#,
+    );
+}
+
+#[itest]
+fn serde_variant_nested() {
+    let value = varray![1, "two", varray![true], dict! { "key": Variant::nil() }].to_variant();
+
+    let expected_json = r#"{"Array":[{"Int":1},{"String":"two"},{"Array":[{"Bool":true}]},{"Dictionary":[[{"String":"key"},"Nil"]]}]}"#;
+
+    serde_roundtrip(&value, expected_json)
+}
+
+#[itest]
+fn serde_variant_typed_array() {
+    let typed: Array<i64> = array![1, 2];
+    let json = serde_json::to_string(&typed.to_variant()).unwrap();
+    assert_eq!(json, r#"{"Array":[{"Int":1},{"Int":2}]}"#);
+
+    // Deserializes as untyped array.
+    let back: Variant = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, varray![1, 2].to_variant());
+}
+
+#[itest]
+fn serde_variant_unsupported() {
+    let object = RefCounted::new_gd().to_variant();
+    let err = serde_json::to_string(&object).unwrap_err();
+    assert!(
+        err.to_string().contains("cannot serialize Variant"),
+        "{err}"
+    );
+
+    let callable = Callable::invalid().to_variant();
+    assert!(serde_json::to_string(&callable).is_err());
+
+    // Also when nested.
+    assert!(serde_json::to_string(&varray![1, object]).is_err());
+
+    let err = serde_json::from_str::<Variant>(r#"{"Object":1}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown variant"), "{err}");
+}
+
+#[itest]
+fn serde_dictionary() {
+    let value: Dictionary = dict! { "level": 3, 7: Vector2i::new(1, 1) };
+
+    let expected_json =
+        r#"[[{"String":"level"},{"Int":3}],[{"Int":7},{"Vector2i":{"x":1,"y":1}}]]"#;
+
+    serde_roundtrip(&value, expected_json)
+}
+
 #[itest]
 fn color_serde() {
     let color = Color::default();
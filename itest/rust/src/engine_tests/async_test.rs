@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::builtin::{GString, Signal};
+use godot::classes::{Object, RefCounted};
+use godot::meta::ToGodot;
+use godot::obj::{Base, NewAlloc, NewGd};
+use godot::register::{godot_api, GodotClass};
+use godot::task;
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn async_task_resumes_on_signal() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("custom_signal");
+    let signal = Signal::from_object_signal(&object, "custom_signal");
+
+    let result = Rc::new(Cell::new(None));
+    let handle = {
+        let result = result.clone();
+        task::spawn(async move {
+            let (number, text): (i64, GString) = signal.to_future().await;
+            result.set(Some((number, text.to_string())));
+        })
+    };
+
+    // Task runs until its first await point.
+    assert!(handle.is_pending());
+    assert_eq!(result.take(), None);
+
+    // Task is resumed synchronously inside the emission.
+    object.emit_signal("custom_signal", &[987.to_variant(), "text".to_variant()]);
+    assert_eq!(result.take(), Some((987, "text".to_string())));
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn async_task_typed_signal() {
+    let mut emitter = AsyncEmitter::new_alloc();
+
+    let result = Rc::new(Cell::new(0));
+    let handle = {
+        let result = result.clone();
+        let emitter = emitter.clone();
+        task::spawn(async move {
+            let (value,) = emitter.signals().value_changed().await;
+            result.set(value);
+        })
+    };
+
+    assert!(handle.is_pending());
+
+    emitter.signals().value_changed().emit(123);
+    assert_eq!(result.get(), 123);
+    assert!(!handle.is_pending());
+
+    emitter.free();
+}
+
+#[itest]
+fn async_task_completes_immediately() {
+    let done = Rc::new(Cell::new(false));
+    let handle = {
+        let done = done.clone();
+        task::spawn(async move { done.set(true) })
+    };
+
+    assert!(done.get());
+    assert!(!handle.is_pending());
+}
+
+#[itest]
+fn async_task_cancel() {
+    let mut object = RefCounted::new_gd();
+    object.add_user_signal("custom_signal");
+    let signal = Signal::from_object_signal(&object, "custom_signal");
+
+    let resumed = Rc::new(Cell::new(false));
+    let handle = {
+        let resumed = resumed.clone();
+        let signal = signal.clone();
+        task::spawn(async move {
+            let () = signal.to_future().await;
+            resumed.set(true);
+        })
+    };
+
+    assert!(handle.is_pending());
+    assert_eq!(signal.connections().len(), 1);
+
+    // Dropping the task's future disconnects from the signal.
+    handle.cancel();
+    assert!(!handle.is_pending());
+    assert_eq!(signal.connections().len(), 0);
+
+    object.emit_signal("custom_signal", &[]);
+    assert!(!resumed.get());
+}
+
+#[itest]
+fn async_spawn_off_main_thread() {
+    std::thread::spawn(|| {
+        expect_panic("task::spawn() on other thread", || {
+            task::spawn(async {});
+        });
+    })
+    .join()
+    .expect("spawn() should panic on other thread");
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helper types
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct AsyncEmitter {
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl AsyncEmitter {
+    #[signal]
+    fn value_changed(value: i64);
+}
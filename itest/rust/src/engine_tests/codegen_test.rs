@@ -127,4 +127,8 @@ impl CodegenTest2 {
     #[cfg(since_api = "4.3")]
     #[func(virtual)]
     fn with_virtual_many_unnamed(&self, _: i32, _: GString) {}
+
+    #[cfg(since_api = "4.3")]
+    #[func(virtual)]
+    fn with_virtual_borrowed(&self, _: &GString, _node: &Gd<Node>) {}
 }
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+#![cfg(feature = "leak-diagnostics")]
+
+use godot::classes::{Node, RefCounted};
+use godot::obj::{leak_diagnostics, Gd, GodotClass, NewAlloc, NewGd};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(no_init, base=RefCounted)]
+struct LeakTracked {
+    value: i32,
+}
+
+#[itest]
+fn leak_diagnostics_manual() {
+    let before = leak_diagnostics::live_count::<Node>();
+
+    let node = Node::new_alloc();
+    assert_eq!(leak_diagnostics::live_count::<Node>(), before + 1);
+
+    node.free();
+    assert_eq!(leak_diagnostics::live_count::<Node>(), before);
+}
+
+#[itest]
+fn leak_diagnostics_refcounted() {
+    let before = leak_diagnostics::live_count::<RefCounted>();
+
+    let obj = RefCounted::new_gd();
+    let copy = obj.clone();
+    assert_eq!(leak_diagnostics::live_count::<RefCounted>(), before + 1);
+
+    drop(obj);
+    assert_eq!(leak_diagnostics::live_count::<RefCounted>(), before + 1);
+
+    drop(copy);
+    assert_eq!(leak_diagnostics::live_count::<RefCounted>(), before);
+}
+
+#[itest]
+fn leak_diagnostics_user_class() {
+    let obj = Gd::from_object(LeakTracked { value: 7 });
+    assert_eq!(leak_diagnostics::live_count::<LeakTracked>(), 1);
+
+    let counts = leak_diagnostics::live_counts();
+    assert_eq!(counts.get(&LeakTracked::class_name()), Some(&1));
+    assert_eq!(obj.bind().value, 7);
+
+    drop(obj);
+    assert_eq!(leak_diagnostics::live_count::<LeakTracked>(), 0);
+    assert!(!leak_diagnostics::live_counts().contains_key(&LeakTracked::class_name()));
+}
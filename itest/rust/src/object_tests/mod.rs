@@ -15,15 +15,18 @@ mod enum_test;
 #[cfg(since_api = "4.3")]
 mod get_property_list_test;
 mod init_level_test;
+mod leak_diagnostics_test;
 mod object_arg_test;
 mod object_swap_test;
 mod object_test;
 mod onready_test;
+mod owned_gd_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
 mod singleton_test;
 mod virtual_methods_test;
+mod weak_gd_test;
 
 // Need to test this in the init level method.
 pub use init_level_test::initialize_init_level_test;
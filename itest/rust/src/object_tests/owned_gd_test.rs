@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{Node, Node3D, Object};
+use godot::obj::{NewAlloc, OwnedGd};
+
+use crate::framework::{expect_panic, itest};
+
+#[itest]
+fn owned_gd_frees_on_drop() {
+    let owned = OwnedGd::<Node3D>::new_alloc();
+    let node_ref = owned.clone();
+    assert!(node_ref.is_instance_valid());
+
+    drop(owned);
+    assert!(!node_ref.is_instance_valid(), "object freed");
+}
+
+#[itest]
+fn owned_gd_frees_hierarchy() {
+    let mut parent = OwnedGd::<Node>::new_alloc();
+    let child = OwnedGd::<Node>::new_alloc();
+    let grandchild = Node::new_alloc();
+
+    parent.add_child(&*child);
+    child.clone().add_child(&grandchild);
+
+    let child_ref = child.clone();
+
+    // Child is owned by the tree now; dropping its handle must not free it.
+    drop(child);
+    assert!(child_ref.is_instance_valid());
+
+    drop(parent);
+    assert!(!child_ref.is_instance_valid(), "child freed with parent");
+    assert!(
+        !grandchild.is_instance_valid(),
+        "grandchild freed with parent"
+    );
+}
+
+#[itest]
+fn owned_gd_deferred_queues_free() {
+    let owned = OwnedGd::new_deferred(Node::new_alloc());
+    let node_ref = owned.clone();
+
+    drop(owned);
+    assert!(
+        node_ref.is_instance_valid(),
+        "node alive until end of frame"
+    );
+    assert!(node_ref.is_queued_for_deletion());
+}
+
+#[itest]
+fn owned_gd_deferred_owned_by_parent() {
+    let mut parent = OwnedGd::<Node>::new_alloc();
+    let child = OwnedGd::new_deferred(Node::new_alloc());
+    parent.add_child(&*child);
+
+    let child_ref = child.clone();
+    drop(child);
+    assert!(!child_ref.is_queued_for_deletion(), "child owned by parent");
+
+    drop(parent);
+    assert!(!child_ref.is_instance_valid(), "child freed with parent");
+}
+
+#[itest]
+fn owned_gd_already_freed() {
+    let owned = OwnedGd::new(Node::new_alloc());
+    owned.clone().free();
+
+    // Must not panic with double-free.
+    drop(owned);
+}
+
+#[itest]
+fn owned_gd_release() {
+    let owned = OwnedGd::<Node>::new_alloc();
+    let node = owned.release();
+    assert!(node.is_instance_valid(), "released object stays alive");
+
+    node.free();
+}
+
+#[itest]
+fn owned_gd_upcast_object() {
+    let mut parent = Node::new_alloc();
+    let child = Node::new_alloc();
+    parent.add_child(&child);
+
+    // Node ownership is detected through dynamic type.
+    let owned = OwnedGd::new(child.clone().upcast::<Object>());
+    drop(owned);
+    assert!(child.is_instance_valid(), "child owned by parent");
+
+    parent.free();
+    assert!(!child.is_instance_valid());
+}
+
+#[itest]
+fn owned_gd_frees_on_panic() {
+    let mut node_ref = None;
+
+    expect_panic("panic while OwnedGd is alive", || {
+        let owned = OwnedGd::<Node>::new_alloc();
+        node_ref = Some(owned.clone());
+        panic!("oops");
+    });
+
+    assert!(
+        !node_ref.unwrap().is_instance_valid(),
+        "freed during unwind"
+    );
+}
@@ -5,8 +5,8 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use godot::builtin::{dict, Color, Dictionary, GString, Variant, VariantType};
-use godot::classes::{INode, IRefCounted, Node, Object, RefCounted, Resource, Texture};
+use godot::builtin::{dict, Color, Dictionary, GString, PackedFloat32Array, Variant, VariantType};
+use godot::classes::{INode, IRefCounted, Node, Node3D, Object, RefCounted, Resource, Texture};
 use godot::global::{PropertyHint, PropertyUsageFlags};
 use godot::meta::{GodotConvert, PropertyHintInfo, ToGodot};
 use godot::obj::{Base, EngineBitfield, EngineEnum, Gd, NewAlloc, NewGd};
@@ -447,6 +447,297 @@ fn override_export() {
     check_property(&property, "usage", PropertyUsageFlags::GROUP.ord());
 }
 
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportGroups {
+    #[export]
+    ungrouped: i32,
+
+    #[export_category(name = "Stats")]
+    #[export_group(name = "Movement", prefix = "move_")]
+    #[export]
+    move_speed: f32,
+
+    #[export]
+    move_jump_height: f32,
+
+    #[export_group(name = "Combat")]
+    #[export_subgroup(name = "Melee", prefix = "melee_")]
+    #[export]
+    melee_damage: i32,
+}
+
+#[itest]
+fn export_groups() {
+    let class = ExportGroups::new_gd();
+
+    let properties: Vec<(GString, i64, GString)> = class
+        .get_property_list()
+        .iter_shared()
+        .map(|p| {
+            (
+                p.at("name").to::<GString>(),
+                p.at("usage").to::<i64>(),
+                p.at("hint_string").to::<GString>(),
+            )
+        })
+        .skip_while(|(name, _, _)| name.to_string() != "ungrouped")
+        .take(8)
+        .collect();
+
+    let default = PropertyUsageFlags::DEFAULT.ord() as i64;
+    let category = PropertyUsageFlags::CATEGORY.ord() as i64;
+    let group = PropertyUsageFlags::GROUP.ord() as i64;
+    let subgroup = PropertyUsageFlags::SUBGROUP.ord() as i64;
+
+    let expected = [
+        ("ungrouped", default, ""),
+        ("Stats", category, ""),
+        ("Movement", group, "move_"),
+        ("move_speed", default, ""),
+        ("move_jump_height", default, ""),
+        ("Combat", group, ""),
+        ("Melee", subgroup, "melee_"),
+        ("melee_damage", default, ""),
+    ];
+    let expected: Vec<(GString, i64, GString)> = expected
+        .into_iter()
+        .map(|(name, usage, hint_string)| (name.into(), usage, hint_string.into()))
+        .collect();
+
+    assert_eq!(properties, expected);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct ExportElementHints {
+    #[export]
+    resources: Array<Gd<CustomResource>>,
+
+    #[export]
+    nodes: Array<Gd<Node3D>>,
+
+    #[export(range = (0.0, 10.0))]
+    ranged_ints: Array<i32>,
+
+    #[export(file = "*.txt")]
+    files: Array<GString>,
+
+    #[export(range = (-1.0, 1.0, 0.5))]
+    ranged_packed: PackedFloat32Array,
+}
+
+#[itest]
+fn export_array_element_hints() {
+    let class = ExportElementHints::new_alloc();
+    let property_list = class.get_property_list();
+
+    let find_property = |name: &str| {
+        property_list
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let object = VariantType::OBJECT.ord();
+    let expected = [
+        (
+            "resources",
+            format!(
+                "{object}/{}:CustomResource",
+                PropertyHint::RESOURCE_TYPE.ord()
+            ),
+        ),
+        (
+            "nodes",
+            format!("{object}/{}:Node3D", PropertyHint::NODE_TYPE.ord()),
+        ),
+        (
+            "ranged_ints",
+            format!(
+                "{}/{}:0,10",
+                VariantType::INT.ord(),
+                PropertyHint::RANGE.ord()
+            ),
+        ),
+        (
+            "files",
+            format!(
+                "{}/{}:*.txt",
+                VariantType::STRING.ord(),
+                PropertyHint::FILE.ord()
+            ),
+        ),
+    ];
+
+    for (name, hint_string) in expected {
+        let property = find_property(name);
+        check_property(&property, "type", VariantType::ARRAY.ord());
+        check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+        check_property(&property, "hint_string", hint_string);
+    }
+
+    // Packed arrays only support type hints since Godot 4.3.
+    if godot::sys::GdextBuild::since_api("4.3") {
+        let property = find_property("ranged_packed");
+        check_property(&property, "hint", PropertyHint::TYPE_STRING.ord());
+        check_property(
+            &property,
+            "hint_string",
+            format!(
+                "{}/{}:-1,1,0.5",
+                VariantType::FLOAT.ord(),
+                PropertyHint::RANGE.ord()
+            ),
+        );
+    }
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ExportStorage {
+    #[export(storage)]
+    hidden_state: i32,
+}
+
+#[itest]
+fn export_storage() {
+    let class = ExportStorage::new_gd();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "hidden_state".to_variant())
+        .unwrap();
+
+    check_property(&property, "type", VariantType::INT.ord());
+    check_property(&property, "hint", PropertyHint::NONE.ord());
+    check_property(&property, "usage", PropertyUsageFlags::STORAGE.ord());
+}
+
+#[cfg(since_api = "4.4")]
+#[derive(GodotClass)]
+#[class(init, tool, base=Node)]
+struct ExportToolButton {
+    #[export(tool_button = (press, "Press me", "Callable"))]
+    press_button: godot::builtin::Callable,
+
+    press_count: i32,
+
+    base: Base<Node>,
+}
+
+#[cfg(since_api = "4.4")]
+#[godot_api]
+impl ExportToolButton {
+    #[func]
+    fn press(&mut self) {
+        self.press_count += 1;
+    }
+}
+
+#[cfg(since_api = "4.4")]
+#[itest]
+fn export_tool_button() {
+    let mut class = ExportToolButton::new_alloc();
+
+    let property = class
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "press_button".to_variant())
+        .unwrap();
+
+    check_property(&property, "type", VariantType::CALLABLE.ord());
+    check_property(&property, "hint", PropertyHint::TOOL_BUTTON.ord());
+    check_property(&property, "hint_string", "Press me,Callable");
+    check_property(&property, "usage", PropertyUsageFlags::EDITOR.ord());
+
+    let callable = class.get("press_button").to::<godot::builtin::Callable>();
+    assert!(callable.is_valid());
+
+    callable.callv(&godot::builtin::VariantArray::new());
+    assert_eq!(class.bind().press_count, 1);
+
+    class.free();
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct ComputedProperty {
+    health: i32,
+    max_health: i32,
+}
+
+#[godot_api]
+impl ComputedProperty {
+    #[property(get)]
+    fn health_percent(&self) -> f32 {
+        if self.max_health == 0 {
+            return 0.0;
+        }
+        self.health as f32 / self.max_health as f32 * 100.0
+    }
+
+    #[property(get, set = set_health_ratio, rename = ratio)]
+    fn health_ratio(&self) -> f32 {
+        self.health_percent() / 100.0
+    }
+
+    #[func]
+    fn set_health_ratio(&mut self, ratio: f32) {
+        self.health = (ratio * self.max_health as f32).round() as i32;
+    }
+}
+
+#[itest]
+fn computed_property() {
+    let mut obj = ComputedProperty::new_gd();
+    {
+        let mut guard = obj.bind_mut();
+        guard.health = 30;
+        guard.max_health = 120;
+    }
+
+    assert_eq!(obj.get("health_percent"), 25.0f32.to_variant());
+    assert_eq!(obj.call("get_health_percent", &[]), 25.0f32.to_variant());
+    assert_eq!(obj.get("ratio"), 0.25f32.to_variant());
+
+    obj.set("ratio", &0.5f32.to_variant());
+    assert_eq!(obj.bind().health, 60);
+    assert_eq!(obj.get("health_percent"), 50.0f32.to_variant());
+
+    // Read-only property cannot be assigned.
+    obj.set("health_percent", &10.0f32.to_variant());
+    assert_eq!(obj.bind().health, 60);
+}
+
+#[itest]
+fn computed_property_registration() {
+    let obj = ComputedProperty::new_gd();
+    let property_list = obj.get_property_list();
+
+    let find = |name: &str| {
+        property_list
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == name.to_variant())
+            .unwrap()
+    };
+
+    let property = find("health_percent");
+    check_property(&property, "type", VariantType::FLOAT.ord());
+    check_property(
+        &property,
+        "usage",
+        PropertyUsageFlags::EDITOR.ord() | PropertyUsageFlags::READ_ONLY.ord(),
+    );
+
+    let property = find("ratio");
+    check_property(&property, "type", VariantType::FLOAT.ord());
+    check_property(&property, "usage", PropertyUsageFlags::EDITOR.ord());
+}
+
 fn check_property(property: &Dictionary, key: &str, expected: impl ToGodot) {
     assert_eq!(property.get_or_nil(key), expected.to_variant());
 }
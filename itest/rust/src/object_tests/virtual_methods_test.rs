@@ -158,7 +158,7 @@ impl IPrimitiveMesh for VirtualReturnTest {
     fn surface_get_material(&self, _index: i32) -> Option<Gd<Material>> { unreachable!() }
     fn get_blend_shape_count(&self) -> i32 { unreachable!() }
     fn get_blend_shape_name(&self, _index: i32) -> StringName { unreachable!() }
-    fn set_blend_shape_name(&mut self, _index: i32, _name: StringName) { unreachable!() }
+    fn set_blend_shape_name(&mut self, _index: i32, _name: &StringName) { unreachable!() }
     fn get_aabb(&self) -> godot::prelude::Aabb { unreachable!() }
 }
 
@@ -175,8 +175,8 @@ impl INode2D for VirtualInputTest {
         VirtualInputTest { base, event: None }
     }
 
-    fn input(&mut self, event: Gd<InputEvent>) {
-        self.event = Some(event);
+    fn input(&mut self, event: &Gd<InputEvent>) {
+        self.event = Some(event.clone());
     }
 }
 
@@ -198,22 +198,22 @@ impl IResourceFormatLoader for FormatLoaderTest {
         [GString::from("extension")].into_iter().collect()
     }
 
-    fn handles_type(&self, type_: StringName) -> bool {
+    fn handles_type(&self, type_: &StringName) -> bool {
         type_.to_string() == Self::resource_type().to_string()
     }
 
-    fn get_resource_type(&self, _path: GString) -> GString {
+    fn get_resource_type(&self, _path: &GString) -> GString {
         Self::resource_type()
     }
 
-    fn exists(&self, _path: GString) -> bool {
+    fn exists(&self, _path: &GString) -> bool {
         true
     }
 
     fn load(
         &self,
-        _path: GString,
-        _original_path: GString,
+        _path: &GString,
+        _original_path: &GString,
         _use_sub_threads: bool,
         _cache_mode: i32,
     ) -> Variant {
@@ -683,9 +683,9 @@ pub struct CollisionObject2DTest {
 
 #[godot_api]
 impl IRigidBody2D for CollisionObject2DTest {
-    fn input_event(&mut self, viewport: Gd<Viewport>, _event: Gd<InputEvent>, _shape_idx: i32) {
+    fn input_event(&mut self, viewport: &Gd<Viewport>, _event: &Gd<InputEvent>, _shape_idx: i32) {
         self.input_event_called = true;
-        self.viewport = Some(viewport);
+        self.viewport = Some(viewport.clone());
     }
 }
 
@@ -791,7 +791,7 @@ impl IEditorPlugin for CustomEditorPlugin {
     }
 
     // This parameter is non-null.
-    fn handles(&self, _object: Gd<Object>) -> bool {
+    fn handles(&self, _object: &Gd<Object>) -> bool {
         true
     }
 }
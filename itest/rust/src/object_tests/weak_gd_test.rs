@@ -0,0 +1,103 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::collections::HashSet;
+
+use godot::classes::{Node, Node3D, Object, RefCounted};
+use godot::obj::{NewAlloc, NewGd, WeakGd};
+use godot::register::GodotClass;
+
+use crate::framework::itest;
+
+#[itest]
+fn weak_gd_refcounted() {
+    let obj = RefCounted::new_gd();
+    let weak = obj.downgrade();
+    assert_eq!(obj.get_reference_count(), 1, "downgrade() keeps ref-count");
+
+    let upgraded = weak.upgrade().expect("object alive");
+    assert_eq!(upgraded, obj);
+    assert_eq!(obj.get_reference_count(), 2);
+
+    drop(upgraded);
+    drop(obj);
+    assert_eq!(weak.upgrade(), None, "last strong reference dropped");
+}
+
+#[itest]
+fn weak_gd_manually_managed() {
+    let node = Node::new_alloc();
+    let weak = node.downgrade();
+
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+    assert_eq!(weak.instance_id(), node.instance_id());
+
+    node.free();
+    assert_eq!(weak.upgrade(), None, "object freed");
+}
+
+#[itest]
+fn weak_gd_upgrade_checks_type() {
+    let node = Node3D::new_alloc();
+    let weak: WeakGd<Object> = node.clone().upcast::<Object>().downgrade();
+
+    let upgraded = weak.upgrade().expect("object alive");
+    assert!(upgraded.try_cast::<Node3D>().is_ok());
+
+    node.free();
+}
+
+#[itest]
+fn weak_gd_eq_hash() {
+    let a = RefCounted::new_gd();
+    let b = RefCounted::new_gd();
+
+    let weak_a = a.downgrade();
+    assert_eq!(weak_a, a.downgrade());
+    assert_ne!(weak_a, b.downgrade());
+
+    let set: HashSet<WeakGd<RefCounted>> = [weak_a, a.downgrade(), b.downgrade()].into();
+    assert_eq!(set.len(), 2);
+
+    // Comparing and hashing remains possible after the objects are gone.
+    drop(a);
+    drop(b);
+    assert!(set.contains(&weak_a));
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct WeakSelf {
+    weak_self: Option<WeakGd<WeakSelf>>,
+}
+
+thread_local! {
+    static UPGRADED_IN_DROP: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+impl Drop for WeakSelf {
+    fn drop(&mut self) {
+        let upgraded = self.weak_self.and_then(|weak| weak.upgrade());
+        UPGRADED_IN_DROP.set(Some(upgraded.is_some()));
+    }
+}
+
+#[itest]
+fn weak_gd_upgrade_during_destruction() {
+    let mut obj = WeakSelf::new_gd();
+    let weak = obj.downgrade();
+    obj.bind_mut().weak_self = Some(weak);
+
+    drop(obj);
+    assert_eq!(
+        UPGRADED_IN_DROP.get(),
+        Some(false),
+        "object being destroyed must not be revived"
+    );
+    assert_eq!(weak.upgrade(), None);
+}
@@ -0,0 +1,98 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::{ClassDb, IRefCounted, RefCounted};
+use godot::prelude::*;
+use godot::register::ClassBuilder;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct BuilderRegistered {
+    #[init(val = 10)]
+    value: i64,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl IRefCounted for BuilderRegistered {
+    fn register_class(builder: &mut ClassBuilder<Self>) {
+        builder
+            .method("get_value", |this: &mut Self| this.value)
+            .done();
+
+        builder
+            .method("set_value", |this: &mut Self, value: i64| {
+                this.value = value
+            })
+            .param_names(&["value"])
+            .done();
+
+        // Captured state, as when generating methods from configuration.
+        let suffix = String::from("!");
+        builder
+            .method("describe", move |this: &mut Self, prefix: GString| {
+                GString::from(format!("{prefix}{}{suffix}", this.value))
+            })
+            .done();
+
+        builder
+            .property::<i64>("value")
+            .getter("get_value")
+            .setter("set_value")
+            .done();
+
+        builder
+            .signal("value_reset")
+            .param::<i64>("old_value")
+            .done();
+
+        builder.constant("DEFAULT_VALUE", 10);
+        builder.enum_constants("Mode", &[("MODE_A", 0), ("MODE_B", 1)]);
+    }
+}
+
+#[itest]
+fn builder_method_call() {
+    let mut obj = BuilderRegistered::new_gd();
+
+    assert_eq!(obj.call("get_value", &[]), 10.to_variant());
+
+    obj.call("set_value", &[25.to_variant()]);
+    assert_eq!(obj.bind().value, 25);
+
+    let described = obj.call("describe", &["value=".to_variant()]);
+    assert_eq!(described, "value=25!".to_variant());
+}
+
+#[itest]
+fn builder_property() {
+    let mut obj = BuilderRegistered::new_gd();
+
+    obj.set("value", &77.to_variant());
+    assert_eq!(obj.bind().value, 77);
+    assert_eq!(obj.get("value"), 77.to_variant());
+}
+
+#[itest]
+fn builder_signal_constant() {
+    let class_name = BuilderRegistered::class_name().to_string_name();
+    let class_db = ClassDb::singleton();
+
+    assert!(class_db.class_has_signal(&class_name, "value_reset"));
+    assert!(class_db.class_has_method(&class_name, "describe"));
+    assert_eq!(
+        class_db.class_get_integer_constant(&class_name, "DEFAULT_VALUE"),
+        10
+    );
+    assert_eq!(
+        class_db.class_get_integer_constant(&class_name, "MODE_B"),
+        1
+    );
+    assert!(class_db.class_has_enum(&class_name, "Mode"));
+}
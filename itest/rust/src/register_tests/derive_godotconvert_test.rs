@@ -7,8 +7,10 @@
 
 use std::fmt::Debug;
 
-use godot::builtin::{GString, Vector2};
-use godot::meta::ToGodot;
+use godot::builtin::{
+    dict, varray, Dictionary, GString, StringName, Variant, VariantArray, Vector2,
+};
+use godot::meta::{FromGodot, ToGodot};
 use godot::register::GodotConvert;
 
 use crate::common::roundtrip;
@@ -56,6 +58,54 @@ enum EnumIntyWithExprs {
     I = (EnumInty::B as isize),
 }
 
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = StringName)]
+enum EnumStringName {
+    First,
+    #[godot(rename = "second_one")]
+    Second,
+    #[godot(rename = third)]
+    Third,
+}
+
+#[derive(GodotConvert, Clone, PartialEq, Debug)]
+#[godot(via = GString, case_insensitive)]
+enum EnumCaseInsensitive {
+    Alpha,
+    #[godot(rename = "BetaRay")]
+    Beta,
+}
+
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+struct NamedStruct {
+    health: i64,
+    #[godot(rename = display_name)]
+    name: GString,
+    #[godot(default)]
+    level: i32,
+    #[godot(skip)]
+    cached: Option<Vector2>,
+}
+
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(via = Array)]
+struct TupleStruct(i64, #[godot(skip)] bool, GString, #[godot(default)] f32);
+
+#[derive(GodotConvert, PartialEq, Debug)]
+#[godot(via = Dictionary)]
+enum EnumWithData {
+    Unit,
+    Newtype(GString),
+    #[godot(rename = pair)]
+    Tuple(i64, Vector2),
+    Named {
+        x: i64,
+        #[godot(default)]
+        y: i64,
+    },
+}
+
 #[itest]
 fn newtype_tuple_struct() {
     roundtrip(TupleNewtype(GString::from("hello!")));
@@ -124,6 +174,153 @@ fn enum_inty_with_complex_exprs() {
     assert_eq!(EnumIntyWithExprs::I as isize, 11);
 }
 
+#[itest]
+fn enum_string_name() {
+    roundtrip(EnumStringName::First);
+    roundtrip(EnumStringName::Second);
+    roundtrip(EnumStringName::Third);
+
+    assert_eq!(EnumStringName::First.to_godot(), StringName::from("First"));
+    assert_eq!(
+        EnumStringName::Second.to_godot(),
+        StringName::from("second_one")
+    );
+    assert_eq!(EnumStringName::Third.to_godot(), StringName::from("third"));
+
+    // Renamed enumerators are no longer known by their Rust name; matching is case-sensitive by default.
+    assert!(EnumStringName::try_from_godot(StringName::from("Second")).is_err());
+    assert!(EnumStringName::try_from_godot(StringName::from("first")).is_err());
+}
+
+#[itest]
+fn enum_case_insensitive() {
+    roundtrip(EnumCaseInsensitive::Alpha);
+    roundtrip(EnumCaseInsensitive::Beta);
+
+    assert_eq!(EnumCaseInsensitive::Beta.to_godot(), "BetaRay".into());
+
+    assert_eq!(
+        EnumCaseInsensitive::from_godot("ALPHA".into()),
+        EnumCaseInsensitive::Alpha
+    );
+    assert_eq!(
+        EnumCaseInsensitive::from_godot("betaray".into()),
+        EnumCaseInsensitive::Beta
+    );
+    assert!(EnumCaseInsensitive::try_from_godot("Beta".into()).is_err());
+}
+
+#[itest]
+fn named_struct() {
+    roundtrip(NamedStruct {
+        health: 100,
+        name: "Orc".into(),
+        level: 3,
+        cached: None,
+    });
+
+    let value = NamedStruct {
+        health: 20,
+        name: "Goblin".into(),
+        level: 1,
+        cached: Some(Vector2::ONE),
+    };
+    assert_eq!(
+        value.to_godot(),
+        dict! { "health": 20, "display_name": "Goblin", "level": 1 }
+    );
+
+    // Absent `default` field, and additional keys are ignored.
+    let parsed =
+        NamedStruct::from_godot(dict! { "health": 5, "display_name": "Bat", "extra": true });
+    assert_eq!(
+        parsed,
+        NamedStruct {
+            health: 5,
+            name: "Bat".into(),
+            level: 0,
+            cached: None,
+        }
+    );
+
+    let err = NamedStruct::try_from_godot(dict! { "display_name": "Bat" }).unwrap_err();
+    assert!(
+        err.to_string().contains("missing element with key health"),
+        "{err}"
+    );
+
+    let err =
+        NamedStruct::try_from_godot(dict! { "health": "full", "display_name": "Bat" }).unwrap_err();
+    assert!(err.to_string().contains("with key health"), "{err}");
+}
+
+#[itest]
+fn tuple_struct() {
+    roundtrip(TupleStruct(7, false, "seven".into(), 7.5));
+
+    let value = TupleStruct(1, true, "one".into(), 0.5);
+    assert_eq!(value.to_godot(), varray![1, "one", 0.5]);
+
+    // Trailing `default` element may be absent.
+    let parsed = TupleStruct::from_godot(varray![2, "two"]);
+    assert_eq!(parsed, TupleStruct(2, false, "two".into(), 0.0));
+
+    let err = TupleStruct::try_from_godot(varray![2]).unwrap_err();
+    assert!(
+        err.to_string().contains("missing element at index 1"),
+        "{err}"
+    );
+
+    let err = TupleStruct::try_from_godot(varray![2, "two", 0.5, 1]).unwrap_err();
+    assert!(err.to_string().contains("at most 3 elements"), "{err}");
+
+    let err = TupleStruct::try_from_godot(varray![2, 3]).unwrap_err();
+    assert!(err.to_string().contains("at index 1"), "{err}");
+}
+
+#[itest]
+fn enum_with_data() {
+    roundtrip(EnumWithData::Unit);
+    roundtrip(EnumWithData::Newtype("text".into()));
+    roundtrip(EnumWithData::Tuple(3, Vector2::new(1.0, 2.0)));
+    roundtrip(EnumWithData::Named { x: 1, y: 2 });
+
+    assert_eq!(
+        EnumWithData::Unit.to_godot(),
+        dict! { "Unit": Variant::nil() }
+    );
+    assert_eq!(
+        EnumWithData::Newtype("text".into()).to_godot(),
+        dict! { "Newtype": "text" }
+    );
+    assert_eq!(
+        EnumWithData::Tuple(3, Vector2::new(1.0, 2.0)).to_godot(),
+        dict! { "pair": varray![3, Vector2::new(1.0, 2.0)] }
+    );
+    assert_eq!(
+        EnumWithData::Named { x: 1, y: 2 }.to_godot(),
+        dict! { "Named": dict! { "x": 1, "y": 2 } }
+    );
+
+    let parsed = EnumWithData::from_godot(dict! { "Named": dict! { "x": 4 } });
+    assert_eq!(parsed, EnumWithData::Named { x: 4, y: 0 });
+
+    let err = EnumWithData::try_from_godot(dict! { "Unknown": 1 }).unwrap_err();
+    assert!(
+        err.to_string().contains("invalid EnumWithData variant"),
+        "{err}"
+    );
+
+    let err = EnumWithData::try_from_godot(Dictionary::new()).unwrap_err();
+    assert!(err.to_string().contains("single entry"), "{err}");
+
+    let err = EnumWithData::try_from_godot(dict! { "pair": VariantArray::new() }).unwrap_err();
+    assert!(
+        err.to_string().contains("missing element at index 0"),
+        "{err}"
+    );
+}
+
 macro_rules! test_inty {
     ($T:ident, $test_name:ident, $class_name:ident) => {
         #[derive(GodotConvert, Clone, PartialEq, Debug)]
@@ -10,7 +10,9 @@
 
 use crate::framework::itest;
 use godot::classes::ClassDb;
+use godot::global::MethodFlags;
 use godot::prelude::*;
+use std::error::Error;
 
 #[derive(GodotClass)]
 #[class(init, base=RefCounted)]
@@ -247,6 +249,99 @@ impl IRefCounted for GdSelfObj {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncDefaultsObj;
+
+#[godot_api]
+impl FuncDefaultsObj {
+    const DEFAULT_COUNT: i32 = 3;
+
+    #[func]
+    fn spawn(
+        &self,
+        pos: Vector2,
+        #[opt(default = Self::DEFAULT_COUNT)] count: i32,
+        #[opt(default = GString::from("enemy"))] name: GString,
+    ) -> VariantArray {
+        varray![pos, count, name]
+    }
+
+    #[func(gd_self)]
+    fn spawn_gd_self(
+        _this: Gd<Self>,
+        #[opt(default = Vector2i::new(1, 2))] pos: Vector2i,
+    ) -> Vector2i {
+        pos
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncVarargObj;
+
+#[godot_api]
+impl FuncVarargObj {
+    #[func(vararg)]
+    fn join_args(&self, separator: GString, args: &[&Variant]) -> GString {
+        let parts: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        parts.join(&separator.to_string()).into()
+    }
+
+    #[func(vararg)]
+    fn count_args(args: &[&Variant]) -> i64 {
+        args.len() as i64
+    }
+
+    #[func(gd_self, vararg)]
+    fn first_arg(_this: Gd<Self>, args: &[&Variant]) -> Variant {
+        args.first().map(|&arg| arg.clone()).unwrap_or_default()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncBorrowObj;
+
+#[godot_api]
+impl FuncBorrowObj {
+    #[func]
+    fn string_len(&self, string: &GString) -> i64 {
+        string.len() as i64
+    }
+
+    #[func]
+    fn sum(array: &Array<i64>) -> i64 {
+        array.iter_shared().sum()
+    }
+
+    #[func]
+    fn total_score(scores: &TypedDictionary<GString, i64>) -> i64 {
+        scores.iter_shared().map(|(_name, score)| score).sum()
+    }
+
+    #[func]
+    fn ref_count(&self, object: &Gd<RefCounted>) -> i32 {
+        object.get_reference_count()
+    }
+
+    #[func(gd_self)]
+    fn node_name(_this: Gd<Self>, node: &Gd<Node>) -> StringName {
+        node.get_name()
+    }
+
+    #[func]
+    fn greet(&self, #[opt(default = GString::from("world"))] name: &GString) -> GString {
+        format!("hello {name}").into()
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Tests
 
@@ -292,6 +387,192 @@ fn cfg_removes_or_keeps_signals() {
     assert!(!class_has_signal::<GdSelfObj>("cfg_removes_signal"));
 }
 
+#[itest]
+fn func_default_params_varcall() {
+    let mut obj = FuncDefaultsObj::new_gd();
+    let pos = Vector2::new(1.0, 2.0);
+
+    let result = obj.call("spawn", &[pos.to_variant()]);
+    assert_eq!(result, varray![pos, 3, "enemy"].to_variant());
+
+    let result = obj.call("spawn", &[pos.to_variant(), 7.to_variant()]);
+    assert_eq!(result, varray![pos, 7, "enemy"].to_variant());
+
+    let result = obj.call(
+        "spawn",
+        &[pos.to_variant(), 7.to_variant(), "boss".to_variant()],
+    );
+    assert_eq!(result, varray![pos, 7, "boss"].to_variant());
+
+    let result = obj.call("spawn_gd_self", &[]);
+    assert_eq!(result, Vector2i::new(1, 2).to_variant());
+}
+
+#[itest]
+fn func_default_params_rust_signature() {
+    let obj = FuncDefaultsObj::new_gd();
+
+    // Rust callers still pass all arguments.
+    let result = obj.bind().spawn(Vector2::ZERO, 1, "x".into());
+    assert_eq!(result, varray![Vector2::ZERO, 1, "x"]);
+}
+
+#[itest]
+fn func_default_params_too_few_args() {
+    let mut obj = FuncDefaultsObj::new_gd();
+
+    let call_error = obj
+        .try_call("spawn", &[])
+        .expect_err("expected failed call");
+
+    let source = call_error.source().expect("must have source CallError");
+    assert_eq!(
+        source.to_string(),
+        "godot-rust function call failed: FuncDefaultsObj::spawn()\
+        \n    Reason: function has 3 parameters (2 optional), but received 0 arguments"
+    );
+}
+
+#[itest]
+fn func_default_params_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&FuncDefaultsObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let spawn = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("spawn".to_variant()))
+        .expect("method `spawn` registered");
+
+    let default_args = spawn.at("default_args").to::<VariantArray>();
+    assert_eq!(default_args, varray![3, "enemy"]);
+}
+
+#[itest]
+fn func_vararg_call() {
+    let mut obj = FuncVarargObj::new_gd();
+
+    let result = obj.call("join_args", &["-".to_variant()]);
+    assert_eq!(result, "".to_variant());
+
+    let result = obj.call(
+        "join_args",
+        &[
+            ", ".to_variant(),
+            1.to_variant(),
+            "two".to_variant(),
+            3.5.to_variant(),
+        ],
+    );
+    assert_eq!(result, "1, two, 3.5".to_variant());
+
+    let result = obj.call("count_args", &[]);
+    assert_eq!(result, 0.to_variant());
+
+    let result = obj.call(
+        "count_args",
+        &[Variant::nil(), 2.to_variant(), 3.to_variant()],
+    );
+    assert_eq!(result, 3.to_variant());
+
+    let result = obj.call("first_arg", &[7.to_variant(), 8.to_variant()]);
+    assert_eq!(result, 7.to_variant());
+}
+
+#[itest]
+fn func_vararg_too_few_args() {
+    let mut obj = FuncVarargObj::new_gd();
+
+    let call_error = obj
+        .try_call("join_args", &[])
+        .expect_err("expected failed call");
+
+    let source = call_error.source().expect("must have source CallError");
+    assert_eq!(
+        source.to_string(),
+        "godot-rust function call failed: FuncVarargObj::join_args()\
+        \n    Reason: function has 1 fixed parameter and varargs, but received 0 arguments"
+    );
+}
+
+#[itest]
+fn func_vararg_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&FuncVarargObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let join_args = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("join_args".to_variant()))
+        .expect("method `join_args` registered");
+
+    // Only fixed parameters are registered.
+    let flags = join_args.at("flags").to::<i64>() as u64;
+    assert_ne!(flags & MethodFlags::VARARG.ord(), 0);
+    assert_eq!(join_args.at("args").to::<VariantArray>().len(), 1);
+}
+
+#[itest]
+fn func_borrowed_params_varcall() {
+    let mut obj = FuncBorrowObj::new_gd();
+
+    let result = obj.call("string_len", &["hello".to_variant()]);
+    assert_eq!(result, 5.to_variant());
+
+    let result = obj.call("sum", &[array![1, 2, 3].to_variant()]);
+    assert_eq!(result, 6.to_variant());
+
+    let scores: TypedDictionary<GString, i64> = [("a", 1), ("b", 2)].into_iter().collect();
+    let result = obj.call("total_score", &[scores.to_variant()]);
+    assert_eq!(result, 3.to_variant());
+
+    let mut node = Node::new_alloc();
+    node.set_name("borrowed");
+    let result = obj.call("node_name", &[node.to_variant()]);
+    assert_eq!(result, StringName::from("borrowed").to_variant());
+    node.free();
+
+    let result = obj.call("greet", &[]);
+    assert_eq!(result, "hello world".to_variant());
+
+    let result = obj.call("greet", &["Godot".to_variant()]);
+    assert_eq!(result, "hello Godot".to_variant());
+}
+
+#[itest]
+fn func_borrowed_params_wrong_type() {
+    let mut obj = FuncBorrowObj::new_gd();
+
+    let call_error = obj
+        .try_call("sum", &[varray![1, "two"].to_variant()])
+        .expect_err("expected failed call");
+
+    assert!(call_error.source().is_some(), "must have source CallError");
+}
+
+#[itest]
+fn func_borrowed_params_registered() {
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(&FuncBorrowObj::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let string_len = methods
+        .iter_shared()
+        .find(|method| method.get("name") == Some("string_len".to_variant()))
+        .expect("method `string_len` registered");
+
+    // Registered with the referenced type.
+    let args = string_len.at("args").to::<Array<Dictionary>>();
+    assert_eq!(args.len(), 1);
+    assert_eq!(
+        args.at(0).at("type"),
+        VariantType::STRING.ord().to_variant()
+    );
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers
 
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod builder_test;
 mod constant_test;
 mod conversion_test;
 mod derive_godotconvert_test;
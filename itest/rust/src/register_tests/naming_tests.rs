@@ -19,10 +19,10 @@ struct KeywordParameterEditorExportPlugin;
 impl IEditorExportPlugin for KeywordParameterEditorExportPlugin {
     // This test requires that the second non-self parameter on `export_file`
     // remain named `_type`.
-    fn export_file(&mut self, _path: GString, _type: GString, _features: PackedStringArray) {}
+    fn export_file(&mut self, _path: &GString, _type: &GString, _features: &PackedStringArray) {}
 
-    fn customize_resource(&mut self, _resource: Gd<Resource>, _path: GString) -> Option<Gd<Resource>> { unreachable!() }
-    fn customize_scene(&mut self, _scene: Gd<Node>, _path: GString) -> Option<Gd<Node>> { unreachable!() }
+    fn customize_resource(&mut self, _resource: &Gd<Resource>, _path: &GString) -> Option<Gd<Resource>> { unreachable!() }
+    fn customize_scene(&mut self, _scene: &Gd<Node>, _path: &GString) -> Option<Gd<Node>> { unreachable!() }
     fn get_customization_configuration_hash(&self) -> u64 { unreachable!() }
     fn get_name(&self) -> GString { unreachable!() }
 }